@@ -0,0 +1,208 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_ANIMATIONCOMPONENT_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_ANIMATIONCOMPONENT_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+namespace PixelCraft {
+namespace ECS {
+
+struct AnimationComponentData;
+struct AnimationComponentDataBuilder;
+
+/// What happens when playback reaches the last frame.
+enum LoopMode : int8_t {
+  /// Stop on the last frame.
+  LoopMode_Once = 0,
+  /// Wrap around to the first frame.
+  LoopMode_Loop = 1,
+  /// Reverse direction at either end.
+  LoopMode_PingPong = 2,
+  LoopMode_MIN = LoopMode_Once,
+  LoopMode_MAX = LoopMode_PingPong
+};
+
+inline const LoopMode (&EnumValuesLoopMode())[3] {
+  static const LoopMode values[] = {
+    LoopMode_Once,
+    LoopMode_Loop,
+    LoopMode_PingPong
+  };
+  return values;
+}
+
+inline const char * const *EnumNamesLoopMode() {
+  static const char * const names[4] = {
+    "Once",
+    "Loop",
+    "PingPong",
+    nullptr
+  };
+  return names;
+}
+
+inline const char *EnumNameLoopMode(LoopMode e) {
+  if (::flatbuffers::IsOutRange(e, LoopMode_Once, LoopMode_PingPong)) return "";
+  const size_t index = static_cast<size_t>(e);
+  return EnumNamesLoopMode()[index];
+}
+
+struct AnimationComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef AnimationComponentDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_CLIP_NAME = 4,
+    VT_FRAME_COUNT = 6,
+    VT_FPS = 8,
+    VT_LOOP_MODE = 10,
+    VT_CURRENT_FRAME = 12
+  };
+  /// Name of the clip in the sprite's animation set, e.g. "run".
+  const ::flatbuffers::String *clip_name() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_CLIP_NAME);
+  }
+  uint32_t frame_count() const {
+    return GetField<uint32_t>(VT_FRAME_COUNT, 0);
+  }
+  /// Playback speed in frames per second.
+  float fps() const {
+    return GetField<float>(VT_FPS, 12.0f);
+  }
+  PixelCraft::ECS::LoopMode loop_mode() const {
+    return static_cast<PixelCraft::ECS::LoopMode>(GetField<int8_t>(VT_LOOP_MODE, 1));
+  }
+  /// Frame currently shown, from 0 to frame_count - 1.
+  uint32_t current_frame() const {
+    return GetField<uint32_t>(VT_CURRENT_FRAME, 0);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_CLIP_NAME) &&
+           verifier.VerifyString(clip_name()) &&
+           VerifyField<uint32_t>(verifier, VT_FRAME_COUNT, 4) &&
+           VerifyField<float>(verifier, VT_FPS, 4) &&
+           VerifyField<int8_t>(verifier, VT_LOOP_MODE, 1) &&
+           VerifyField<uint32_t>(verifier, VT_CURRENT_FRAME, 4) &&
+           verifier.EndTable();
+  }
+};
+
+struct AnimationComponentDataBuilder {
+  typedef AnimationComponentData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_clip_name(::flatbuffers::Offset<::flatbuffers::String> clip_name) {
+    fbb_.AddOffset(AnimationComponentData::VT_CLIP_NAME, clip_name);
+  }
+  void add_frame_count(uint32_t frame_count) {
+    fbb_.AddElement<uint32_t>(AnimationComponentData::VT_FRAME_COUNT, frame_count, 0);
+  }
+  void add_fps(float fps) {
+    fbb_.AddElement<float>(AnimationComponentData::VT_FPS, fps, 12.0f);
+  }
+  void add_loop_mode(PixelCraft::ECS::LoopMode loop_mode) {
+    fbb_.AddElement<int8_t>(AnimationComponentData::VT_LOOP_MODE, static_cast<int8_t>(loop_mode), 1);
+  }
+  void add_current_frame(uint32_t current_frame) {
+    fbb_.AddElement<uint32_t>(AnimationComponentData::VT_CURRENT_FRAME, current_frame, 0);
+  }
+  explicit AnimationComponentDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<AnimationComponentData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<AnimationComponentData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<AnimationComponentData> CreateAnimationComponentData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> clip_name = 0,
+    uint32_t frame_count = 0,
+    float fps = 12.0f,
+    PixelCraft::ECS::LoopMode loop_mode = PixelCraft::ECS::LoopMode_Loop,
+    uint32_t current_frame = 0) {
+  AnimationComponentDataBuilder builder_(_fbb);
+  builder_.add_current_frame(current_frame);
+  builder_.add_fps(fps);
+  builder_.add_frame_count(frame_count);
+  builder_.add_clip_name(clip_name);
+  builder_.add_loop_mode(loop_mode);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<AnimationComponentData> CreateAnimationComponentDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *clip_name = nullptr,
+    uint32_t frame_count = 0,
+    float fps = 12.0f,
+    PixelCraft::ECS::LoopMode loop_mode = PixelCraft::ECS::LoopMode_Loop,
+    uint32_t current_frame = 0) {
+  auto clip_name__ = clip_name ? _fbb.CreateString(clip_name) : 0;
+  return PixelCraft::ECS::CreateAnimationComponentData(
+      _fbb,
+      clip_name__,
+      frame_count,
+      fps,
+      loop_mode,
+      current_frame);
+}
+
+inline const PixelCraft::ECS::AnimationComponentData *GetAnimationComponentData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::AnimationComponentData>(buf);
+}
+
+inline const PixelCraft::ECS::AnimationComponentData *GetSizePrefixedAnimationComponentData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::AnimationComponentData>(buf);
+}
+
+inline const char *AnimationComponentDataIdentifier() {
+  return "ANIM";
+}
+
+inline bool AnimationComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, AnimationComponentDataIdentifier());
+}
+
+inline bool SizePrefixedAnimationComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, AnimationComponentDataIdentifier(), true);
+}
+
+inline bool VerifyAnimationComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::AnimationComponentData>(AnimationComponentDataIdentifier());
+}
+
+inline bool VerifySizePrefixedAnimationComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::AnimationComponentData>(AnimationComponentDataIdentifier());
+}
+
+inline void FinishAnimationComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::AnimationComponentData> root) {
+  fbb.Finish(root, AnimationComponentDataIdentifier());
+}
+
+inline void FinishSizePrefixedAnimationComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::AnimationComponentData> root) {
+  fbb.FinishSizePrefixed(root, AnimationComponentDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_ANIMATIONCOMPONENT_PIXELCRAFT_ECS_H_
@@ -0,0 +1,375 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_LOOP_MODE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_LOOP_MODE: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_LOOP_MODE: [LoopMode; 3] = [
+  LoopMode::Once,
+  LoopMode::Loop,
+  LoopMode::PingPong,
+];
+
+/// What happens when playback reaches the last frame.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct LoopMode(pub i8);
+#[allow(non_upper_case_globals)]
+impl LoopMode {
+  /// Stop on the last frame.
+  pub const Once: Self = Self(0);
+  /// Wrap around to the first frame.
+  pub const Loop: Self = Self(1);
+  /// Reverse direction at either end.
+  pub const PingPong: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Once,
+    Self::Loop,
+    Self::PingPong,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Once => Some("Once"),
+      Self::Loop => Some("Loop"),
+      Self::PingPong => Some("PingPong"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for LoopMode {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for LoopMode {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for LoopMode {
+    type Output = LoopMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for LoopMode {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for LoopMode {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for LoopMode {}
+pub enum AnimationComponentDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AnimationComponentData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AnimationComponentData<'a> {
+  type Inner = AnimationComponentData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> AnimationComponentData<'a> {
+  pub const VT_CLIP_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_FRAME_COUNT: flatbuffers::VOffsetT = 6;
+  pub const VT_FPS: flatbuffers::VOffsetT = 8;
+  pub const VT_LOOP_MODE: flatbuffers::VOffsetT = 10;
+  pub const VT_CURRENT_FRAME: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    AnimationComponentData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args AnimationComponentDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<AnimationComponentData<'bldr>> {
+    let mut builder = AnimationComponentDataBuilder::new(_fbb);
+    builder.add_current_frame(args.current_frame);
+    builder.add_fps(args.fps);
+    builder.add_frame_count(args.frame_count);
+    if let Some(x) = args.clip_name { builder.add_clip_name(x); }
+    builder.add_loop_mode(args.loop_mode);
+    builder.finish()
+  }
+
+
+  /// Name of the clip in the sprite's animation set, e.g. "run".
+  #[inline]
+  pub fn clip_name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(AnimationComponentData::VT_CLIP_NAME, None)}
+  }
+  #[inline]
+  pub fn frame_count(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(AnimationComponentData::VT_FRAME_COUNT, Some(0)).unwrap()}
+  }
+  /// Playback speed in frames per second.
+  #[inline]
+  pub fn fps(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(AnimationComponentData::VT_FPS, Some(12.0)).unwrap()}
+  }
+  #[inline]
+  pub fn loop_mode(&self) -> LoopMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<LoopMode>(AnimationComponentData::VT_LOOP_MODE, Some(LoopMode::Loop)).unwrap()}
+  }
+  /// Frame currently shown, from 0 to frame_count - 1.
+  #[inline]
+  pub fn current_frame(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(AnimationComponentData::VT_CURRENT_FRAME, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for AnimationComponentData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("clip_name", Self::VT_CLIP_NAME, false)?
+     .visit_field::<u32>("frame_count", Self::VT_FRAME_COUNT, false)?
+     .visit_field::<f32>("fps", Self::VT_FPS, false)?
+     .visit_field::<LoopMode>("loop_mode", Self::VT_LOOP_MODE, false)?
+     .visit_field::<u32>("current_frame", Self::VT_CURRENT_FRAME, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AnimationComponentDataArgs<'a> {
+    pub clip_name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub frame_count: u32,
+    pub fps: f32,
+    pub loop_mode: LoopMode,
+    pub current_frame: u32,
+}
+impl<'a> Default for AnimationComponentDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    AnimationComponentDataArgs {
+      clip_name: None,
+      frame_count: 0,
+      fps: 12.0,
+      loop_mode: LoopMode::Loop,
+      current_frame: 0,
+    }
+  }
+}
+
+pub struct AnimationComponentDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AnimationComponentDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_clip_name(&mut self, clip_name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(AnimationComponentData::VT_CLIP_NAME, clip_name);
+  }
+  #[inline]
+  pub fn add_frame_count(&mut self, frame_count: u32) {
+    self.fbb_.push_slot::<u32>(AnimationComponentData::VT_FRAME_COUNT, frame_count, 0);
+  }
+  #[inline]
+  pub fn add_fps(&mut self, fps: f32) {
+    self.fbb_.push_slot::<f32>(AnimationComponentData::VT_FPS, fps, 12.0);
+  }
+  #[inline]
+  pub fn add_loop_mode(&mut self, loop_mode: LoopMode) {
+    self.fbb_.push_slot::<LoopMode>(AnimationComponentData::VT_LOOP_MODE, loop_mode, LoopMode::Loop);
+  }
+  #[inline]
+  pub fn add_current_frame(&mut self, current_frame: u32) {
+    self.fbb_.push_slot::<u32>(AnimationComponentData::VT_CURRENT_FRAME, current_frame, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AnimationComponentDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    AnimationComponentDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<AnimationComponentData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for AnimationComponentData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("AnimationComponentData");
+      ds.field("clip_name", &self.clip_name());
+      ds.field("frame_count", &self.frame_count());
+      ds.field("fps", &self.fps());
+      ds.field("loop_mode", &self.loop_mode());
+      ds.field("current_frame", &self.current_frame());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `AnimationComponentData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_animation_component_data_unchecked`.
+pub fn root_as_animation_component_data(buf: &[u8]) -> Result<AnimationComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<AnimationComponentData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `AnimationComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_animation_component_data_unchecked`.
+pub fn size_prefixed_root_as_animation_component_data(buf: &[u8]) -> Result<AnimationComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<AnimationComponentData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `AnimationComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_animation_component_data_unchecked`.
+pub fn root_as_animation_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<AnimationComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<AnimationComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `AnimationComponentData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_animation_component_data_unchecked`.
+pub fn size_prefixed_root_as_animation_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<AnimationComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<AnimationComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a AnimationComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `AnimationComponentData`.
+pub unsafe fn root_as_animation_component_data_unchecked(buf: &[u8]) -> AnimationComponentData {
+  flatbuffers::root_unchecked::<AnimationComponentData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed AnimationComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `AnimationComponentData`.
+pub unsafe fn size_prefixed_root_as_animation_component_data_unchecked(buf: &[u8]) -> AnimationComponentData {
+  flatbuffers::size_prefixed_root_unchecked::<AnimationComponentData>(buf)
+}
+pub const ANIMATION_COMPONENT_DATA_IDENTIFIER: &str = "ANIM";
+
+#[inline]
+pub fn animation_component_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, ANIMATION_COMPONENT_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn animation_component_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, ANIMATION_COMPONENT_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_animation_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<AnimationComponentData<'a>>) {
+  fbb.finish(root, Some(ANIMATION_COMPONENT_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_animation_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<AnimationComponentData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(ANIMATION_COMPONENT_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
@@ -0,0 +1,49 @@
+// Starting values for newly created `AudioSourceComponentData` tables.
+
+use crate::audio_source_component_generated::*;
+
+/// Arguments for a new audio source: full volume, normal pitch and a
+/// 100 unit falloff range.
+///
+/// The schema keeps 0 as the default for `volume`, `pitch` and
+/// `max_distance`, because the C++ writer leaves out fields equal to their
+/// default and saved sources must keep loading with their stored values.
+/// Start new components from this instead of `Default::default()`.
+pub fn new_audio_source_args<'a>() -> AudioSourceComponentDataArgs<'a> {
+    AudioSourceComponentDataArgs {
+        volume: 1.0,
+        pitch: 1.0,
+        max_distance: 100.0,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use flatbuffers::FlatBufferBuilder;
+
+    #[test]
+    fn new_sources_are_audible_and_saved_zeros_stay_zero() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = AudioSourceComponentData::create(&mut fbb, &new_audio_source_args());
+        finish_audio_source_component_data_buffer(&mut fbb, offset);
+        let audio = root_as_audio_source_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(audio.volume(), 1.0);
+        assert_eq!(audio.pitch(), 1.0);
+        assert_eq!(audio.max_distance(), 100.0);
+
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = AudioSourceComponentData::create(
+            &mut fbb,
+            &AudioSourceComponentDataArgs {
+                volume: 0.0,
+                ..new_audio_source_args()
+            },
+        );
+        finish_audio_source_component_data_buffer(&mut fbb, offset);
+        let muted = root_as_audio_source_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(muted.volume(), 0.0);
+    }
+}
@@ -29,7 +29,8 @@ struct AudioSourceComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers:
     VT_SPATIAL_BLEND = 12,
     VT_MIN_DISTANCE = 14,
     VT_MAX_DISTANCE = 16,
-    VT_PLAYING = 18
+    VT_PLAYING = 18,
+    VT_PLAY_ON_SPAWN = 20
   };
   const ::flatbuffers::String *audio_clip_path() const {
     return GetPointer<const ::flatbuffers::String *>(VT_AUDIO_CLIP_PATH);
@@ -55,6 +56,10 @@ struct AudioSourceComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers:
   bool playing() const {
     return GetField<uint8_t>(VT_PLAYING, 0) != 0;
   }
+  /// Start playing as soon as the entity is spawned.
+  bool play_on_spawn() const {
+    return GetField<uint8_t>(VT_PLAY_ON_SPAWN, 0) != 0;
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyOffset(verifier, VT_AUDIO_CLIP_PATH) &&
@@ -66,6 +71,7 @@ struct AudioSourceComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers:
            VerifyField<float>(verifier, VT_MIN_DISTANCE, 4) &&
            VerifyField<float>(verifier, VT_MAX_DISTANCE, 4) &&
            VerifyField<uint8_t>(verifier, VT_PLAYING, 1) &&
+           VerifyField<uint8_t>(verifier, VT_PLAY_ON_SPAWN, 1) &&
            verifier.EndTable();
   }
 };
@@ -98,6 +104,9 @@ struct AudioSourceComponentDataBuilder {
   void add_playing(bool playing) {
     fbb_.AddElement<uint8_t>(AudioSourceComponentData::VT_PLAYING, static_cast<uint8_t>(playing), 0);
   }
+  void add_play_on_spawn(bool play_on_spawn) {
+    fbb_.AddElement<uint8_t>(AudioSourceComponentData::VT_PLAY_ON_SPAWN, static_cast<uint8_t>(play_on_spawn), 0);
+  }
   explicit AudioSourceComponentDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -118,7 +127,8 @@ inline ::flatbuffers::Offset<AudioSourceComponentData> CreateAudioSourceComponen
     float spatial_blend = 0.0f,
     float min_distance = 0.0f,
     float max_distance = 0.0f,
-    bool playing = false) {
+    bool playing = false,
+    bool play_on_spawn = false) {
   AudioSourceComponentDataBuilder builder_(_fbb);
   builder_.add_max_distance(max_distance);
   builder_.add_min_distance(min_distance);
@@ -126,6 +136,7 @@ inline ::flatbuffers::Offset<AudioSourceComponentData> CreateAudioSourceComponen
   builder_.add_pitch(pitch);
   builder_.add_volume(volume);
   builder_.add_audio_clip_path(audio_clip_path);
+  builder_.add_play_on_spawn(play_on_spawn);
   builder_.add_playing(playing);
   builder_.add_loop(loop);
   return builder_.Finish();
@@ -140,7 +151,8 @@ inline ::flatbuffers::Offset<AudioSourceComponentData> CreateAudioSourceComponen
     float spatial_blend = 0.0f,
     float min_distance = 0.0f,
     float max_distance = 0.0f,
-    bool playing = false) {
+    bool playing = false,
+    bool play_on_spawn = false) {
   auto audio_clip_path__ = audio_clip_path ? _fbb.CreateString(audio_clip_path) : 0;
   return PixelCraft::ECS::CreateAudioSourceComponentData(
       _fbb,
@@ -151,7 +163,8 @@ inline ::flatbuffers::Offset<AudioSourceComponentData> CreateAudioSourceComponen
       spatial_blend,
       min_distance,
       max_distance,
-      playing);
+      playing,
+      play_on_spawn);
 }
 
 inline const PixelCraft::ECS::AudioSourceComponentData *GetAudioSourceComponentData(const void *buf) {
@@ -50,6 +50,7 @@ impl<'a> AudioSourceComponentData<'a> {
   pub const VT_MIN_DISTANCE: flatbuffers::VOffsetT = 14;
   pub const VT_MAX_DISTANCE: flatbuffers::VOffsetT = 16;
   pub const VT_PLAYING: flatbuffers::VOffsetT = 18;
+  pub const VT_PLAY_ON_SPAWN: flatbuffers::VOffsetT = 20;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -67,6 +68,7 @@ impl<'a> AudioSourceComponentData<'a> {
     builder.add_pitch(args.pitch);
     builder.add_volume(args.volume);
     if let Some(x) = args.audio_clip_path { builder.add_audio_clip_path(x); }
+    builder.add_play_on_spawn(args.play_on_spawn);
     builder.add_playing(args.playing);
     builder.add_loop_(args.loop_);
     builder.finish()
@@ -129,6 +131,14 @@ impl<'a> AudioSourceComponentData<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<bool>(AudioSourceComponentData::VT_PLAYING, Some(false)).unwrap()}
   }
+  /// Start playing as soon as the entity is spawned.
+  #[inline]
+  pub fn play_on_spawn(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(AudioSourceComponentData::VT_PLAY_ON_SPAWN, Some(false)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for AudioSourceComponentData<'_> {
@@ -146,6 +156,7 @@ impl flatbuffers::Verifiable for AudioSourceComponentData<'_> {
      .visit_field::<f32>("min_distance", Self::VT_MIN_DISTANCE, false)?
      .visit_field::<f32>("max_distance", Self::VT_MAX_DISTANCE, false)?
      .visit_field::<bool>("playing", Self::VT_PLAYING, false)?
+     .visit_field::<bool>("play_on_spawn", Self::VT_PLAY_ON_SPAWN, false)?
      .finish();
     Ok(())
   }
@@ -159,6 +170,7 @@ pub struct AudioSourceComponentDataArgs<'a> {
     pub min_distance: f32,
     pub max_distance: f32,
     pub playing: bool,
+    pub play_on_spawn: bool,
 }
 impl<'a> Default for AudioSourceComponentDataArgs<'a> {
   #[inline]
@@ -172,6 +184,7 @@ impl<'a> Default for AudioSourceComponentDataArgs<'a> {
       min_distance: 0.0,
       max_distance: 0.0,
       playing: false,
+      play_on_spawn: false,
     }
   }
 }
@@ -214,6 +227,10 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AudioSourceComponentDataBuilder
     self.fbb_.push_slot::<bool>(AudioSourceComponentData::VT_PLAYING, playing, false);
   }
   #[inline]
+  pub fn add_play_on_spawn(&mut self, play_on_spawn: bool) {
+    self.fbb_.push_slot::<bool>(AudioSourceComponentData::VT_PLAY_ON_SPAWN, play_on_spawn, false);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AudioSourceComponentDataBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     AudioSourceComponentDataBuilder {
@@ -239,6 +256,7 @@ impl core::fmt::Debug for AudioSourceComponentData<'_> {
       ds.field("min_distance", &self.min_distance());
       ds.field("max_distance", &self.max_distance());
       ds.field("playing", &self.playing());
+      ds.field("play_on_spawn", &self.play_on_spawn());
       ds.finish()
   }
 }
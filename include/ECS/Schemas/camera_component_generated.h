@@ -13,6 +13,8 @@ static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
               FLATBUFFERS_VERSION_REVISION == 10,
              "Non-compatible flatbuffers version included");
 
+#include "common_types_generated.h"
+
 namespace PixelCraft {
 namespace ECS {
 
@@ -58,7 +60,8 @@ struct CameraComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Tabl
     VT_NEAR_PLANE = 10,
     VT_FAR_PLANE = 12,
     VT_ASPECT_RATIO = 14,
-    VT_IS_MAIN = 16
+    VT_IS_MAIN = 16,
+    VT_VIEWPORT = 18
   };
   PixelCraft::ECS::CameraType camera_type() const {
     return static_cast<PixelCraft::ECS::CameraType>(GetField<int8_t>(VT_CAMERA_TYPE, 0));
@@ -81,6 +84,11 @@ struct CameraComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Tabl
   bool is_main() const {
     return GetField<uint8_t>(VT_IS_MAIN, 0) != 0;
   }
+  /// Normalized screen rectangle the camera renders to: x, y, width, height.
+  /// Absent means the whole screen.
+  const PixelCraft::ECS::Vec4 *viewport() const {
+    return GetStruct<const PixelCraft::ECS::Vec4 *>(VT_VIEWPORT);
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyField<int8_t>(verifier, VT_CAMERA_TYPE, 1) &&
@@ -90,6 +98,7 @@ struct CameraComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Tabl
            VerifyField<float>(verifier, VT_FAR_PLANE, 4) &&
            VerifyField<float>(verifier, VT_ASPECT_RATIO, 4) &&
            VerifyField<uint8_t>(verifier, VT_IS_MAIN, 1) &&
+           VerifyField<PixelCraft::ECS::Vec4>(verifier, VT_VIEWPORT, 4) &&
            verifier.EndTable();
   }
 };
@@ -119,6 +128,9 @@ struct CameraComponentDataBuilder {
   void add_is_main(bool is_main) {
     fbb_.AddElement<uint8_t>(CameraComponentData::VT_IS_MAIN, static_cast<uint8_t>(is_main), 0);
   }
+  void add_viewport(const PixelCraft::ECS::Vec4 *viewport) {
+    fbb_.AddStruct(CameraComponentData::VT_VIEWPORT, viewport);
+  }
   explicit CameraComponentDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -138,8 +150,10 @@ inline ::flatbuffers::Offset<CameraComponentData> CreateCameraComponentData(
     float near_plane = 0.0f,
     float far_plane = 0.0f,
     float aspect_ratio = 0.0f,
-    bool is_main = false) {
+    bool is_main = false,
+    const PixelCraft::ECS::Vec4 *viewport = nullptr) {
   CameraComponentDataBuilder builder_(_fbb);
+  builder_.add_viewport(viewport);
   builder_.add_aspect_ratio(aspect_ratio);
   builder_.add_far_plane(far_plane);
   builder_.add_near_plane(near_plane);
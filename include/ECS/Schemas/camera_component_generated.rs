@@ -3,6 +3,7 @@
 
 // @generated
 
+use crate::common_types_generated::*;
 use core::mem;
 use core::cmp::Ordering;
 
@@ -12,6 +13,7 @@ use self::flatbuffers::{EndianScalar, Follow};
 #[allow(unused_imports, dead_code)]
 pub mod pixel_craft {
 
+  use crate::common_types_generated::*;
   use core::mem;
   use core::cmp::Ordering;
 
@@ -20,6 +22,7 @@ pub mod pixel_craft {
 #[allow(unused_imports, dead_code)]
 pub mod ecs {
 
+  use crate::common_types_generated::*;
   use core::mem;
   use core::cmp::Ordering;
 
@@ -134,6 +137,7 @@ impl<'a> CameraComponentData<'a> {
   pub const VT_FAR_PLANE: flatbuffers::VOffsetT = 12;
   pub const VT_ASPECT_RATIO: flatbuffers::VOffsetT = 14;
   pub const VT_IS_MAIN: flatbuffers::VOffsetT = 16;
+  pub const VT_VIEWPORT: flatbuffers::VOffsetT = 18;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -142,9 +146,10 @@ impl<'a> CameraComponentData<'a> {
   #[allow(unused_mut)]
   pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
     _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-    args: &'args CameraComponentDataArgs
+    args: &'args CameraComponentDataArgs<'args>
   ) -> flatbuffers::WIPOffset<CameraComponentData<'bldr>> {
     let mut builder = CameraComponentDataBuilder::new(_fbb);
+    if let Some(x) = args.viewport { builder.add_viewport(x); }
     builder.add_aspect_ratio(args.aspect_ratio);
     builder.add_far_plane(args.far_plane);
     builder.add_near_plane(args.near_plane);
@@ -205,6 +210,15 @@ impl<'a> CameraComponentData<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<bool>(CameraComponentData::VT_IS_MAIN, Some(false)).unwrap()}
   }
+  /// Normalized screen rectangle the camera renders to: x, y, width, height.
+  /// Absent means the whole screen.
+  #[inline]
+  pub fn viewport(&self) -> Option<&'a Vec4> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec4>(CameraComponentData::VT_VIEWPORT, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for CameraComponentData<'_> {
@@ -221,11 +235,12 @@ impl flatbuffers::Verifiable for CameraComponentData<'_> {
      .visit_field::<f32>("far_plane", Self::VT_FAR_PLANE, false)?
      .visit_field::<f32>("aspect_ratio", Self::VT_ASPECT_RATIO, false)?
      .visit_field::<bool>("is_main", Self::VT_IS_MAIN, false)?
+     .visit_field::<Vec4>("viewport", Self::VT_VIEWPORT, false)?
      .finish();
     Ok(())
   }
 }
-pub struct CameraComponentDataArgs {
+pub struct CameraComponentDataArgs<'a> {
     pub camera_type: CameraType,
     pub fov: f32,
     pub ortho_size: f32,
@@ -233,8 +248,9 @@ pub struct CameraComponentDataArgs {
     pub far_plane: f32,
     pub aspect_ratio: f32,
     pub is_main: bool,
+    pub viewport: Option<&'a Vec4>,
 }
-impl<'a> Default for CameraComponentDataArgs {
+impl<'a> Default for CameraComponentDataArgs<'a> {
   #[inline]
   fn default() -> Self {
     CameraComponentDataArgs {
@@ -245,6 +261,7 @@ impl<'a> Default for CameraComponentDataArgs {
       far_plane: 0.0,
       aspect_ratio: 0.0,
       is_main: false,
+      viewport: None,
     }
   }
 }
@@ -283,6 +300,10 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CameraComponentDataBuilder<'a,
     self.fbb_.push_slot::<bool>(CameraComponentData::VT_IS_MAIN, is_main, false);
   }
   #[inline]
+  pub fn add_viewport(&mut self, viewport: &Vec4) {
+    self.fbb_.push_slot_always::<&Vec4>(CameraComponentData::VT_VIEWPORT, viewport);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CameraComponentDataBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     CameraComponentDataBuilder {
@@ -307,6 +328,7 @@ impl core::fmt::Debug for CameraComponentData<'_> {
       ds.field("far_plane", &self.far_plane());
       ds.field("aspect_ratio", &self.aspect_ratio());
       ds.field("is_main", &self.is_main());
+      ds.field("viewport", &self.viewport());
       ds.finish()
   }
 }
@@ -0,0 +1,389 @@
+// Batch reader over a `ColliderComponentArrayData` buffer.
+
+use crate::collider_component_array_generated::*;
+use crate::collider_component_generated::*;
+use crate::collider_geometry::*;
+use crate::common_types_generated::*;
+
+use flatbuffers::{
+    ForwardsUOffset, InvalidFlatbuffer, Vector, VectorIter, Verifiable, Verifier, VerifierOptions,
+};
+
+/// Read-only view over every collider stored in one `ColliderComponentArrayData`.
+///
+/// The buffer is verified once, up front, when the view is created; `get` and
+/// `iter` then read elements in place without further verification or
+/// allocation.
+///
+/// Verification dominates the cost, and one pass over the array is not cheaper
+/// per element than `root_as_collider_component_data` on separate buffers.
+/// With 10 000 sphere colliders in a release build, the test
+/// `bench_array_vs_separate_buffers` measured 230-290 ns per collider for
+/// `from_buffer` plus one pass, against 210-250 ns for separate buffers. What
+/// the view saves is repeated work: reading from a kept view took 5-8 ns per
+/// collider.
+#[derive(Clone, Copy, Debug)]
+pub struct ColliderComponentArray<'a> {
+    colliders: Vector<'a, ForwardsUOffset<ColliderComponentData<'a>>>,
+}
+
+impl<'a> ColliderComponentArray<'a> {
+    /// Verifies `buf` as a `ColliderComponentArrayData` and wraps its colliders.
+    pub fn from_buffer(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_collider_component_array_data(buf).map(Self::new)
+    }
+
+    /// Wraps an already verified table. A missing `colliders` vector reads as empty.
+    pub fn new(data: ColliderComponentArrayData<'a>) -> Self {
+        ColliderComponentArray {
+            colliders: data.colliders().unwrap_or_default(),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.colliders.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.colliders.is_empty()
+    }
+
+    /// Returns the collider at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<ColliderComponentData<'a>> {
+        (index < self.len()).then(|| self.colliders.get(index))
+    }
+
+    #[inline]
+    pub fn iter(&self) -> VectorIter<'a, ForwardsUOffset<ColliderComponentData<'a>>> {
+        self.colliders.iter()
+    }
+
+    /// Lazily yields only the colliders of type `t`, in order, without allocating.
+    pub fn iter_of_type(&self, t: ColliderType) -> impl Iterator<Item = ColliderComponentData<'a>> {
+        self.iter()
+            .filter(move |collider| collider.collider_type() == t)
+    }
+}
+
+/// Splits the array's indices into `(triggers, solids)` in one pass, reading
+/// only `is_trigger` from each collider.
+pub fn partition_triggers(array: &ColliderComponentArray<'_>) -> (Vec<usize>, Vec<usize>) {
+    let mut triggers = Vec::new();
+    let mut solids = Vec::new();
+    for (index, collider) in array.iter().enumerate() {
+        if collider.is_trigger() {
+            triggers.push(index);
+        } else {
+            solids.push(index);
+        }
+    }
+    (triggers, solids)
+}
+
+/// Splits the array's indices into `(statics, dynamics)` by `is_static`, for
+/// building the broadphase's static and dynamic trees.
+pub fn partition_static_dynamic(array: &ColliderComponentArray<'_>) -> (Vec<usize>, Vec<usize>) {
+    let mut statics = Vec::new();
+    let mut dynamics = Vec::new();
+    for (index, collider) in array.iter().enumerate() {
+        if collider.is_static() {
+            statics.push(index);
+        } else {
+            dynamics.push(index);
+        }
+    }
+    (statics, dynamics)
+}
+
+/// Local-space bounds enclosing every collider in the array, as `(min, max)`.
+///
+/// Folds `collider_local_aabb` over the colliders, so each collider's `center`
+/// is included but no transform is applied. Colliders without local bounds
+/// (meshes, boxes without a size, unknown types) are skipped; returns `None`
+/// if none has bounds.
+pub fn colliders_bounding_aabb(array: &ColliderComponentArray<'_>) -> Option<(Vec3, Vec3)> {
+    array
+        .iter()
+        .filter_map(|collider| collider_local_aabb(&collider))
+        .reduce(|(min_a, max_a), (min_b, max_b)| {
+            (
+                Vec3::new(
+                    min_a.x().min(min_b.x()),
+                    min_a.y().min(min_b.y()),
+                    min_a.z().min(min_b.z()),
+                ),
+                Vec3::new(
+                    max_a.x().max(max_b.x()),
+                    max_a.y().max(max_b.y()),
+                    max_a.z().max(max_b.z()),
+                ),
+            )
+        })
+}
+
+/// Verifies each collider in a `ColliderComponentArrayData` buffer separately.
+///
+/// The outer table and the `colliders` offset vector are checked first; if they
+/// are broken nothing can be located and that error is returned. Otherwise the
+/// result has one entry per collider, in order, so a loader can skip or report
+/// the bad indices instead of rejecting the whole file. Each element gets a
+/// fresh verifier, so one large element cannot use up another's table budget.
+pub fn verify_collider_array(
+    array_bytes: &[u8],
+) -> Result<Vec<Result<(), InvalidFlatbuffer>>, InvalidFlatbuffer> {
+    let opts = VerifierOptions::default();
+    let mut v = Verifier::new(&opts, array_bytes);
+    let root = v.get_uoffset(0)? as usize;
+    let mut table = v.visit_table(root)?;
+    let Some(field_pos) = table.deref(ColliderComponentArrayData::VT_COLLIDERS)? else {
+        return Ok(Vec::new());
+    };
+    // Viewed as raw `u32`s the offsets are checked for bounds and alignment
+    // without following them into the colliders.
+    <ForwardsUOffset<Vector<u32>>>::run_verifier(table.verifier(), field_pos)?;
+    let vector_pos = field_pos + v.get_uoffset(field_pos)? as usize;
+    let len = v.get_uoffset(vector_pos)? as usize;
+
+    Ok((0..len)
+        .map(|i| {
+            let element_pos = vector_pos + 4 + 4 * i;
+            let mut element_verifier = Verifier::new(&opts, array_bytes);
+            <ForwardsUOffset<ColliderComponentData>>::run_verifier(
+                &mut element_verifier,
+                element_pos,
+            )
+        })
+        .collect())
+}
+
+impl<'a> IntoIterator for ColliderComponentArray<'a> {
+    type Item = ColliderComponentData<'a>;
+    type IntoIter = VectorIter<'a, ForwardsUOffset<ColliderComponentData<'a>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+    use flatbuffers::FlatBufferBuilder;
+    use std::hint::black_box;
+    use std::time::Instant;
+
+    fn array_buffer(colliders: &[ColliderComponent]) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let offsets: Vec<_> = colliders.iter().map(|c| c.serialize(&mut fbb)).collect();
+        let colliders = fbb.create_vector(&offsets);
+        let root = ColliderComponentArrayData::create(
+            &mut fbb,
+            &ColliderComponentArrayDataArgs {
+                colliders: Some(colliders),
+            },
+        );
+        finish_collider_component_array_data_buffer(&mut fbb, root);
+        fbb.finished_data().to_vec()
+    }
+
+    fn sphere(radius: f32) -> ColliderComponent {
+        ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reads_a_thousand_colliders_in_order() {
+        let colliders: Vec<_> = (0..1000).map(|i| sphere(i as f32)).collect();
+        let buf = array_buffer(&colliders);
+        let array = ColliderComponentArray::from_buffer(&buf).unwrap();
+        assert_eq!(array.len(), 1000);
+        assert_eq!(array.get(999).unwrap().radius(), 999.0);
+        assert!(array.get(1000).is_none());
+        for (i, collider) in array.iter().enumerate() {
+            assert_eq!(collider.radius(), i as f32);
+        }
+    }
+
+    #[test]
+    fn missing_colliders_vector_reads_as_empty() {
+        let mut fbb = FlatBufferBuilder::new();
+        let root = ColliderComponentArrayData::create(&mut fbb, &Default::default());
+        finish_collider_component_array_data_buffer(&mut fbb, root);
+        let array = ColliderComponentArray::from_buffer(fbb.finished_data()).unwrap();
+        assert!(array.is_empty());
+        assert!(array.get(0).is_none());
+    }
+
+    #[test]
+    fn partition_triggers_splits_indices_in_order() {
+        let colliders: Vec<_> = [true, false, false, true]
+            .into_iter()
+            .map(|is_trigger| ColliderComponent {
+                is_trigger,
+                ..Default::default()
+            })
+            .collect();
+        let buf = array_buffer(&colliders);
+        let array = ColliderComponentArray::from_buffer(&buf).unwrap();
+        assert_eq!(partition_triggers(&array), (vec![0, 3], vec![1, 2]));
+    }
+
+    #[test]
+    fn iter_of_type_yields_only_matching_colliders() {
+        let types = [
+            ColliderType::Box,
+            ColliderType::Sphere,
+            ColliderType::Mesh,
+            ColliderType::Sphere,
+        ];
+        let colliders: Vec<_> = types
+            .into_iter()
+            .enumerate()
+            .map(|(i, collider_type)| ColliderComponent {
+                collider_type,
+                radius: i as f32,
+                ..Default::default()
+            })
+            .collect();
+        let buf = array_buffer(&colliders);
+        let array = ColliderComponentArray::from_buffer(&buf).unwrap();
+        let radii: Vec<_> = array
+            .iter_of_type(ColliderType::Sphere)
+            .map(|collider| collider.radius())
+            .collect();
+        assert_eq!(radii, [1.0, 3.0]);
+        assert_eq!(array.iter_of_type(ColliderType::Capsule).count(), 0);
+    }
+
+    #[test]
+    fn verify_collider_array_isolates_a_corrupt_element() {
+        let colliders: Vec<_> = ["a", "b", "c"]
+            .into_iter()
+            .map(|name| ColliderComponent {
+                material_name: Some(name.to_owned()),
+                ..Default::default()
+            })
+            .collect();
+        let mut buf = array_buffer(&colliders);
+        let results = verify_collider_array(&buf).unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(Result::is_ok));
+
+        // Give the second collider's "b" string a length running past the buffer.
+        let string_pos = buf
+            .windows(6)
+            .position(|w| w == [1, 0, 0, 0, b'b', 0])
+            .unwrap();
+        buf[string_pos..string_pos + 4].copy_from_slice(&0x00FF_FFFFu32.to_le_bytes());
+        let results = verify_collider_array(&buf).unwrap();
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+        assert!(ColliderComponentArray::from_buffer(&buf).is_err());
+    }
+
+    #[test]
+    fn verify_collider_array_rejects_a_broken_outer_table() {
+        assert!(verify_collider_array(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn bounding_aabb_skips_meshes() {
+        let buf = array_buffer(&[
+            ColliderComponent {
+                size: Some(Vec3::new(2.0, 2.0, 2.0)),
+                ..Default::default()
+            },
+            ColliderComponent {
+                collider_type: ColliderType::Mesh,
+                mesh_path: Some("big.obj".into()),
+                ..Default::default()
+            },
+            ColliderComponent {
+                center: Some(Vec3::new(5.0, 0.0, 0.0)),
+                ..sphere(1.0)
+            },
+        ]);
+        let array = ColliderComponentArray::from_buffer(&buf).unwrap();
+        assert_eq!(
+            colliders_bounding_aabb(&array),
+            Some((Vec3::new(-1.0, -1.0, -1.0), Vec3::new(6.0, 1.0, 1.0)))
+        );
+
+        let buf = array_buffer(&[ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            ..Default::default()
+        }]);
+        let array = ColliderComponentArray::from_buffer(&buf).unwrap();
+        assert_eq!(colliders_bounding_aabb(&array), None);
+    }
+
+    #[test]
+    fn partition_splits_static_from_dynamic_indices() {
+        let floor = ColliderComponent {
+            is_static: true,
+            size: Some(Vec3::new(1.0, 1.0, 1.0)),
+            ..Default::default()
+        };
+        let buf = array_buffer(&[
+            floor.clone(),
+            ColliderComponent::default(),
+            floor,
+            sphere(0.5),
+        ]);
+        let array = ColliderComponentArray::from_buffer(&buf).unwrap();
+        assert_eq!(partition_static_dynamic(&array), (vec![0, 2], vec![1, 3]));
+    }
+
+    /// Compares loading 10 000 colliders from one array buffer against loading
+    /// each from its own buffer. Run with
+    /// `cargo test --release -- --ignored --nocapture bench_array_vs_separate_buffers`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn bench_array_vs_separate_buffers() {
+        const COUNT: usize = 10_000;
+        const ROUNDS: u32 = 200;
+        let colliders: Vec<_> = (0..COUNT).map(|i| sphere(i as f32)).collect();
+        let array = array_buffer(&colliders);
+        let separate: Vec<_> = colliders.iter().map(make_collider_buffer).collect();
+
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            let view = ColliderComponentArray::from_buffer(black_box(&array)).unwrap();
+            let total: f32 = view.iter().map(|c| c.radius()).sum();
+            black_box(total);
+        }
+        let batched = start.elapsed().as_nanos() as f64 / (ROUNDS as f64 * COUNT as f64);
+
+        let view = ColliderComponentArray::from_buffer(&array).unwrap();
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            let total: f32 = black_box(view).iter().map(|c| c.radius()).sum();
+            black_box(total);
+        }
+        let read_only = start.elapsed().as_nanos() as f64 / (ROUNDS as f64 * COUNT as f64);
+
+        let start = Instant::now();
+        for _ in 0..ROUNDS {
+            let total: f32 = black_box(&separate)
+                .iter()
+                .map(|buf| root_as_collider_component_data(buf).unwrap().radius())
+                .sum();
+            black_box(total);
+        }
+        let per_buffer = start.elapsed().as_nanos() as f64 / (ROUNDS as f64 * COUNT as f64);
+
+        println!(
+            "array: {batched:.1} ns/collider ({read_only:.1} after verification), \
+         separate buffers: {per_buffer:.1} ns/collider"
+        );
+    }
+}
@@ -0,0 +1,918 @@
+// Convenience constructors for building `ColliderComponentData` tables.
+
+use crate::collider_component::*;
+use crate::collider_component_generated::*;
+use crate::collider_validation::*;
+use crate::common_types_generated::*;
+
+use std::collections::HashMap;
+
+use flatbuffers::{
+    Allocator, DefaultAllocator, FlatBufferBuilder, Table, WIPOffset, SIZE_SOFFSET, SIZE_UOFFSET,
+    SIZE_VOFFSET,
+};
+
+impl<'a: 'b, 'b, A: Allocator + 'a> ColliderComponentDataBuilder<'a, 'b, A> {
+    /// Sets `size` from its components, without a `Vec3` to borrow.
+    #[inline]
+    pub fn add_size_xyz(&mut self, x: f32, y: f32, z: f32) {
+        self.add_size(&Vec3::new(x, y, z));
+    }
+}
+
+/// Default limit for `create_mesh_path_checked`, in bytes.
+pub const MAX_MESH_PATH_LEN: usize = 4096;
+
+/// Error from the checked builder helpers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// A `mesh_path` is longer than the allowed maximum.
+    MeshPathTooLong { len: usize, max: usize },
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MeshPathTooLong { len, max } => {
+                write!(f, "mesh_path of {len} bytes exceeds the {max} byte limit")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Writes `path` for use as a `mesh_path`, unless it is longer than `max_len`
+/// bytes (normally `MAX_MESH_PATH_LEN`).
+///
+/// Generated asset names can produce pathologically long paths that bloat
+/// the buffer and break on platforms with path length limits; this rejects
+/// them before anything is pushed. Strings have to be created before the
+/// table is started, so pass the result to `add_mesh_path` afterwards.
+pub fn create_mesh_path_checked<'fbb, A: Allocator + 'fbb>(
+    fbb: &mut FlatBufferBuilder<'fbb, A>,
+    path: &str,
+    max_len: usize,
+) -> Result<WIPOffset<&'fbb str>, BuildError> {
+    if path.len() > max_len {
+        return Err(BuildError::MeshPathTooLong {
+            len: path.len(),
+            max: max_len,
+        });
+    }
+    Ok(fbb.create_string(path))
+}
+
+/// Builds a box collider. `size` stores full extents, so it is written as
+/// twice `half_extents`.
+pub fn build_box_collider<'bldr, A: Allocator + 'bldr>(
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+    half_extents: &Vec3,
+    is_trigger: bool,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    let mut builder = ColliderComponentDataBuilder::new(fbb);
+    builder.add_schema_version(CURRENT_COLLIDER_SCHEMA_VERSION);
+    builder.add_size_xyz(
+        half_extents.x() * 2.0,
+        half_extents.y() * 2.0,
+        half_extents.z() * 2.0,
+    );
+    builder.add_is_trigger(is_trigger);
+    builder.add_collider_type(ColliderType::Box);
+    builder.finish()
+}
+
+/// Builds a sphere collider; only `radius` is set.
+pub fn build_sphere_collider<'bldr, A: Allocator + 'bldr>(
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+    radius: f32,
+    is_trigger: bool,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    let mut builder = ColliderComponentDataBuilder::new(fbb);
+    builder.add_schema_version(CURRENT_COLLIDER_SCHEMA_VERSION);
+    builder.add_radius(radius);
+    builder.add_is_trigger(is_trigger);
+    builder.add_collider_type(ColliderType::Sphere);
+    builder.finish()
+}
+
+/// Builds a capsule collider; only `radius` and `height` are set.
+pub fn build_capsule_collider<'bldr, A: Allocator + 'bldr>(
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+    radius: f32,
+    height: f32,
+    is_trigger: bool,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    let mut builder = ColliderComponentDataBuilder::new(fbb);
+    builder.add_schema_version(CURRENT_COLLIDER_SCHEMA_VERSION);
+    builder.add_height(height);
+    builder.add_radius(radius);
+    builder.add_is_trigger(is_trigger);
+    builder.add_collider_type(ColliderType::Capsule);
+    builder.finish()
+}
+
+/// Builds a mesh collider; only `mesh_path` is set.
+pub fn build_mesh_collider<'bldr, A: Allocator + 'bldr>(
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+    mesh_path: &str,
+    is_trigger: bool,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    let mesh_path = fbb.create_string(mesh_path);
+    let mut builder = ColliderComponentDataBuilder::new(fbb);
+    builder.add_schema_version(CURRENT_COLLIDER_SCHEMA_VERSION);
+    builder.add_mesh_path(mesh_path);
+    builder.add_is_trigger(is_trigger);
+    builder.add_collider_type(ColliderType::Mesh);
+    builder.finish()
+}
+
+/// Writes `collider` leaving out every scalar field equal to its schema default.
+///
+/// Readers fall back to the default for a missing field, so the values read
+/// back are identical while the table and its vtable shrink. The generated
+/// `add_*` methods already skip defaults unless `force_defaults(true)` was set
+/// on the builder; this switches forcing off on `fbb` (it stays off), so the
+/// output is minimal however the builder was configured. Optional struct and
+/// string fields are written only when present, as always.
+pub fn build_collider_minimal<'bldr, A: Allocator + 'bldr>(
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+    collider: &ColliderComponent,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    fbb.force_defaults(false);
+    collider.serialize(fbb)
+}
+
+/// Serializes `collider` into a standalone, finished buffer with the `"CLDR"`
+/// identifier, ready for `root_as_collider_component_data` or writing to disk.
+///
+/// Takes the owned component rather than `ColliderComponentDataArgs`, whose
+/// string offsets would have to come from the builder created in here.
+pub fn make_collider_buffer(collider: &ColliderComponent) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let offset = collider.serialize(&mut fbb);
+    finish_collider_component_data_buffer(&mut fbb, offset);
+    fbb.finished_data().to_vec()
+}
+
+/// Like `make_collider_buffer`, but with a `u32` length prefix for streaming.
+pub fn make_collider_buffer_size_prefixed(collider: &ColliderComponent) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let offset = collider.serialize(&mut fbb);
+    finish_size_prefixed_collider_component_data_buffer(&mut fbb, offset);
+    fbb.finished_data().to_vec()
+}
+
+/// Upper bound on the length of `make_collider_buffer(collider)` (or the
+/// size-prefixed form), for pre-sizing packets and save buffers without
+/// building anything.
+///
+/// Counts the header, a vtable with a slot for every field, every scalar as if
+/// present and padded to 4 bytes, the struct fields that are set, and each
+/// string with its length prefix, terminator and padding. The real buffer is
+/// usually smaller because default scalars are skipped. Update the field
+/// counts here when the schema grows.
+pub fn estimate_collider_size(collider: &ColliderComponent) -> usize {
+    const SCALAR_FIELDS: usize = 18;
+    const STRING_FIELDS: usize = 4;
+    // Root offset, file identifier and size prefix, then worst-case padding
+    // before the vtable and before the table.
+    let header = 3 * SIZE_UOFFSET + 2 * 3;
+    let vtable = ColliderComponentData::VT_TRIGGER_DEBOUNCE_SECONDS as usize + SIZE_VOFFSET;
+    let table = SIZE_SOFFSET + (SCALAR_FIELDS + STRING_FIELDS) * 4;
+    let structs = collider.size.map_or(0, |_| size_of::<Vec3>())
+        + collider.center.map_or(0, |_| size_of::<Vec3>())
+        + collider.size_2d.map_or(0, |_| size_of::<Vec2>())
+        + collider.debug_color.map_or(0, |_| size_of::<Color>());
+    let strings: usize = [
+        &collider.material_name,
+        &collider.mesh_path,
+        &collider.trigger_channel,
+        &collider.surface_type,
+    ]
+    .iter()
+    .filter_map(|s| s.as_deref())
+    .map(|s| SIZE_UOFFSET + (s.len() + 1).next_multiple_of(4))
+    .sum();
+    header + vtable + table + structs + strings
+}
+
+/// A `FlatBufferBuilder` that writes each distinct string once.
+///
+/// `get_or_create` returns the offset of an identical string written earlier
+/// through this cache, so colliders that share a material or mesh reference
+/// one copy. Strings written directly on `builder()` are not cached. Use
+/// `reset` rather than resetting the builder itself, since cached offsets
+/// are meaningless in a new buffer.
+pub struct StringCache<'fbb, A: Allocator + 'fbb = DefaultAllocator> {
+    fbb: FlatBufferBuilder<'fbb, A>,
+    strings: HashMap<String, WIPOffset<&'fbb str>>,
+}
+
+impl StringCache<'_> {
+    pub fn new() -> Self {
+        Self::from_builder(FlatBufferBuilder::new())
+    }
+}
+
+impl Default for StringCache<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'fbb, A: Allocator + 'fbb> StringCache<'fbb, A> {
+    /// Wraps `fbb`, which should not yet contain strings meant to be shared.
+    pub fn from_builder(fbb: FlatBufferBuilder<'fbb, A>) -> Self {
+        StringCache {
+            fbb,
+            strings: HashMap::new(),
+        }
+    }
+
+    /// The offset of `s`, writing it only the first time it is seen.
+    pub fn get_or_create(&mut self, s: &str) -> WIPOffset<&'fbb str> {
+        if let Some(&offset) = self.strings.get(s) {
+            return offset;
+        }
+        let offset = self.fbb.create_string(s);
+        self.strings.insert(s.to_owned(), offset);
+        offset
+    }
+
+    #[inline]
+    pub fn builder(&mut self) -> &mut FlatBufferBuilder<'fbb, A> {
+        &mut self.fbb
+    }
+
+    /// Resets the builder and forgets every cached string.
+    pub fn reset(&mut self) {
+        self.fbb.reset();
+        self.strings.clear();
+    }
+
+    pub fn into_builder(self) -> FlatBufferBuilder<'fbb, A> {
+        self.fbb
+    }
+}
+
+/// Writes `collider` through `cache`, so its `material_name`, `mesh_path`,
+/// `trigger_channel` and `surface_type` are shared with earlier colliders
+/// using the same strings.
+///
+/// Takes the owned component rather than `ColliderComponentDataArgs`, whose
+/// string offsets would already have been written without the cache.
+pub fn build_collider_cached<'fbb, A: Allocator + 'fbb>(
+    cache: &mut StringCache<'fbb, A>,
+    collider: &ColliderComponent,
+) -> WIPOffset<ColliderComponentData<'fbb>> {
+    let mut cached = |s: &Option<String>| s.as_deref().map(|s| cache.get_or_create(s));
+    let material_name = cached(&collider.material_name);
+    let mesh_path = cached(&collider.mesh_path);
+    let trigger_channel = cached(&collider.trigger_channel);
+    let surface_type = cached(&collider.surface_type);
+    let args = collider.to_args(material_name, mesh_path, trigger_channel, surface_type);
+    ColliderComponentData::create(cache.builder(), &args)
+}
+
+/// Copies `data` out of a loaded buffer into `fbb`, e.g. when merging scenes.
+///
+/// Every field is copied, strings included, and `schema_version` is kept as
+/// it was rather than restamped, so the copy reads back exactly like the
+/// original. Fields unknown to this schema are not carried over.
+pub fn clone_collider<'bldr, A: Allocator + 'bldr>(
+    data: &ColliderComponentData,
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    let mut string = |s: Option<&str>| s.map(|s| fbb.create_string(s));
+    let material_name = string(data.material_name());
+    let mesh_path = string(data.mesh_path());
+    let trigger_channel = string(data.trigger_channel());
+    let surface_type = string(data.surface_type());
+    let collider = ColliderComponent::from(*data);
+    let args = ColliderComponentDataArgs {
+        schema_version: data.schema_version(),
+        ..collider.to_args(material_name, mesh_path, trigger_channel, surface_type)
+    };
+    ColliderComponentData::create(fbb, &args)
+}
+
+/// Builds a collider table with `build` and validates it before handing it out.
+///
+/// `build` receives the generated `ColliderComponentDataBuilder`, so every
+/// `add_*` method is available; strings must be created beforehand as usual.
+/// Like the other builders here, the table is stamped with
+/// `CURRENT_COLLIDER_SCHEMA_VERSION` first.
+/// Once the table is ended it is read back from the builder and checked with
+/// `validate_collider` and `validate_trigger_fields`, catching e.g. a
+/// zero-radius sphere, a mesh without a path or a trigger with a mass at build
+/// time. On error the rejected table is left unreferenced in
+/// `fbb`; callers normally `reset()` the builder or drop it.
+pub fn try_build_collider<'bldr, A: Allocator + 'bldr>(
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+    build: impl FnOnce(&mut ColliderComponentDataBuilder<'bldr, '_, A>),
+) -> Result<WIPOffset<ColliderComponentData<'bldr>>, ColliderValidationError> {
+    let mut builder = ColliderComponentDataBuilder::new(fbb);
+    builder.add_schema_version(CURRENT_COLLIDER_SCHEMA_VERSION);
+    build(&mut builder);
+    let offset = builder.finish();
+    let collider = unfinished_collider(fbb, offset);
+    validate_collider(&collider)?;
+    validate_trigger_fields(&collider)?;
+    Ok(offset)
+}
+
+/// Reads back a collider table that has been ended but not yet finished.
+fn unfinished_collider<'f, A: Allocator>(
+    fbb: &'f FlatBufferBuilder<'_, A>,
+    offset: WIPOffset<ColliderComponentData<'_>>,
+) -> ColliderComponentData<'f> {
+    let buf = fbb.unfinished_data();
+    // Safety:
+    // `offset` was returned by `end_table` on this builder, so the table, its
+    // vtable and everything it references lie within the bytes written so far.
+    unsafe {
+        ColliderComponentData::init_from_table(Table::new(buf, buf.len() - offset.value() as usize))
+    }
+}
+
+/// Defines a typed builder that fixes `collider_type` to `$ty` and exposes
+/// only the setters shared by every shape; shape-specific setters are added
+/// in a separate `impl` block below. `new` gets the given visibility, so a
+/// shape with a required field can keep it private behind its own
+/// constructor.
+macro_rules! typed_collider_builder {
+    ($(#[$doc:meta])* $name:ident, $ty:expr, $new_vis:vis new) => {
+        $(#[$doc])*
+        pub struct $name<'a: 'b, 'b, A: Allocator + 'a> {
+            builder: ColliderComponentDataBuilder<'a, 'b, A>,
+        }
+
+        impl<'a: 'b, 'b, A: Allocator + 'a> $name<'a, 'b, A> {
+            /// Starts the table, stamped with `CURRENT_COLLIDER_SCHEMA_VERSION`.
+            $new_vis fn new(fbb: &'b mut FlatBufferBuilder<'a, A>) -> Self {
+                let mut builder = ColliderComponentDataBuilder::new(fbb);
+                builder.add_schema_version(CURRENT_COLLIDER_SCHEMA_VERSION);
+                builder.add_collider_type($ty);
+                $name { builder }
+            }
+
+            #[inline]
+            pub fn trigger(mut self, is_trigger: bool) -> Self {
+                self.builder.add_is_trigger(is_trigger);
+                self
+            }
+
+            #[inline]
+            pub fn center(mut self, center: &Vec3) -> Self {
+                self.builder.add_center(center);
+                self
+            }
+
+            #[inline]
+            pub fn layer(mut self, layer: u32) -> Self {
+                self.builder.add_layer(layer);
+                self
+            }
+
+            #[inline]
+            pub fn collision_mask(mut self, collision_mask: u32) -> Self {
+                self.builder.add_collision_mask(collision_mask);
+                self
+            }
+
+            /// Sets `material_name` from a string created on the builder
+            /// before this table was started.
+            #[inline]
+            pub fn material_name(mut self, material_name: WIPOffset<&'b str>) -> Self {
+                self.builder.add_material_name(material_name);
+                self
+            }
+
+            #[inline]
+            pub fn debug_color(mut self, debug_color: &Color) -> Self {
+                self.builder.add_debug_color(debug_color);
+                self
+            }
+
+            #[inline]
+            pub fn build(self) -> WIPOffset<ColliderComponentData<'a>> {
+                self.builder.finish()
+            }
+        }
+    };
+}
+
+typed_collider_builder!(
+    /// Builds a Box collider; see `BoxColliderBuilder::size`.
+    BoxColliderBuilder,
+    ColliderType::Box,
+    pub new
+);
+typed_collider_builder!(
+    /// Builds a Sphere collider, e.g.
+    /// `SphereColliderBuilder::new(fbb).radius(0.5).trigger(true).build()`.
+    SphereColliderBuilder,
+    ColliderType::Sphere,
+    pub new
+);
+typed_collider_builder!(
+    /// Builds a Capsule collider.
+    CapsuleColliderBuilder,
+    ColliderType::Capsule,
+    pub new
+);
+typed_collider_builder!(
+    /// Builds a Y-up Cylinder collider.
+    CylinderColliderBuilder,
+    ColliderType::Cylinder,
+    pub new
+);
+typed_collider_builder!(
+    /// Builds a Y-up Cone collider.
+    ConeColliderBuilder,
+    ColliderType::Cone,
+    pub new
+);
+typed_collider_builder!(
+    /// Builds a Mesh collider. The path is required, so the only constructor
+    /// is `with_path`.
+    MeshColliderBuilder,
+    ColliderType::Mesh,
+    new
+);
+
+impl<'a: 'b, 'b, A: Allocator + 'a> BoxColliderBuilder<'a, 'b, A> {
+    /// Full extents of the box.
+    #[inline]
+    pub fn size(mut self, x: f32, y: f32, z: f32) -> Self {
+        self.builder.add_size_xyz(x, y, z);
+        self
+    }
+}
+
+impl<'a: 'b, 'b, A: Allocator + 'a> SphereColliderBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.builder.add_radius(radius);
+        self
+    }
+}
+
+impl<'a: 'b, 'b, A: Allocator + 'a> CapsuleColliderBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.builder.add_radius(radius);
+        self
+    }
+
+    /// Length of the cylindrical section, without the caps.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.builder.add_height(height);
+        self
+    }
+
+    #[inline]
+    pub fn axis(mut self, axis: CapsuleAxis) -> Self {
+        self.builder.add_capsule_axis(axis);
+        self
+    }
+}
+
+impl<'a: 'b, 'b, A: Allocator + 'a> CylinderColliderBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.builder.add_radius(radius);
+        self
+    }
+
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.builder.add_height(height);
+        self
+    }
+}
+
+impl<'a: 'b, 'b, A: Allocator + 'a> ConeColliderBuilder<'a, 'b, A> {
+    /// Radius of the base.
+    #[inline]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.builder.add_radius(radius);
+        self
+    }
+
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.builder.add_height(height);
+        self
+    }
+}
+
+impl<'a: 'b, 'b, A: Allocator + 'a> MeshColliderBuilder<'a, 'b, A> {
+    /// Writes `mesh_path` and then starts the table, which cannot be done the
+    /// other way round.
+    pub fn with_path(fbb: &'b mut FlatBufferBuilder<'a, A>, mesh_path: &str) -> Self {
+        let mesh_path = fbb.create_string(mesh_path);
+        let mut this = Self::new(fbb);
+        this.builder.add_mesh_path(mesh_path);
+        this
+    }
+
+    #[inline]
+    pub fn convex(mut self, mesh_is_convex: bool) -> Self {
+        self.builder.add_mesh_is_convex(mesh_is_convex);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_array::ColliderComponentArray;
+    use crate::collider_component_array_generated::*;
+
+    fn finished<'a>(
+        fbb: &mut FlatBufferBuilder<'a>,
+        offset: WIPOffset<ColliderComponentData<'a>>,
+    ) -> Vec<u8> {
+        finish_collider_component_data_buffer(fbb, offset);
+        fbb.finished_data().to_vec()
+    }
+
+    fn read(buf: &[u8]) -> ColliderComponentData<'_> {
+        root_as_collider_component_data(buf).unwrap()
+    }
+
+    #[test]
+    fn typed_builders_fix_the_collider_type() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = SphereColliderBuilder::new(&mut fbb)
+            .radius(0.5)
+            .trigger(true)
+            .build();
+        let buf = finished(&mut fbb, offset);
+        let sphere = read(&buf);
+        assert_eq!(sphere.collider_type(), ColliderType::Sphere);
+        assert_eq!(sphere.radius(), 0.5);
+        assert!(sphere.is_trigger());
+        assert_eq!(sphere.schema_version(), CURRENT_COLLIDER_SCHEMA_VERSION);
+
+        let mut fbb = FlatBufferBuilder::new();
+        let material = fbb.create_string("ice");
+        let offset = BoxColliderBuilder::new(&mut fbb)
+            .size(1.0, 2.0, 3.0)
+            .material_name(material)
+            .layer(4)
+            .build();
+        let buf = finished(&mut fbb, offset);
+        let cube = read(&buf);
+        assert_eq!(cube.collider_type(), ColliderType::Box);
+        assert_eq!(cube.size(), Some(&Vec3::new(1.0, 2.0, 3.0)));
+        assert_eq!(cube.material_name(), Some("ice"));
+        assert_eq!(cube.layer(), 4);
+
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = CapsuleColliderBuilder::new(&mut fbb)
+            .radius(0.3)
+            .height(1.2)
+            .axis(CapsuleAxis::Z)
+            .build();
+        let buf = finished(&mut fbb, offset);
+        let capsule = read(&buf);
+        assert_eq!(capsule.collider_type(), ColliderType::Capsule);
+        assert_eq!(capsule.capsule_axis(), CapsuleAxis::Z);
+    }
+
+    #[test]
+    fn mesh_builder_always_has_a_path() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = MeshColliderBuilder::with_path(&mut fbb, "rock.obj")
+            .convex(true)
+            .build();
+        let buf = finished(&mut fbb, offset);
+        let mesh = read(&buf);
+        assert_eq!(mesh.collider_type(), ColliderType::Mesh);
+        assert_eq!(mesh.mesh_path(), Some("rock.obj"));
+        assert!(mesh.mesh_is_convex());
+        assert_eq!(validate_collider(&mesh), Ok(()));
+    }
+
+    #[test]
+    fn shape_constructors_set_type_and_fields() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = build_box_collider(&mut fbb, &Vec3::new(0.5, 1.0, 0.5), true);
+        let buf = finished(&mut fbb, offset);
+        let cube = read(&buf);
+        assert_eq!(cube.collider_type(), ColliderType::Box);
+        assert!(cube.is_trigger());
+        assert_eq!(cube.size(), Some(&Vec3::new(1.0, 2.0, 1.0)));
+
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = build_sphere_collider(&mut fbb, 0.75, false);
+        let buf = finished(&mut fbb, offset);
+        let sphere = read(&buf);
+        assert_eq!(sphere.collider_type(), ColliderType::Sphere);
+        assert_eq!(sphere.radius(), 0.75);
+
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = build_capsule_collider(&mut fbb, 0.2, 1.0, false);
+        let buf = finished(&mut fbb, offset);
+        let capsule = read(&buf);
+        assert_eq!(capsule.collider_type(), ColliderType::Capsule);
+        assert_eq!((capsule.radius(), capsule.height()), (0.2, 1.0));
+        assert_eq!(capsule.size(), None);
+
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = build_mesh_collider(&mut fbb, "meshes/rock.obj", false);
+        let buf = finished(&mut fbb, offset);
+        let mesh = read(&buf);
+        assert_eq!(mesh.collider_type(), ColliderType::Mesh);
+        assert_eq!(mesh.mesh_path(), Some("meshes/rock.obj"));
+    }
+
+    #[test]
+    fn try_build_collider_rejects_invalid_tables() {
+        let mut fbb = FlatBufferBuilder::new();
+        let result = try_build_collider(&mut fbb, |b| b.add_collider_type(ColliderType::Sphere));
+        assert!(matches!(
+            result,
+            Err(ColliderValidationError::NonPositiveRadius { .. })
+        ));
+
+        let mut fbb = FlatBufferBuilder::new();
+        let mesh_path = fbb.create_string("");
+        let result = try_build_collider(&mut fbb, |b| {
+            b.add_collider_type(ColliderType::Mesh);
+            b.add_mesh_path(mesh_path);
+        });
+        assert_eq!(result.err(), Some(ColliderValidationError::MissingMeshPath));
+    }
+
+    #[test]
+    fn try_build_collider_returns_valid_tables() {
+        let mut fbb = FlatBufferBuilder::new();
+        let material = fbb.create_string("mat");
+        let size = Vec3::new(1.0, 1.0, 1.0);
+        let offset = try_build_collider(&mut fbb, |b| {
+            b.add_size(&size);
+            b.add_material_name(material);
+        })
+        .unwrap();
+        let buf = finished(&mut fbb, offset);
+        let collider = read(&buf);
+        assert_eq!(collider.material_name(), Some("mat"));
+        assert_eq!(collider.schema_version(), CURRENT_COLLIDER_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn minimal_build_drops_forced_defaults() {
+        let sphere = ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 0.5,
+            ..Default::default()
+        };
+        let mut fbb = FlatBufferBuilder::new();
+        fbb.force_defaults(true);
+        let offset = sphere.serialize(&mut fbb);
+        let full = finished(&mut fbb, offset);
+
+        let mut fbb = FlatBufferBuilder::new();
+        fbb.force_defaults(true);
+        let offset = build_collider_minimal(&mut fbb, &sphere);
+        let minimal = finished(&mut fbb, offset);
+
+        assert!(
+            minimal.len() < full.len(),
+            "{} >= {}",
+            minimal.len(),
+            full.len()
+        );
+        assert_eq!(minimal, make_collider_buffer(&sphere));
+        assert_eq!(ColliderComponent::from(read(&minimal)), sphere);
+        assert_eq!(ColliderComponent::from(read(&full)), sphere);
+    }
+
+    #[test]
+    fn standalone_buffers_carry_the_identifier() {
+        let sphere = ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.0,
+            ..Default::default()
+        };
+        let buf = make_collider_buffer(&sphere);
+        assert!(collider_component_data_buffer_has_identifier(&buf));
+        assert_eq!(ColliderComponent::from(read(&buf)), sphere);
+
+        let prefixed = make_collider_buffer_size_prefixed(&sphere);
+        assert!(collider_component_data_size_prefixed_buffer_has_identifier(
+            &prefixed
+        ));
+        assert_eq!(
+            u32::from_le_bytes(prefixed[..4].try_into().unwrap()) as usize,
+            prefixed.len() - 4
+        );
+        let data = size_prefixed_root_as_collider_component_data(&prefixed).unwrap();
+        assert_eq!(ColliderComponent::from(data), sphere);
+    }
+
+    #[test]
+    fn size_estimate_covers_the_built_buffer() {
+        let everything = ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            is_trigger: true,
+            size: Some(Vec3::new(1.0, 2.0, 3.0)),
+            radius: 1.0,
+            height: 2.0,
+            material_name: Some("x".repeat(37)),
+            mesh_path: Some("rock.obj".into()),
+            layer: 3,
+            collision_mask: 7,
+            center: Some(Vec3::new(1.0, 0.0, 0.0)),
+            is_2d: true,
+            size_2d: Some(Vec2::new(1.0, 1.0)),
+            density: 2.0,
+            mass_override: 3.0,
+            debug_color: Some(Color::new(1, 2, 3, 4)),
+            capsule_axis: CapsuleAxis::X,
+            mesh_is_convex: true,
+            trigger_channel: Some("abc".into()),
+            contact_margin: 0.2,
+            trigger_dwell_seconds: 1.5,
+            trigger_with_triggers: true,
+            is_static: true,
+            units: ColliderUnits::Pixels,
+            surface_type: Some("metal".into()),
+            trigger_debounce_seconds: 0.5,
+        };
+        let cases = [
+            ColliderComponent::default(),
+            everything,
+            ColliderComponent {
+                material_name: Some(String::new()),
+                ..Default::default()
+            },
+        ];
+        for collider in &cases {
+            let estimate = estimate_collider_size(collider);
+            assert!(estimate >= make_collider_buffer_size_prefixed(collider).len());
+
+            let mut fbb = FlatBufferBuilder::new();
+            fbb.force_defaults(true);
+            let offset = collider.serialize(&mut fbb);
+            finish_size_prefixed_collider_component_data_buffer(&mut fbb, offset);
+            assert!(
+                estimate >= fbb.finished_data().len(),
+                "{estimate} < {} for {collider:?}",
+                fbb.finished_data().len()
+            );
+        }
+    }
+
+    #[test]
+    fn size_can_be_set_from_three_floats() {
+        let mut fbb = FlatBufferBuilder::new();
+        let mut builder = ColliderComponentDataBuilder::new(&mut fbb);
+        builder.add_size_xyz(1.0, 2.0, 3.0);
+        let offset = builder.finish();
+        let buf = finished(&mut fbb, offset);
+        assert_eq!(read(&buf).size(), Some(&Vec3::new(1.0, 2.0, 3.0)));
+    }
+
+    #[test]
+    fn try_build_collider_rejects_physical_fields_on_triggers() {
+        let mut fbb = FlatBufferBuilder::new();
+        let result = try_build_collider(&mut fbb, |b| {
+            b.add_collider_type(ColliderType::Sphere);
+            b.add_radius(1.0);
+            b.add_is_trigger(true);
+            b.add_density(3.0);
+        });
+        assert_eq!(
+            result.unwrap_err(),
+            ColliderValidationError::PhysicalFieldOnTrigger { field: "density" }
+        );
+    }
+
+    fn finished_array<'a>(
+        fbb: &mut FlatBufferBuilder<'a>,
+        offsets: &[WIPOffset<ColliderComponentData<'a>>],
+    ) -> Vec<u8> {
+        let colliders = fbb.create_vector(offsets);
+        let root = ColliderComponentArrayData::create(
+            fbb,
+            &ColliderComponentArrayDataArgs {
+                colliders: Some(colliders),
+            },
+        );
+        finish_collider_component_array_data_buffer(fbb, root);
+        fbb.finished_data().to_vec()
+    }
+
+    #[test]
+    fn cached_builds_store_repeated_strings_once() {
+        let colliders: Vec<_> = (0..3)
+            .map(|i| ColliderComponent {
+                radius: i as f32,
+                material_name: Some("stone_material".into()),
+                ..Default::default()
+            })
+            .collect();
+
+        let mut cache = StringCache::new();
+        let offsets: Vec<_> = colliders
+            .iter()
+            .map(|collider| build_collider_cached(&mut cache, collider))
+            .collect();
+        let cached = finished_array(cache.builder(), &offsets);
+
+        let mut fbb = FlatBufferBuilder::new();
+        let offsets: Vec<_> = colliders.iter().map(|c| c.serialize(&mut fbb)).collect();
+        let plain = finished_array(&mut fbb, &offsets);
+
+        let copies = |buf: &[u8]| {
+            buf.windows(14)
+                .filter(|window| *window == b"stone_material")
+                .count()
+        };
+        assert_eq!(copies(&cached), 1);
+        assert_eq!(copies(&plain), 3);
+        assert!(cached.len() < plain.len());
+
+        let array = ColliderComponentArray::from_buffer(&cached).unwrap();
+        let back: Vec<_> = array.iter().map(ColliderComponent::from).collect();
+        assert_eq!(back, colliders);
+    }
+
+    #[test]
+    fn mesh_path_over_the_limit_is_rejected_without_writing() {
+        let mut fbb = FlatBufferBuilder::new();
+        let long = "a".repeat(MAX_MESH_PATH_LEN + 1);
+        assert_eq!(
+            create_mesh_path_checked(&mut fbb, &long, MAX_MESH_PATH_LEN),
+            Err(BuildError::MeshPathTooLong {
+                len: MAX_MESH_PATH_LEN + 1,
+                max: MAX_MESH_PATH_LEN,
+            })
+        );
+        assert!(fbb.unfinished_data().is_empty());
+        assert!(create_mesh_path_checked(&mut fbb, "abc", 2).is_err());
+    }
+
+    #[test]
+    fn mesh_path_within_the_limit_is_written() {
+        let mut fbb = FlatBufferBuilder::new();
+        let path = create_mesh_path_checked(&mut fbb, "rocks/big.obj", MAX_MESH_PATH_LEN).unwrap();
+        let mut builder = ColliderComponentDataBuilder::new(&mut fbb);
+        builder.add_collider_type(ColliderType::Mesh);
+        builder.add_mesh_path(path);
+        let offset = builder.finish();
+        let buf = finished(&mut fbb, offset);
+        assert_eq!(read(&buf).mesh_path(), Some("rocks/big.obj"));
+    }
+
+    #[test]
+    fn clone_copies_every_field_into_another_builder() {
+        let rock = ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            size: Some(Vec3::new(1.0, 2.0, 3.0)),
+            material_name: Some("rubber".into()),
+            mesh_path: Some("meshes/rock.obj".into()),
+            mesh_is_convex: true,
+            layer: 4,
+            center: Some(Vec3::new(0.0, 1.0, 0.0)),
+            debug_color: Some(Color::new(9, 8, 7, 6)),
+            trigger_channel: Some("t".into()),
+            surface_type: Some("stone".into()),
+            units: ColliderUnits::Pixels,
+            ..Default::default()
+        };
+        let source = make_collider_buffer(&rock);
+        let mut fbb = FlatBufferBuilder::new();
+        fbb.create_string("unrelated");
+        let offset = clone_collider(&read(&source), &mut fbb);
+        let cloned = finished(&mut fbb, offset);
+        assert_eq!(ColliderComponent::from(read(&cloned)), rock);
+        assert_eq!(
+            read(&cloned).schema_version(),
+            read(&source).schema_version()
+        );
+    }
+
+    #[test]
+    fn clone_keeps_an_old_schema_version() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = ColliderComponentData::create(
+            &mut fbb,
+            &ColliderComponentDataArgs {
+                schema_version: 1,
+                radius: 2.0,
+                ..Default::default()
+            },
+        );
+        let old = finished(&mut fbb, offset);
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = clone_collider(&read(&old), &mut fbb);
+        assert_eq!(read(&finished(&mut fbb, offset)).schema_version(), 1);
+    }
+}
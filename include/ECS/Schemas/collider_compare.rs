@@ -0,0 +1,937 @@
+// Field-level comparisons between collider tables.
+
+use crate::collider_component_generated::*;
+use crate::common_types_generated::*;
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// One field that differs between two colliders, with its old and new value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColliderFieldChange<'a> {
+    ColliderType {
+        old: ColliderType,
+        new: ColliderType,
+    },
+    IsTrigger {
+        old: bool,
+        new: bool,
+    },
+    Size {
+        old: Option<Vec3>,
+        new: Option<Vec3>,
+    },
+    Radius {
+        old: f32,
+        new: f32,
+    },
+    Height {
+        old: f32,
+        new: f32,
+    },
+    MaterialName {
+        old: Option<&'a str>,
+        new: Option<&'a str>,
+    },
+    MeshPath {
+        old: Option<&'a str>,
+        new: Option<&'a str>,
+    },
+    Layer {
+        old: u32,
+        new: u32,
+    },
+    CollisionMask {
+        old: u32,
+        new: u32,
+    },
+    Center {
+        old: Option<Vec3>,
+        new: Option<Vec3>,
+    },
+    Is2d {
+        old: bool,
+        new: bool,
+    },
+    Size2d {
+        old: Option<Vec2>,
+        new: Option<Vec2>,
+    },
+    Density {
+        old: f32,
+        new: f32,
+    },
+    MassOverride {
+        old: f32,
+        new: f32,
+    },
+    DebugColor {
+        old: Option<Color>,
+        new: Option<Color>,
+    },
+    CapsuleAxis {
+        old: CapsuleAxis,
+        new: CapsuleAxis,
+    },
+    MeshIsConvex {
+        old: bool,
+        new: bool,
+    },
+    TriggerChannel {
+        old: Option<&'a str>,
+        new: Option<&'a str>,
+    },
+    ContactMargin {
+        old: f32,
+        new: f32,
+    },
+    TriggerDwellSeconds {
+        old: f32,
+        new: f32,
+    },
+    TriggerWithTriggers {
+        old: bool,
+        new: bool,
+    },
+    IsStatic {
+        old: bool,
+        new: bool,
+    },
+    Units {
+        old: ColliderUnits,
+        new: ColliderUnits,
+    },
+    SurfaceType {
+        old: Option<&'a str>,
+        new: Option<&'a str>,
+    },
+    TriggerDebounceSeconds {
+        old: f32,
+        new: f32,
+    },
+}
+
+impl ColliderFieldChange<'_> {
+    /// The schema name of the changed field.
+    pub fn field_name(&self) -> &'static str {
+        match self {
+            Self::ColliderType { .. } => "collider_type",
+            Self::IsTrigger { .. } => "is_trigger",
+            Self::Size { .. } => "size",
+            Self::Radius { .. } => "radius",
+            Self::Height { .. } => "height",
+            Self::MaterialName { .. } => "material_name",
+            Self::MeshPath { .. } => "mesh_path",
+            Self::Layer { .. } => "layer",
+            Self::CollisionMask { .. } => "collision_mask",
+            Self::Center { .. } => "center",
+            Self::Is2d { .. } => "is_2d",
+            Self::Size2d { .. } => "size_2d",
+            Self::Density { .. } => "density",
+            Self::MassOverride { .. } => "mass_override",
+            Self::DebugColor { .. } => "debug_color",
+            Self::CapsuleAxis { .. } => "capsule_axis",
+            Self::MeshIsConvex { .. } => "mesh_is_convex",
+            Self::TriggerChannel { .. } => "trigger_channel",
+            Self::ContactMargin { .. } => "contact_margin",
+            Self::TriggerDwellSeconds { .. } => "trigger_dwell_seconds",
+            Self::TriggerWithTriggers { .. } => "trigger_with_triggers",
+            Self::IsStatic { .. } => "is_static",
+            Self::Units { .. } => "units",
+            Self::SurfaceType { .. } => "surface_type",
+            Self::TriggerDebounceSeconds { .. } => "trigger_debounce_seconds",
+        }
+    }
+}
+
+/// Lists the fields that differ from `a` to `b`, in schema order.
+///
+/// Float fields (including each `Vec3` component) count as unchanged when they
+/// are within `epsilon` of each other. An empty result means the colliders are
+/// logically identical.
+pub fn diff_colliders<'a>(
+    a: &ColliderComponentData<'a>,
+    b: &ColliderComponentData<'a>,
+    epsilon: f32,
+) -> Vec<ColliderFieldChange<'a>> {
+    let mut changes = Vec::new();
+    if a.collider_type() != b.collider_type() {
+        changes.push(ColliderFieldChange::ColliderType {
+            old: a.collider_type(),
+            new: b.collider_type(),
+        });
+    }
+    if a.is_trigger() != b.is_trigger() {
+        changes.push(ColliderFieldChange::IsTrigger {
+            old: a.is_trigger(),
+            new: b.is_trigger(),
+        });
+    }
+    if !vec3_opt_close(a.size(), b.size(), epsilon) {
+        changes.push(ColliderFieldChange::Size {
+            old: a.size().copied(),
+            new: b.size().copied(),
+        });
+    }
+    if !floats_close(a.radius(), b.radius(), epsilon) {
+        changes.push(ColliderFieldChange::Radius {
+            old: a.radius(),
+            new: b.radius(),
+        });
+    }
+    if !floats_close(a.height(), b.height(), epsilon) {
+        changes.push(ColliderFieldChange::Height {
+            old: a.height(),
+            new: b.height(),
+        });
+    }
+    if a.material_name() != b.material_name() {
+        changes.push(ColliderFieldChange::MaterialName {
+            old: a.material_name(),
+            new: b.material_name(),
+        });
+    }
+    if a.mesh_path() != b.mesh_path() {
+        changes.push(ColliderFieldChange::MeshPath {
+            old: a.mesh_path(),
+            new: b.mesh_path(),
+        });
+    }
+    if a.layer() != b.layer() {
+        changes.push(ColliderFieldChange::Layer {
+            old: a.layer(),
+            new: b.layer(),
+        });
+    }
+    if a.collision_mask() != b.collision_mask() {
+        changes.push(ColliderFieldChange::CollisionMask {
+            old: a.collision_mask(),
+            new: b.collision_mask(),
+        });
+    }
+    if !vec3_opt_close(a.center(), b.center(), epsilon) {
+        changes.push(ColliderFieldChange::Center {
+            old: a.center().copied(),
+            new: b.center().copied(),
+        });
+    }
+    if a.is_2d() != b.is_2d() {
+        changes.push(ColliderFieldChange::Is2d {
+            old: a.is_2d(),
+            new: b.is_2d(),
+        });
+    }
+    if !vec2_opt_close(a.size_2d(), b.size_2d(), epsilon) {
+        changes.push(ColliderFieldChange::Size2d {
+            old: a.size_2d().copied(),
+            new: b.size_2d().copied(),
+        });
+    }
+    if !floats_close(a.density(), b.density(), epsilon) {
+        changes.push(ColliderFieldChange::Density {
+            old: a.density(),
+            new: b.density(),
+        });
+    }
+    if !floats_close(a.mass_override(), b.mass_override(), epsilon) {
+        changes.push(ColliderFieldChange::MassOverride {
+            old: a.mass_override(),
+            new: b.mass_override(),
+        });
+    }
+    if a.debug_color() != b.debug_color() {
+        changes.push(ColliderFieldChange::DebugColor {
+            old: a.debug_color().copied(),
+            new: b.debug_color().copied(),
+        });
+    }
+    if a.capsule_axis() != b.capsule_axis() {
+        changes.push(ColliderFieldChange::CapsuleAxis {
+            old: a.capsule_axis(),
+            new: b.capsule_axis(),
+        });
+    }
+    if a.mesh_is_convex() != b.mesh_is_convex() {
+        changes.push(ColliderFieldChange::MeshIsConvex {
+            old: a.mesh_is_convex(),
+            new: b.mesh_is_convex(),
+        });
+    }
+    if a.trigger_channel() != b.trigger_channel() {
+        changes.push(ColliderFieldChange::TriggerChannel {
+            old: a.trigger_channel(),
+            new: b.trigger_channel(),
+        });
+    }
+    if !floats_close(a.contact_margin(), b.contact_margin(), epsilon) {
+        changes.push(ColliderFieldChange::ContactMargin {
+            old: a.contact_margin(),
+            new: b.contact_margin(),
+        });
+    }
+    if !floats_close(
+        a.trigger_dwell_seconds(),
+        b.trigger_dwell_seconds(),
+        epsilon,
+    ) {
+        changes.push(ColliderFieldChange::TriggerDwellSeconds {
+            old: a.trigger_dwell_seconds(),
+            new: b.trigger_dwell_seconds(),
+        });
+    }
+    if a.trigger_with_triggers() != b.trigger_with_triggers() {
+        changes.push(ColliderFieldChange::TriggerWithTriggers {
+            old: a.trigger_with_triggers(),
+            new: b.trigger_with_triggers(),
+        });
+    }
+    if a.is_static() != b.is_static() {
+        changes.push(ColliderFieldChange::IsStatic {
+            old: a.is_static(),
+            new: b.is_static(),
+        });
+    }
+    if a.units() != b.units() {
+        changes.push(ColliderFieldChange::Units {
+            old: a.units(),
+            new: b.units(),
+        });
+    }
+    if a.surface_type() != b.surface_type() {
+        changes.push(ColliderFieldChange::SurfaceType {
+            old: a.surface_type(),
+            new: b.surface_type(),
+        });
+    }
+    if !floats_close(
+        a.trigger_debounce_seconds(),
+        b.trigger_debounce_seconds(),
+        epsilon,
+    ) {
+        changes.push(ColliderFieldChange::TriggerDebounceSeconds {
+            old: a.trigger_debounce_seconds(),
+            new: b.trigger_debounce_seconds(),
+        });
+    }
+    changes
+}
+
+/// Whether `a` and `b` hold the same collider, with floats within `eps` and
+/// every other field compared exactly.
+///
+/// This is the equality to use in tests. The derived `PartialEq` on
+/// `ColliderComponentData` compares the underlying buffers and table positions,
+/// so the same collider written with fields in a different order, with
+/// defaults forced or into a different buffer is reported as unequal.
+/// `schema_version` is not compared.
+pub fn colliders_approx_eq(a: &ColliderComponentData, b: &ColliderComponentData, eps: f32) -> bool {
+    diff_colliders(a, b, eps).is_empty()
+}
+
+/// Whether `a` and `b` have the same shape, so they can share a physics handle.
+///
+/// Compares `collider_type`, `is_trigger`, `size`, `radius`, `height`, `center`,
+/// `is_2d`, `size_2d`, `capsule_axis`, `mesh_is_convex`, `contact_margin` and
+/// `units`, with floats within `epsilon`. The string fields and the
+/// per-instance layer, mask, mass and debug color settings are ignored.
+pub fn collider_shape_eq(
+    a: &ColliderComponentData,
+    b: &ColliderComponentData,
+    epsilon: f32,
+) -> bool {
+    a.collider_type() == b.collider_type()
+        && a.is_trigger() == b.is_trigger()
+        && vec3_opt_close(a.size(), b.size(), epsilon)
+        && floats_close(a.radius(), b.radius(), epsilon)
+        && floats_close(a.height(), b.height(), epsilon)
+        && vec3_opt_close(a.center(), b.center(), epsilon)
+        && a.is_2d() == b.is_2d()
+        && vec2_opt_close(a.size_2d(), b.size_2d(), epsilon)
+        && a.capsule_axis() == b.capsule_axis()
+        && a.units() == b.units()
+        && a.mesh_is_convex() == b.mesh_is_convex()
+        && floats_close(a.contact_margin(), b.contact_margin(), epsilon)
+}
+
+/// Stable 64-bit FNV-1a hash of a collider's content, for keying cached
+/// physics assets.
+///
+/// Every field the physics engine reads is hashed: shape, placement, mass,
+/// material and collision filtering. Fields only read outside it are left
+/// out, so changing them does not invalidate a bake: `debug_color` (editor),
+/// `trigger_channel`, `trigger_dwell_seconds` and `trigger_debounce_seconds`
+/// (trigger event routing and filtering), `surface_type` (audio) and
+/// `schema_version`.
+///
+/// Fields are fed in schema order with fixed-width little-endian encodings, so
+/// the hash is the same across runs, platforms and builds. Floats are hashed by
+/// bit pattern after mapping `-0.0` to `0.0` and every NaN to one canonical NaN.
+/// Optional fields hash a presence byte first and strings are length-prefixed,
+/// so adjacent fields cannot run into each other. Hashing a new physics field
+/// changes every hash, which invalidates caches built by older versions.
+pub fn collider_content_hash(data: &ColliderComponentData) -> u64 {
+    let mut h = Fnv1a::new();
+    write_content(data, &mut h);
+    h.finish()
+}
+
+/// A collider's content, usable as a `HashMap` key.
+///
+/// Owns the canonical encoding that `collider_content_hash` hashes, so two
+/// keys are equal exactly when the colliders hash and compare equal there:
+/// `-0.0` equals `0.0`, all NaNs are equal, and the buffer layout does not
+/// matter. Fields left out of the hash are ignored. `Hash` feeds the content
+/// hash to the hasher, so keys hash as cheaply as a `u64`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColliderKey {
+    hash: u64,
+    content: Box<[u8]>,
+}
+
+impl ColliderKey {
+    /// Same as `collider_content_hash` for the collider this key was made from.
+    #[inline]
+    pub fn content_hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl From<&ColliderComponentData<'_>> for ColliderKey {
+    fn from(data: &ColliderComponentData<'_>) -> Self {
+        let mut content = Vec::new();
+        write_content(data, &mut content);
+        let mut h = Fnv1a::new();
+        h.write(&content);
+        ColliderKey {
+            hash: h.finish(),
+            content: content.into_boxed_slice(),
+        }
+    }
+}
+
+impl Hash for ColliderKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+/// Feeds the fields the physics engine reads to `h` in schema order, in the
+/// encoding described on `collider_content_hash`.
+fn write_content(data: &ColliderComponentData, h: &mut impl ContentSink) {
+    h.write(&data.collider_type().0.to_le_bytes());
+    h.write(&[data.is_trigger() as u8]);
+    h.vec3(data.size());
+    h.float(data.radius());
+    h.float(data.height());
+    h.str(data.material_name());
+    h.str(data.mesh_path());
+    h.write(&data.layer().to_le_bytes());
+    h.write(&data.collision_mask().to_le_bytes());
+    h.vec3(data.center());
+    h.write(&[data.is_2d() as u8]);
+    match data.size_2d() {
+        Some(v) => {
+            h.write(&[1]);
+            h.float(v.x());
+            h.float(v.y());
+        }
+        None => h.write(&[0]),
+    }
+    h.float(data.density());
+    h.float(data.mass_override());
+    h.write(&data.capsule_axis().0.to_le_bytes());
+    h.write(&[data.mesh_is_convex() as u8]);
+    h.float(data.contact_margin());
+    h.write(&[data.trigger_with_triggers() as u8]);
+    h.write(&[data.is_static() as u8]);
+    h.write(&data.units().0.to_le_bytes());
+}
+
+/// Total, platform-independent order over colliders, for sorting them
+/// deterministically.
+///
+/// Compares `collider_type`, then `is_trigger`, then the numeric fields in
+/// schema order, then `material_name`, `mesh_path`, `trigger_channel` and
+/// `surface_type`. Floats are ordered with `f32::total_cmp` after the same
+/// canonicalization as `collider_content_hash`, so `-0.0` equals `0.0` and all
+/// NaNs are equal and sort after every number. Absent optional fields sort
+/// before present ones. `schema_version` is ignored.
+pub fn collider_cmp(a: &ColliderComponentData, b: &ColliderComponentData) -> Ordering {
+    a.collider_type()
+        .cmp(&b.collider_type())
+        .then(a.is_trigger().cmp(&b.is_trigger()))
+        .then_with(|| vec3_opt_cmp(a.size(), b.size()))
+        .then_with(|| float_cmp(a.radius(), b.radius()))
+        .then_with(|| float_cmp(a.height(), b.height()))
+        .then(a.layer().cmp(&b.layer()))
+        .then(a.collision_mask().cmp(&b.collision_mask()))
+        .then_with(|| vec3_opt_cmp(a.center(), b.center()))
+        .then(a.is_2d().cmp(&b.is_2d()))
+        .then_with(|| match (a.size_2d(), b.size_2d()) {
+            (Some(a), Some(b)) => float_cmp(a.x(), b.x()).then(float_cmp(a.y(), b.y())),
+            (a, b) => a.is_some().cmp(&b.is_some()),
+        })
+        .then_with(|| float_cmp(a.density(), b.density()))
+        .then_with(|| float_cmp(a.mass_override(), b.mass_override()))
+        .then_with(|| {
+            let rgba = |c: &Color| [c.r(), c.g(), c.b(), c.a()];
+            a.debug_color().map(rgba).cmp(&b.debug_color().map(rgba))
+        })
+        .then(a.capsule_axis().cmp(&b.capsule_axis()))
+        .then(a.mesh_is_convex().cmp(&b.mesh_is_convex()))
+        .then_with(|| float_cmp(a.contact_margin(), b.contact_margin()))
+        .then_with(|| float_cmp(a.trigger_dwell_seconds(), b.trigger_dwell_seconds()))
+        .then(a.trigger_with_triggers().cmp(&b.trigger_with_triggers()))
+        .then(a.is_static().cmp(&b.is_static()))
+        .then(a.units().cmp(&b.units()))
+        .then_with(|| float_cmp(a.trigger_debounce_seconds(), b.trigger_debounce_seconds()))
+        .then_with(|| a.material_name().cmp(&b.material_name()))
+        .then_with(|| a.mesh_path().cmp(&b.mesh_path()))
+        .then_with(|| a.trigger_channel().cmp(&b.trigger_channel()))
+        .then_with(|| a.surface_type().cmp(&b.surface_type()))
+}
+
+/// Destination for the canonical content encoding.
+trait ContentSink {
+    fn write(&mut self, bytes: &[u8]);
+
+    fn float(&mut self, value: f32) {
+        self.write(&canonical_float(value).to_bits().to_le_bytes());
+    }
+
+    fn vec3(&mut self, value: Option<&Vec3>) {
+        match value {
+            Some(v) => {
+                self.write(&[1]);
+                self.float(v.x());
+                self.float(v.y());
+                self.float(v.z());
+            }
+            None => self.write(&[0]),
+        }
+    }
+
+    fn str(&mut self, value: Option<&str>) {
+        match value {
+            Some(s) => {
+                self.write(&[1]);
+                self.write(&(s.len() as u64).to_le_bytes());
+                self.write(s.as_bytes());
+            }
+            None => self.write(&[0]),
+        }
+    }
+}
+
+impl ContentSink for Vec<u8> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Fnv1a(Self::OFFSET_BASIS)
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl ContentSink for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+/// Maps `-0.0` to `0.0` and every NaN to one canonical NaN.
+fn canonical_float(value: f32) -> f32 {
+    if value == 0.0 {
+        0.0
+    } else if value.is_nan() {
+        f32::NAN
+    } else {
+        value
+    }
+}
+
+fn float_cmp(a: f32, b: f32) -> Ordering {
+    canonical_float(a).total_cmp(&canonical_float(b))
+}
+
+fn vec3_opt_cmp(a: Option<&Vec3>, b: Option<&Vec3>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => float_cmp(a.x(), b.x())
+            .then(float_cmp(a.y(), b.y()))
+            .then(float_cmp(a.z(), b.z())),
+        (a, b) => a.is_some().cmp(&b.is_some()),
+    }
+}
+
+/// Whether two floats are within `epsilon`. NaN compares equal to NaN so that
+/// an unchanged NaN is not reported as a change.
+fn floats_close(a: f32, b: f32, epsilon: f32) -> bool {
+    a == b || (a - b).abs() <= epsilon || (a.is_nan() && b.is_nan())
+}
+
+fn vec3_close(a: &Vec3, b: &Vec3, epsilon: f32) -> bool {
+    floats_close(a.x(), b.x(), epsilon)
+        && floats_close(a.y(), b.y(), epsilon)
+        && floats_close(a.z(), b.z(), epsilon)
+}
+
+fn vec2_opt_close(a: Option<&Vec2>, b: Option<&Vec2>, epsilon: f32) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            floats_close(a.x(), b.x(), epsilon) && floats_close(a.y(), b.y(), epsilon)
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn vec3_opt_close(a: Option<&Vec3>, b: Option<&Vec3>, epsilon: f32) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => vec3_close(a, b, epsilon),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+
+    use flatbuffers::FlatBufferBuilder;
+    use std::collections::HashMap;
+
+    fn hash_of(collider: &ColliderComponent) -> u64 {
+        let buf = make_collider_buffer(collider);
+        collider_content_hash(&read(&buf))
+    }
+
+    fn read(buf: &[u8]) -> ColliderComponentData<'_> {
+        root_as_collider_component_data(buf).unwrap()
+    }
+
+    fn sphere() -> ColliderComponent {
+        ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 0.5,
+            is_trigger: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn content_hash_ignores_fields_the_physics_engine_does_not_read() {
+        let annotated = ColliderComponent {
+            debug_color: Some(Color::new(255, 0, 0, 255)),
+            trigger_channel: Some("checkpoint".into()),
+            trigger_dwell_seconds: 2.0,
+            surface_type: Some("gravel".into()),
+            trigger_debounce_seconds: 0.25,
+            ..sphere()
+        };
+        assert_eq!(hash_of(&annotated), hash_of(&sphere()));
+    }
+
+    #[test]
+    fn content_hash_tracks_material_and_collision_filtering() {
+        let variants = [
+            ColliderComponent {
+                material_name: Some("ice".into()),
+                ..sphere()
+            },
+            ColliderComponent {
+                layer: 3,
+                ..sphere()
+            },
+            ColliderComponent {
+                collision_mask: 0b101,
+                ..sphere()
+            },
+            ColliderComponent {
+                trigger_with_triggers: true,
+                ..sphere()
+            },
+            ColliderComponent {
+                is_static: true,
+                ..sphere()
+            },
+        ];
+        for variant in &variants {
+            assert_ne!(hash_of(variant), hash_of(&sphere()), "{variant:?}");
+        }
+    }
+
+    #[test]
+    fn identical_colliders_have_no_diff() {
+        let buf = make_collider_buffer(&sphere());
+        let other = make_collider_buffer(&sphere());
+        let (a, b) = (read(&buf), read(&other));
+        assert!(diff_colliders(&a, &b, 1e-6).is_empty());
+    }
+
+    #[test]
+    fn diff_lists_changed_fields_in_schema_order() {
+        let buf = make_collider_buffer(&sphere());
+        let other = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5000001,
+            material_name: Some("ice".into()),
+            ..sphere()
+        });
+        let changes = diff_colliders(&read(&buf), &read(&other), 1e-5);
+        assert_eq!(
+            changes,
+            vec![
+                ColliderFieldChange::ColliderType {
+                    old: ColliderType::Sphere,
+                    new: ColliderType::Capsule,
+                },
+                ColliderFieldChange::MaterialName {
+                    old: None,
+                    new: Some("ice"),
+                },
+            ]
+        );
+        let names: Vec<_> = changes.iter().map(|c| c.field_name()).collect();
+        assert_eq!(names, ["collider_type", "material_name"]);
+    }
+
+    fn shape_eq(a: &ColliderComponent, b: &ColliderComponent) -> bool {
+        let (a, b) = (make_collider_buffer(a), make_collider_buffer(b));
+        collider_shape_eq(&read(&a), &read(&b), 1e-6)
+    }
+
+    #[test]
+    fn shape_eq_ignores_per_instance_settings() {
+        let other = ColliderComponent {
+            material_name: Some("ice".into()),
+            layer: 3,
+            collision_mask: 0b101,
+            mass_override: 4.0,
+            debug_color: Some(Color::new(0, 255, 0, 255)),
+            ..sphere()
+        };
+        assert!(shape_eq(&sphere(), &other));
+    }
+
+    #[test]
+    fn shape_eq_compares_dimensions() {
+        let bigger = ColliderComponent {
+            radius: 1.0,
+            ..sphere()
+        };
+        assert!(!shape_eq(&sphere(), &bigger));
+        let solid = ColliderComponent {
+            is_trigger: false,
+            ..sphere()
+        };
+        assert!(!shape_eq(&sphere(), &solid));
+    }
+
+    #[test]
+    fn content_hash_tracks_shape_fields() {
+        assert_eq!(hash_of(&sphere()), hash_of(&sphere()));
+        let bigger = ColliderComponent {
+            radius: 1.5,
+            ..sphere()
+        };
+        assert_ne!(hash_of(&bigger), hash_of(&sphere()));
+    }
+
+    #[test]
+    fn content_hash_treats_signed_zeros_alike() {
+        let positive = ColliderComponent {
+            center: Some(Vec3::new(0.0, 0.0, 0.0)),
+            ..sphere()
+        };
+        let negative = ColliderComponent {
+            center: Some(Vec3::new(-0.0, 0.0, -0.0)),
+            ..sphere()
+        };
+        assert_eq!(hash_of(&positive), hash_of(&negative));
+    }
+
+    #[test]
+    fn content_hash_keeps_string_fields_apart() {
+        let material = ColliderComponent {
+            material_name: Some("ab".into()),
+            ..sphere()
+        };
+        let mesh = ColliderComponent {
+            mesh_path: Some("ab".into()),
+            ..sphere()
+        };
+        assert_ne!(hash_of(&material), hash_of(&mesh));
+        let split = ColliderComponent {
+            material_name: Some("a".into()),
+            mesh_path: Some("b".into()),
+            ..sphere()
+        };
+        assert_ne!(hash_of(&split), hash_of(&material));
+    }
+
+    fn cmp(a: &ColliderComponent, b: &ColliderComponent) -> Ordering {
+        let (a, b) = (make_collider_buffer(a), make_collider_buffer(b));
+        collider_cmp(&read(&a), &read(&b))
+    }
+
+    fn sphere_of(radius: f32) -> ColliderComponent {
+        ColliderComponent { radius, ..sphere() }
+    }
+
+    #[test]
+    fn collider_cmp_orders_by_type_then_fields() {
+        let cube = ColliderComponent {
+            size: Some(Vec3::new(1.0, 1.0, 1.0)),
+            ..Default::default()
+        };
+        assert_eq!(cmp(&cube, &sphere()), Ordering::Less);
+        assert_eq!(cmp(&sphere_of(1.0), &sphere_of(2.0)), Ordering::Less);
+        let solid = ColliderComponent {
+            is_trigger: false,
+            radius: 9.0,
+            ..sphere()
+        };
+        assert_eq!(cmp(&solid, &sphere()), Ordering::Less);
+        let named = |name: &str| ColliderComponent {
+            material_name: Some(name.into()),
+            ..sphere()
+        };
+        assert_eq!(cmp(&sphere(), &named("a")), Ordering::Less);
+        assert_eq!(cmp(&named("a"), &named("b")), Ordering::Less);
+    }
+
+    #[test]
+    fn collider_cmp_canonicalizes_floats() {
+        assert_eq!(cmp(&sphere_of(-0.0), &sphere_of(0.0)), Ordering::Equal);
+        assert_eq!(
+            cmp(&sphere_of(f32::NAN), &sphere_of(-f32::NAN)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            cmp(&sphere_of(f32::INFINITY), &sphere_of(f32::NAN)),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn sorting_is_independent_of_input_order() {
+        let colliders = [
+            sphere_of(1.0),
+            sphere_of(f32::NAN),
+            sphere_of(0.0),
+            ColliderComponent::default(),
+            ColliderComponent {
+                material_name: Some("b".into()),
+                ..sphere()
+            },
+            ColliderComponent {
+                material_name: Some("a".into()),
+                ..sphere()
+            },
+        ];
+        let buffers: Vec<_> = colliders.iter().map(make_collider_buffer).collect();
+        let sorted = |order: [usize; 6]| {
+            let mut tables: Vec<_> = order.iter().map(|&i| read(&buffers[i])).collect();
+            tables.sort_by(collider_cmp);
+            tables
+                .iter()
+                .map(|t| (t.radius().to_bits(), t.material_name()))
+                .collect::<Vec<_>>()
+        };
+        let forward = sorted([0, 1, 2, 3, 4, 5]);
+        assert_eq!(forward, sorted([5, 4, 3, 2, 1, 0]));
+        assert_eq!(forward, sorted([2, 5, 0, 3, 1, 4]));
+    }
+
+    #[test]
+    fn approx_eq_ignores_field_order_and_forced_defaults() {
+        let mut fbb = FlatBufferBuilder::new();
+        let mut builder = ColliderComponentDataBuilder::new(&mut fbb);
+        builder.add_radius(1.0);
+        builder.add_collider_type(ColliderType::Sphere);
+        builder.add_is_trigger(true);
+        let offset = builder.finish();
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        let plain = fbb.finished_data().to_vec();
+
+        let mut fbb = FlatBufferBuilder::new();
+        fbb.force_defaults(true);
+        let mut builder = ColliderComponentDataBuilder::new(&mut fbb);
+        builder.add_is_trigger(true);
+        builder.add_collider_type(ColliderType::Sphere);
+        builder.add_radius(1.0 + 1e-7);
+        builder.add_height(0.0);
+        let offset = builder.finish();
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        let reordered = fbb.finished_data().to_vec();
+
+        assert_ne!(read(&plain), read(&reordered));
+        assert!(colliders_approx_eq(&read(&plain), &read(&reordered), 1e-5));
+
+        let larger = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.1,
+            is_trigger: true,
+            ..Default::default()
+        });
+        assert!(!colliders_approx_eq(&read(&plain), &read(&larger), 1e-5));
+    }
+
+    fn key_of(buf: &[u8]) -> ColliderKey {
+        ColliderKey::from(&read(buf))
+    }
+
+    #[test]
+    fn equal_content_shares_a_map_key_across_encodings() {
+        let cube = ColliderComponent {
+            size: Some(Vec3::new(1.0, 2.0, 3.0)),
+            ..Default::default()
+        };
+        let plain = make_collider_buffer(&cube);
+        let mut fbb = FlatBufferBuilder::new();
+        fbb.force_defaults(true);
+        let offset = ColliderComponent {
+            radius: -0.0,
+            ..cube
+        }
+        .serialize(&mut fbb);
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        let forced = fbb.finished_data().to_vec();
+        assert_ne!(plain, forced);
+
+        let mut counts = HashMap::new();
+        *counts.entry(key_of(&plain)).or_insert(0) += 1;
+        *counts.entry(key_of(&forced)).or_insert(0) += 1;
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[&key_of(&plain)], 2);
+        assert_eq!(
+            key_of(&plain).content_hash(),
+            collider_content_hash(&read(&plain))
+        );
+    }
+
+    #[test]
+    fn keys_treat_all_nans_alike_and_differ_on_content() {
+        let nan = make_collider_buffer(&sphere_of(f32::NAN));
+        let negative_nan = make_collider_buffer(&sphere_of(-f32::NAN));
+        assert_eq!(key_of(&nan), key_of(&negative_nan));
+        let small = make_collider_buffer(&sphere_of(0.5));
+        let large = make_collider_buffer(&sphere_of(1.0));
+        assert_ne!(key_of(&small), key_of(&large));
+    }
+}
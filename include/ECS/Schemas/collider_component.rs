@@ -0,0 +1,1412 @@
+// Owned runtime counterpart of the generated `ColliderComponentData` table.
+// The serde impls need the `serde` feature and the property test needs the
+// `proptest` dev-dependency; README.md lists the manifest entries.
+
+use crate::collider_component_generated::*;
+use crate::common_types_generated::*;
+
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use flatbuffers::{Allocator, FlatBufferBuilder, WIPOffset};
+
+/// Schema version written by this build.
+///
+/// 1 is the original table; 2 added the collision layers, `center`, the 2D
+/// fields, `density`, `mass_override` and `schema_version` itself. Fields added
+/// since default to "unset" and need no migration.
+pub const CURRENT_COLLIDER_SCHEMA_VERSION: u16 = 2;
+
+/// Owned copy of a `ColliderComponentData` table.
+///
+/// The generated reader borrows the buffer it was read from; this type copies
+/// every field out so a collider can be stored in the ECS world and mutated
+/// after deserialization. Use `serialize` to write it back out.
+///
+/// `schema_version` is not stored: fields missing from older buffers take their
+/// defaults when read, so the copy is always current and is written out as
+/// `CURRENT_COLLIDER_SCHEMA_VERSION`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColliderComponent {
+    pub collider_type: ColliderType,
+    pub is_trigger: bool,
+    pub size: Option<Vec3>,
+    pub radius: f32,
+    pub height: f32,
+    pub material_name: Option<String>,
+    pub mesh_path: Option<String>,
+    pub layer: u32,
+    pub collision_mask: u32,
+    pub center: Option<Vec3>,
+    pub is_2d: bool,
+    pub size_2d: Option<Vec2>,
+    pub density: f32,
+    pub mass_override: f32,
+    pub debug_color: Option<Color>,
+    pub capsule_axis: CapsuleAxis,
+    pub mesh_is_convex: bool,
+    pub trigger_channel: Option<String>,
+    pub contact_margin: f32,
+    pub trigger_dwell_seconds: f32,
+    pub trigger_with_triggers: bool,
+    pub is_static: bool,
+    pub units: ColliderUnits,
+    pub surface_type: Option<String>,
+    pub trigger_debounce_seconds: f32,
+}
+
+impl Default for ColliderComponent {
+    /// Matches the schema defaults, i.e. what an empty table reads back as.
+    fn default() -> Self {
+        ColliderComponent {
+            collider_type: ColliderType::Box,
+            is_trigger: false,
+            size: None,
+            radius: 0.0,
+            height: 0.0,
+            material_name: None,
+            mesh_path: None,
+            layer: 0,
+            collision_mask: u32::MAX,
+            center: None,
+            is_2d: false,
+            size_2d: None,
+            density: 1.0,
+            mass_override: 0.0,
+            debug_color: None,
+            capsule_axis: CapsuleAxis::Y,
+            mesh_is_convex: false,
+            trigger_channel: None,
+            contact_margin: 0.01,
+            trigger_dwell_seconds: 0.0,
+            trigger_with_triggers: false,
+            is_static: false,
+            units: ColliderUnits::Meters,
+            surface_type: None,
+            trigger_debounce_seconds: 0.0,
+        }
+    }
+}
+
+impl From<ColliderComponentData<'_>> for ColliderComponent {
+    fn from(data: ColliderComponentData<'_>) -> Self {
+        ColliderComponent {
+            collider_type: data.collider_type(),
+            is_trigger: data.is_trigger(),
+            size: data.size().copied(),
+            radius: data.radius(),
+            height: data.height(),
+            material_name: data.material_name().map(str::to_owned),
+            mesh_path: data.mesh_path().map(str::to_owned),
+            layer: data.layer(),
+            collision_mask: data.collision_mask(),
+            center: data.center().copied(),
+            is_2d: data.is_2d(),
+            size_2d: data.size_2d().copied(),
+            density: data.density(),
+            mass_override: data.mass_override(),
+            debug_color: data.debug_color().copied(),
+            capsule_axis: data.capsule_axis(),
+            mesh_is_convex: data.mesh_is_convex(),
+            trigger_channel: data.trigger_channel().map(str::to_owned),
+            contact_margin: data.contact_margin(),
+            trigger_dwell_seconds: data.trigger_dwell_seconds(),
+            trigger_with_triggers: data.trigger_with_triggers(),
+            is_static: data.is_static(),
+            units: data.units(),
+            surface_type: data.surface_type().map(str::to_owned),
+            trigger_debounce_seconds: data.trigger_debounce_seconds(),
+        }
+    }
+}
+
+impl ColliderComponent {
+    /// Writes this component into `fbb` as a `ColliderComponentData` table.
+    pub fn serialize<'bldr, A: Allocator + 'bldr>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'bldr, A>,
+    ) -> WIPOffset<ColliderComponentData<'bldr>> {
+        let material_name = self.material_name.as_deref().map(|s| fbb.create_string(s));
+        let mesh_path = self.mesh_path.as_deref().map(|s| fbb.create_string(s));
+        let trigger_channel = self
+            .trigger_channel
+            .as_deref()
+            .map(|s| fbb.create_string(s));
+        let surface_type = self.surface_type.as_deref().map(|s| fbb.create_string(s));
+        let args = self.to_args(material_name, mesh_path, trigger_channel, surface_type);
+        ColliderComponentData::create(fbb, &args)
+    }
+
+    /// The args for writing this component, with the string fields already
+    /// created, e.g. shared through a `StringCache`. `schema_version` is set to
+    /// `CURRENT_COLLIDER_SCHEMA_VERSION`.
+    pub fn to_args<'a>(
+        &'a self,
+        material_name: Option<WIPOffset<&'a str>>,
+        mesh_path: Option<WIPOffset<&'a str>>,
+        trigger_channel: Option<WIPOffset<&'a str>>,
+        surface_type: Option<WIPOffset<&'a str>>,
+    ) -> ColliderComponentDataArgs<'a> {
+        ColliderComponentDataArgs {
+            collider_type: self.collider_type,
+            is_trigger: self.is_trigger,
+            size: self.size.as_ref(),
+            radius: self.radius,
+            height: self.height,
+            material_name,
+            mesh_path,
+            layer: self.layer,
+            collision_mask: self.collision_mask,
+            center: self.center.as_ref(),
+            is_2d: self.is_2d,
+            size_2d: self.size_2d.as_ref(),
+            density: self.density,
+            mass_override: self.mass_override,
+            schema_version: CURRENT_COLLIDER_SCHEMA_VERSION,
+            debug_color: self.debug_color.as_ref(),
+            capsule_axis: self.capsule_axis,
+            mesh_is_convex: self.mesh_is_convex,
+            trigger_channel,
+            contact_margin: self.contact_margin,
+            trigger_dwell_seconds: self.trigger_dwell_seconds,
+            trigger_with_triggers: self.trigger_with_triggers,
+            is_static: self.is_static,
+            units: self.units,
+            surface_type,
+            trigger_debounce_seconds: self.trigger_debounce_seconds,
+        }
+    }
+}
+
+/// Rewrites `data`, read from a buffer of any schema version, as a table at
+/// `CURRENT_COLLIDER_SCHEMA_VERSION`.
+///
+/// Fields the old buffer lacks take their schema defaults, which are chosen so
+/// that older colliders behave as before (no offset, every layer, density 1).
+/// Version-specific fix-ups belong here, keyed on `data.schema_version()`, if
+/// a later version changes the meaning of an existing field.
+pub fn migrate_collider<'bldr, A: Allocator + 'bldr>(
+    data: &ColliderComponentData,
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    ColliderComponent::from(*data).serialize(fbb)
+}
+
+/// Writes a copy of `data` with a uniform `factor` baked into its dimensions,
+/// for instancing a prefab at a different scale without a transform.
+///
+/// `size`, `size_2d`, `radius` and `height` are multiplied by `factor`, and so
+/// is the `center` offset, since it is a local-space position that moves with
+/// the shape. Everything else is copied as is, including `mass_override`.
+/// Mesh colliders are copied unchanged because their scale is applied to the
+/// mesh asset.
+pub fn scale_collider<'bldr, A: Allocator + 'bldr>(
+    data: &ColliderComponentData,
+    factor: f32,
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    let mut collider = ColliderComponent::from(*data);
+    scale_lengths(&mut collider, factor);
+    collider.serialize(fbb)
+}
+
+/// Writes a copy of `data` with its lengths converted from `from` units to
+/// `to` units and `units` set to `to`.
+///
+/// Meters and Pixels convert at `pixels_per_meter`; lengths are scaled as by
+/// `scale_collider`, so `center` is converted too and Mesh colliders keep
+/// their lengths. Converting to or from Unitless, or between equal units,
+/// only relabels the collider. `from` is taken as given rather than read from
+/// `data`, so mislabeled colliders can be fixed up.
+pub fn convert_collider_units<'bldr, A: Allocator + 'bldr>(
+    data: &ColliderComponentData,
+    from: ColliderUnits,
+    to: ColliderUnits,
+    pixels_per_meter: f32,
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    let factor = match (from, to) {
+        (ColliderUnits::Meters, ColliderUnits::Pixels) => pixels_per_meter,
+        (ColliderUnits::Pixels, ColliderUnits::Meters) => 1.0 / pixels_per_meter,
+        _ => 1.0,
+    };
+    let mut collider = ColliderComponent::from(*data);
+    scale_lengths(&mut collider, factor);
+    collider.units = to;
+    collider.serialize(fbb)
+}
+
+/// Multiplies the lengths of a non-mesh collider by `factor`.
+fn scale_lengths(collider: &mut ColliderComponent, factor: f32) {
+    if collider.collider_type == ColliderType::Mesh {
+        return;
+    }
+    let scale3 = |v: Vec3| Vec3::new(v.x() * factor, v.y() * factor, v.z() * factor);
+    collider.size = collider.size.map(scale3);
+    collider.center = collider.center.map(scale3);
+    collider.size_2d = collider
+        .size_2d
+        .map(|v| Vec2::new(v.x() * factor, v.y() * factor));
+    collider.radius *= factor;
+    collider.height *= factor;
+}
+
+/// The collider's `surface_type`, or `fallback` when it has none, for picking
+/// footstep and impact sounds.
+pub fn surface_type_or<'a>(data: &ColliderComponentData<'a>, fallback: &'a str) -> &'a str {
+    data.surface_type().unwrap_or(fallback)
+}
+
+/// The collider's `material_name`, or `fallback` when it has none.
+pub fn collider_material_or<'a>(data: &ColliderComponentData<'a>, fallback: &'a str) -> &'a str {
+    data.material_name().unwrap_or(fallback)
+}
+
+/// Conventional physics material for a shape whose collider names none:
+/// `"terrain"` for Mesh, which is mostly level geometry, and `"default"` for
+/// everything else. Games with other conventions use `DefaultMaterials`.
+pub fn default_material_for_type(collider_type: ColliderType) -> &'static str {
+    match collider_type {
+        ColliderType::Mesh => "terrain",
+        _ => "default",
+    }
+}
+
+/// The collider's `material_name`, or the conventional default for its type.
+pub fn collider_material_or_default<'a>(data: &ColliderComponentData<'a>) -> &'a str {
+    collider_material_or(data, default_material_for_type(data.collider_type()))
+}
+
+/// The collider's `mesh_path`, or `fallback` when it has none.
+pub fn collider_mesh_path_or<'a>(data: &ColliderComponentData<'a>, fallback: &'a str) -> &'a str {
+    data.mesh_path().unwrap_or(fallback)
+}
+
+/// The collider's `mesh_path` as a filesystem path.
+///
+/// The path is untrusted data from the buffer; check `mesh_path_is_safe`
+/// before joining it onto an asset root.
+pub fn mesh_path_as_path<'a>(data: &ColliderComponentData<'a>) -> Option<&'a Path> {
+    data.mesh_path().map(Path::new)
+}
+
+/// The event channel a trigger reports to: its `trigger_channel`, or
+/// `"default"` when it has none or is not a trigger at all.
+pub fn trigger_channel_or_default<'a>(data: &ColliderComponentData<'a>) -> &'a str {
+    match data.trigger_channel() {
+        Some(channel) if data.is_trigger() => channel,
+        _ => "default",
+    }
+}
+
+/// The overlay color used when a collider has no `debug_color`: translucent
+/// green for triggers, opaque orange for solid colliders.
+pub fn default_debug_color(is_trigger: bool) -> Color {
+    if is_trigger {
+        Color::new(0, 255, 0, 128)
+    } else {
+        Color::new(255, 160, 0, 255)
+    }
+}
+
+/// The collider's `debug_color`, or `default_debug_color` for its trigger flag.
+pub fn collider_debug_color(data: &ColliderComponentData) -> Color {
+    data.debug_color()
+        .copied()
+        .unwrap_or_else(|| default_debug_color(data.is_trigger()))
+}
+
+/// Whether the collider is a concave mesh, which must be cooked before the
+/// physics backend can use it.
+pub fn mesh_requires_cooking(data: &ColliderComponentData) -> bool {
+    data.collider_type() == ColliderType::Mesh && !data.mesh_is_convex()
+}
+
+/// Broadphase grouping of collider types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColliderKindGroup {
+    /// Analytic shapes: Box, Sphere, Capsule, Cylinder and Cone.
+    Primitive,
+    Mesh,
+    /// A `collider_type` value this build does not know.
+    Unknown,
+}
+
+pub fn collider_kind_group(data: &ColliderComponentData) -> ColliderKindGroup {
+    match data.collider_type() {
+        ColliderType::Box
+        | ColliderType::Sphere
+        | ColliderType::Capsule
+        | ColliderType::Cylinder
+        | ColliderType::Cone => ColliderKindGroup::Primitive,
+        ColliderType::Mesh => ColliderKindGroup::Mesh,
+        _ => ColliderKindGroup::Unknown,
+    }
+}
+
+pub fn collider_is_primitive(data: &ColliderComponentData) -> bool {
+    collider_kind_group(data) == ColliderKindGroup::Primitive
+}
+
+pub fn collider_is_mesh(data: &ColliderComponentData) -> bool {
+    collider_kind_group(data) == ColliderKindGroup::Mesh
+}
+
+/// Whether the collider is a trigger that fires only after an entity has
+/// stayed inside for `trigger_dwell_seconds`.
+pub fn trigger_requires_dwell(data: &ColliderComponentData) -> bool {
+    data.is_trigger() && data.trigger_dwell_seconds() > 0.0
+}
+
+/// Per-pair state for applying `trigger_debounce_seconds` to a trigger's
+/// events.
+///
+/// Keep one per trigger and entity pair (or per trigger, to debounce all of
+/// its events together) and ask it before raising each event.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TriggerDebouncer {
+    last_fired: Option<f32>,
+}
+
+impl TriggerDebouncer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether an event at time `now`, in seconds, should be raised given a
+    /// `debounce` interval, normally the collider's `trigger_debounce_seconds`.
+    ///
+    /// The first event always fires. Later ones fire once at least `debounce`
+    /// seconds have passed since the last event that fired; suppressed events
+    /// do not extend the wait, so a steady flicker still fires every
+    /// `debounce` seconds. A `debounce` of 0 or less fires every event.
+    pub fn should_fire(&mut self, now: f32, debounce: f32) -> bool {
+        let fire = self.last_fired.is_none_or(|last| now - last >= debounce);
+        if fire {
+            self.last_fired = Some(now);
+        }
+        fire
+    }
+
+    /// Forgets the last event, so the next one fires.
+    pub fn reset(&mut self) {
+        self.last_fired = None;
+    }
+}
+
+/// Whether contacts between `a` and `b` raise events, given their trigger
+/// settings.
+///
+/// A pair with at least one solid collider always interacts. Two triggers
+/// only interact when both set `trigger_with_triggers`, so overlapping zones
+/// stay silent unless each opts in. Layers and masks are checked separately.
+pub fn triggers_interact(a: &ColliderComponentData, b: &ColliderComponentData) -> bool {
+    !a.is_trigger() || !b.is_trigger() || (a.trigger_with_triggers() && b.trigger_with_triggers())
+}
+
+/// The collider's `contact_margin` with negative values clamped to 0, as the
+/// physics backend expects.
+pub fn effective_margin(data: &ColliderComponentData) -> f32 {
+    data.contact_margin().max(0.0)
+}
+
+impl ColliderType {
+    /// Looks up a variant by name, ignoring ASCII case; the inverse of `variant_name`.
+    pub fn from_name(name: &str) -> Option<ColliderType> {
+        Self::ENUM_VALUES.iter().copied().find(|t| {
+            t.variant_name()
+                .is_some_and(|v| v.eq_ignore_ascii_case(name))
+        })
+    }
+}
+
+/// Returned when a string names no `ColliderType` variant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseColliderTypeError {
+    pub name: String,
+}
+
+impl fmt::Display for ParseColliderTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown collider type `{}`", self.name)
+    }
+}
+
+impl std::error::Error for ParseColliderTypeError {}
+
+impl FromStr for ColliderType {
+    type Err = ParseColliderTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or_else(|| ParseColliderTypeError { name: s.to_owned() })
+    }
+}
+
+/// One-line summary for logs and debug overlays, e.g. `Box(size=1,2,1, trigger)`,
+/// `Sphere(r=0.5)` or `Mesh(path=foo.obj)`. Only the fields the shape uses are
+/// printed; `center` is included when set and 2D colliders are marked `2d`.
+impl fmt::Display for ColliderComponentData<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        match self.collider_type() {
+            ColliderType::Box if self.is_2d() => {
+                if let Some(size) = self.size_2d() {
+                    parts.push(format!("size={},{}", size.x(), size.y()));
+                }
+            }
+            ColliderType::Box => {
+                if let Some(size) = self.size() {
+                    parts.push(format!("size={},{},{}", size.x(), size.y(), size.z()));
+                }
+            }
+            ColliderType::Sphere => parts.push(format!("r={}", self.radius())),
+            ColliderType::Capsule => {
+                parts.push(format!("r={}", self.radius()));
+                parts.push(format!("h={}", self.height()));
+                if self.capsule_axis() != CapsuleAxis::Y {
+                    match self.capsule_axis().variant_name() {
+                        Some(axis) => parts.push(format!("axis={axis}")),
+                        None => parts.push(format!("axis={}", self.capsule_axis().0)),
+                    }
+                }
+            }
+            ColliderType::Cylinder | ColliderType::Cone => {
+                parts.push(format!("r={}", self.radius()));
+                parts.push(format!("h={}", self.height()));
+            }
+            ColliderType::Mesh => {
+                if let Some(path) = self.mesh_path() {
+                    parts.push(format!("path={path}"));
+                }
+                if self.mesh_is_convex() {
+                    parts.push("convex".to_owned());
+                }
+            }
+            _ => {}
+        }
+        if let Some(center) = self.center() {
+            parts.push(format!(
+                "center={},{},{}",
+                center.x(),
+                center.y(),
+                center.z()
+            ));
+        }
+        if self.is_2d() {
+            parts.push("2d".to_owned());
+        }
+        if self.is_trigger() {
+            parts.push("trigger".to_owned());
+        }
+        if self.is_static() {
+            parts.push("static".to_owned());
+        }
+
+        match self.collider_type().variant_name() {
+            Some(name) => f.write_str(name)?,
+            None => write!(f, "ColliderType({})", self.collider_type().0)?,
+        }
+        if !parts.is_empty() {
+            write!(f, "({})", parts.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// `ColliderType` and `CapsuleAxis` serialize as their variant name, or as the
+/// raw integer for values this build does not know about, so unknown values
+/// survive a round trip through JSON. Names are read case-insensitively, as
+/// with `FromStr`.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use crate::collider_component_generated::*;
+
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum EnumRepr {
+        Name(String),
+        Value(i8),
+    }
+
+    impl Serialize for ColliderType {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.variant_name() {
+                Some(name) => serializer.serialize_str(name),
+                None => serializer.serialize_i8(self.0),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ColliderType {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            match EnumRepr::deserialize(deserializer)? {
+                EnumRepr::Name(name) => name.parse().map_err(D::Error::custom),
+                EnumRepr::Value(value) => Ok(ColliderType(value)),
+            }
+        }
+    }
+
+    impl Serialize for CapsuleAxis {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.variant_name() {
+                Some(name) => serializer.serialize_str(name),
+                None => serializer.serialize_i8(self.0),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for CapsuleAxis {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            match EnumRepr::deserialize(deserializer)? {
+                EnumRepr::Name(name) => CapsuleAxis::ENUM_VALUES
+                    .iter()
+                    .copied()
+                    .find(|a| {
+                        a.variant_name()
+                            .is_some_and(|v| v.eq_ignore_ascii_case(&name))
+                    })
+                    .ok_or_else(|| D::Error::custom(format!("unknown capsule axis `{name}`"))),
+                EnumRepr::Value(value) => Ok(CapsuleAxis(value)),
+            }
+        }
+    }
+
+    impl Serialize for ColliderUnits {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.variant_name() {
+                Some(name) => serializer.serialize_str(name),
+                None => serializer.serialize_i8(self.0),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ColliderUnits {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            match EnumRepr::deserialize(deserializer)? {
+                EnumRepr::Name(name) => ColliderUnits::ENUM_VALUES
+                    .iter()
+                    .copied()
+                    .find(|u| {
+                        u.variant_name()
+                            .is_some_and(|v| v.eq_ignore_ascii_case(&name))
+                    })
+                    .ok_or_else(|| D::Error::custom(format!("unknown collider units `{name}`"))),
+                EnumRepr::Value(value) => Ok(ColliderUnits(value)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(collider: &ColliderComponent) -> ColliderComponent {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = collider.serialize(&mut fbb);
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        ColliderComponent::from(root_as_collider_component_data(fbb.finished_data()).unwrap())
+    }
+
+    #[test]
+    fn each_shape_round_trips() {
+        let shapes = [
+            ColliderComponent {
+                size: Some(Vec3::new(1.0, 2.0, 3.0)),
+                ..Default::default()
+            },
+            ColliderComponent {
+                collider_type: ColliderType::Sphere,
+                radius: 0.5,
+                is_trigger: true,
+                material_name: Some("rubber".into()),
+                ..Default::default()
+            },
+            ColliderComponent {
+                collider_type: ColliderType::Capsule,
+                radius: 0.3,
+                height: 1.8,
+                ..Default::default()
+            },
+            ColliderComponent {
+                collider_type: ColliderType::Mesh,
+                mesh_path: Some("meshes/rock.obj".into()),
+                ..Default::default()
+            },
+        ];
+        for collider in shapes {
+            assert_eq!(round_trip(&collider), collider);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn capsule_serializes_to_exact_json() {
+        let capsule = ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            size: Some(Vec3::new(1.0, 0.0, 0.0)),
+            radius: 0.5,
+            height: 2.0,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&capsule).unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"collider_type":"Capsule","is_trigger":false,"#,
+                r#""size":{"x":1.0,"y":0.0,"z":0.0},"radius":0.5,"height":2.0,"#,
+                r#""material_name":null,"mesh_path":null,"layer":0,"#,
+                r#""collision_mask":4294967295,"center":null,"is_2d":false,"#,
+                r#""size_2d":null,"density":1.0,"mass_override":0.0,"#,
+                r#""debug_color":null,"capsule_axis":"Y","mesh_is_convex":false,"#,
+                r#""trigger_channel":null,"contact_margin":0.01,"#,
+                r#""trigger_dwell_seconds":0.0,"trigger_with_triggers":false,"#,
+                r#""is_static":false,"units":"Meters","surface_type":null,"#,
+                r#""trigger_debounce_seconds":0.0}"#,
+            )
+        );
+        let back: ColliderComponent = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, capsule);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn unknown_collider_type_serializes_as_its_value() {
+        let unknown = ColliderComponent {
+            collider_type: ColliderType(42),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&unknown).unwrap();
+        assert!(json.starts_with(r#"{"collider_type":42,"#), "{json}");
+        assert_eq!(
+            serde_json::from_str::<ColliderComponent>(&json).unwrap(),
+            unknown
+        );
+    }
+
+    #[test]
+    fn old_buffer_reads_default_layer_and_mask() {
+        // A table written before `layer` and `collision_mask` existed.
+        let mut fbb = FlatBufferBuilder::new();
+        let start = fbb.start_table();
+        fbb.push_slot::<f32>(ColliderComponentData::VT_RADIUS, 2.0, 0.0);
+        let offset = fbb.end_table(start);
+        fbb.finish(offset, Some(COLLIDER_COMPONENT_DATA_IDENTIFIER));
+        let data = root_as_collider_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(data.radius(), 2.0);
+        assert_eq!(data.layer(), 0);
+        assert_eq!(data.collision_mask(), u32::MAX);
+
+        let layered = ColliderComponent {
+            layer: 4,
+            collision_mask: 0b11,
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&layered), layered);
+    }
+
+    #[test]
+    fn center_round_trips() {
+        let capsule = ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.3,
+            height: 1.8,
+            center: Some(Vec3::new(0.0, 0.9, 0.0)),
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&capsule), capsule);
+        assert_eq!(round_trip(&ColliderComponent::default()).center, None);
+    }
+
+    #[test]
+    fn cylinder_round_trips_and_prints_its_name() {
+        let cylinder = ColliderComponent {
+            collider_type: ColliderType::Cylinder,
+            radius: 0.5,
+            height: 1.0,
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&cylinder), cylinder);
+        assert_eq!(ColliderType::Cylinder.variant_name(), Some("Cylinder"));
+        assert_eq!(format!("{:?}", ColliderType::Cylinder), "Cylinder");
+        assert_eq!(format!("{:?}", ColliderType::Cone), "Cone");
+    }
+
+    fn printed(collider: &ColliderComponent) -> String {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = collider.serialize(&mut fbb);
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        root_as_collider_component_data(fbb.finished_data())
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn display_prints_only_the_shape_fields() {
+        let cube = ColliderComponent {
+            size: Some(Vec3::new(1.0, 2.0, 1.0)),
+            is_trigger: true,
+            ..Default::default()
+        };
+        assert_eq!(printed(&cube), "Box(size=1,2,1, trigger)");
+        let sphere = ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 0.5,
+            height: 3.0,
+            ..Default::default()
+        };
+        assert_eq!(printed(&sphere), "Sphere(r=0.5)");
+        let mesh = ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("foo.obj".into()),
+            ..Default::default()
+        };
+        assert_eq!(printed(&mesh), "Mesh(path=foo.obj)");
+    }
+
+    #[test]
+    fn display_names_unknown_types_by_value() {
+        let unknown = ColliderComponent {
+            collider_type: ColliderType(9),
+            ..Default::default()
+        };
+        assert_eq!(printed(&unknown), "ColliderType(9)");
+    }
+
+    #[test]
+    fn box_2d_round_trips_and_prints_as_2d() {
+        let flat = ColliderComponent {
+            is_2d: true,
+            size_2d: Some(Vec2::new(2.0, 3.0)),
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&flat), flat);
+        assert_eq!(printed(&flat), "Box(size=2,3, 2d)");
+        assert!(!round_trip(&ColliderComponent::default()).is_2d);
+    }
+
+    #[test]
+    fn collider_type_parses_names_ignoring_case() {
+        assert_eq!("Sphere".parse::<ColliderType>(), Ok(ColliderType::Sphere));
+        assert_eq!("sphere".parse::<ColliderType>(), Ok(ColliderType::Sphere));
+        assert_eq!("MESH".parse::<ColliderType>(), Ok(ColliderType::Mesh));
+        assert_eq!(ColliderType::from_name("cone"), Some(ColliderType::Cone));
+        for &t in ColliderType::ENUM_VALUES {
+            assert_eq!(t.variant_name().unwrap().parse::<ColliderType>(), Ok(t));
+        }
+    }
+
+    #[test]
+    fn unknown_collider_type_name_is_an_error() {
+        let err = "blob".parse::<ColliderType>().unwrap_err();
+        assert_eq!(err.name, "blob");
+        assert_eq!(err.to_string(), "unknown collider type `blob`");
+        assert_eq!(ColliderType::from_name(""), None);
+    }
+
+    #[test]
+    fn density_defaults_to_one_and_round_trips() {
+        assert_eq!(round_trip(&ColliderComponent::default()).density, 1.0);
+        let heavy = ColliderComponent {
+            density: 7.8,
+            mass_override: 12.0,
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&heavy), heavy);
+    }
+
+    fn buffer(collider: &ColliderComponent) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = collider.serialize(&mut fbb);
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        fbb.finished_data().to_vec()
+    }
+
+    fn read(buf: &[u8]) -> ColliderComponentData<'_> {
+        root_as_collider_component_data(buf).unwrap()
+    }
+
+    #[test]
+    fn string_fallbacks_apply_only_when_absent() {
+        let named = buffer(&ColliderComponent {
+            material_name: Some("ice".into()),
+            mesh_path: Some("rock.obj".into()),
+            ..Default::default()
+        });
+        assert_eq!(collider_material_or(&read(&named), "default"), "ice");
+        assert_eq!(collider_mesh_path_or(&read(&named), "none.obj"), "rock.obj");
+
+        let unnamed = buffer(&ColliderComponent::default());
+        assert_eq!(collider_material_or(&read(&unnamed), "default"), "default");
+        assert_eq!(
+            collider_mesh_path_or(&read(&unnamed), "none.obj"),
+            "none.obj"
+        );
+    }
+
+    #[test]
+    fn migrate_stamps_the_current_version_and_keeps_fields() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = ColliderComponentData::create(
+            &mut fbb,
+            &ColliderComponentDataArgs {
+                collider_type: ColliderType::Sphere,
+                radius: 2.0,
+                ..Default::default()
+            },
+        );
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        let old = fbb.finished_data().to_vec();
+        assert_eq!(read(&old).schema_version(), 1);
+
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = migrate_collider(&read(&old), &mut fbb);
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        let migrated = read(fbb.finished_data());
+        assert_eq!(migrated.schema_version(), CURRENT_COLLIDER_SCHEMA_VERSION);
+        assert_eq!(migrated.collider_type(), ColliderType::Sphere);
+        assert_eq!(migrated.radius(), 2.0);
+        assert_eq!(migrated.collision_mask(), u32::MAX);
+    }
+
+    #[test]
+    fn debug_color_round_trips_and_falls_back_by_trigger_flag() {
+        let tinted = ColliderComponent {
+            debug_color: Some(Color::new(1, 2, 3, 4)),
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&tinted), tinted);
+        let buf = buffer(&tinted);
+        assert_eq!(collider_debug_color(&read(&buf)), Color::new(1, 2, 3, 4));
+
+        let trigger = buffer(&ColliderComponent {
+            is_trigger: true,
+            ..Default::default()
+        });
+        assert_eq!(
+            collider_debug_color(&read(&trigger)),
+            default_debug_color(true)
+        );
+        let solid = buffer(&ColliderComponent::default());
+        assert_eq!(
+            collider_debug_color(&read(&solid)),
+            default_debug_color(false)
+        );
+        assert_ne!(default_debug_color(true), default_debug_color(false));
+    }
+
+    #[test]
+    fn capsule_axis_defaults_to_y_and_round_trips() {
+        assert_eq!(
+            round_trip(&ColliderComponent::default()).capsule_axis,
+            CapsuleAxis::Y
+        );
+        let lying = ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5,
+            height: 2.0,
+            capsule_axis: CapsuleAxis::Z,
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&lying), lying);
+        assert_eq!(printed(&lying), "Capsule(r=0.5, h=2, axis=Z)");
+    }
+
+    #[test]
+    fn mesh_path_reads_as_a_path() {
+        let mesh = buffer(&ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("a/b.obj".into()),
+            ..Default::default()
+        });
+        assert_eq!(mesh_path_as_path(&read(&mesh)), Some(Path::new("a/b.obj")));
+        let none = buffer(&ColliderComponent::default());
+        assert_eq!(mesh_path_as_path(&read(&none)), None);
+    }
+
+    #[test]
+    fn only_concave_meshes_need_cooking() {
+        let convex = ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("rock.obj".into()),
+            mesh_is_convex: true,
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&convex), convex);
+        assert!(!mesh_requires_cooking(&read(&buffer(&convex))));
+        let concave = ColliderComponent {
+            mesh_is_convex: false,
+            ..convex
+        };
+        assert!(mesh_requires_cooking(&read(&buffer(&concave))));
+        assert!(!mesh_requires_cooking(&read(&buffer(
+            &ColliderComponent::default()
+        ))));
+    }
+
+    #[test]
+    fn scale_collider_scales_dimensions_and_center() {
+        let capsule = buffer(&ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5,
+            height: 2.0,
+            center: Some(Vec3::new(0.0, 1.0, 0.0)),
+            mass_override: 3.0,
+            ..Default::default()
+        });
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = scale_collider(&read(&capsule), 2.0, &mut fbb);
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        let scaled = read(fbb.finished_data());
+        assert_eq!(scaled.radius(), 1.0);
+        assert_eq!(scaled.height(), 4.0);
+        assert_eq!(scaled.center(), Some(&Vec3::new(0.0, 2.0, 0.0)));
+        assert_eq!(scaled.mass_override(), 3.0);
+    }
+
+    #[test]
+    fn scale_collider_scales_box_sizes() {
+        let flat = buffer(&ColliderComponent {
+            size: Some(Vec3::new(1.0, 2.0, 3.0)),
+            size_2d: Some(Vec2::new(4.0, 5.0)),
+            ..Default::default()
+        });
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = scale_collider(&read(&flat), 0.5, &mut fbb);
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        let scaled = read(fbb.finished_data());
+        assert_eq!(scaled.size(), Some(&Vec3::new(0.5, 1.0, 1.5)));
+        assert_eq!(scaled.size_2d(), Some(&Vec2::new(2.0, 2.5)));
+    }
+
+    #[test]
+    fn scale_collider_leaves_meshes_unchanged() {
+        let mesh = ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("rock.obj".into()),
+            size: Some(Vec3::new(1.0, 1.0, 1.0)),
+            ..Default::default()
+        };
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = scale_collider(&read(&buffer(&mesh)), 3.0, &mut fbb);
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        assert_eq!(ColliderComponent::from(read(fbb.finished_data())), mesh);
+    }
+
+    #[test]
+    fn trigger_channel_round_trips_and_defaults() {
+        let checkpoint = ColliderComponent {
+            is_trigger: true,
+            trigger_channel: Some("checkpoint".into()),
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&checkpoint), checkpoint);
+        let buf = buffer(&checkpoint);
+        assert_eq!(trigger_channel_or_default(&read(&buf)), "checkpoint");
+
+        let solid = buffer(&ColliderComponent {
+            is_trigger: false,
+            ..checkpoint.clone()
+        });
+        assert_eq!(trigger_channel_or_default(&read(&solid)), "default");
+        let unrouted = buffer(&ColliderComponent {
+            trigger_channel: None,
+            ..checkpoint
+        });
+        assert_eq!(trigger_channel_or_default(&read(&unrouted)), "default");
+    }
+
+    #[test]
+    fn contact_margin_defaults_and_clamps() {
+        assert_eq!(
+            round_trip(&ColliderComponent::default()).contact_margin,
+            0.01
+        );
+        let padded = ColliderComponent {
+            contact_margin: 0.05,
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&padded), padded);
+        assert_eq!(effective_margin(&read(&buffer(&padded))), 0.05);
+        let negative = buffer(&ColliderComponent {
+            contact_margin: -1.0,
+            ..Default::default()
+        });
+        assert_eq!(effective_margin(&read(&negative)), 0.0);
+    }
+
+    #[test]
+    fn kind_groups_split_primitives_from_meshes() {
+        let group_of = |collider_type| {
+            let buf = buffer(&ColliderComponent {
+                collider_type,
+                ..Default::default()
+            });
+            let data = read(&buf);
+            (
+                collider_kind_group(&data),
+                collider_is_primitive(&data),
+                collider_is_mesh(&data),
+            )
+        };
+        for primitive in [
+            ColliderType::Box,
+            ColliderType::Sphere,
+            ColliderType::Capsule,
+            ColliderType::Cylinder,
+            ColliderType::Cone,
+        ] {
+            assert_eq!(
+                group_of(primitive),
+                (ColliderKindGroup::Primitive, true, false)
+            );
+        }
+        assert_eq!(
+            group_of(ColliderType::Mesh),
+            (ColliderKindGroup::Mesh, false, true)
+        );
+        assert_eq!(
+            group_of(ColliderType(42)),
+            (ColliderKindGroup::Unknown, false, false)
+        );
+    }
+
+    #[test]
+    fn only_triggers_with_a_dwell_time_require_dwell() {
+        let lingering = ColliderComponent {
+            is_trigger: true,
+            trigger_dwell_seconds: 2.5,
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&lingering), lingering);
+        assert!(trigger_requires_dwell(&read(&buffer(&lingering))));
+        let solid = ColliderComponent {
+            is_trigger: false,
+            ..lingering.clone()
+        };
+        assert!(!trigger_requires_dwell(&read(&buffer(&solid))));
+        let instant = ColliderComponent {
+            trigger_dwell_seconds: 0.0,
+            ..lingering
+        };
+        assert!(!trigger_requires_dwell(&read(&buffer(&instant))));
+    }
+
+    #[test]
+    fn triggers_only_interact_when_both_opt_in() {
+        let solid = buffer(&ColliderComponent::default());
+        let zone = buffer(&ColliderComponent {
+            is_trigger: true,
+            ..Default::default()
+        });
+        let nested = ColliderComponent {
+            is_trigger: true,
+            trigger_with_triggers: true,
+            ..Default::default()
+        };
+        let nested_zone = buffer(&nested);
+        let larger_nested_zone = buffer(&ColliderComponent {
+            radius: 2.0,
+            ..nested.clone()
+        });
+
+        assert!(!read(&solid).trigger_with_triggers());
+        assert!(triggers_interact(&read(&solid), &read(&zone)));
+        assert!(triggers_interact(&read(&zone), &read(&solid)));
+        assert!(!triggers_interact(&read(&zone), &read(&zone)));
+        assert!(!triggers_interact(&read(&zone), &read(&nested_zone)));
+        assert!(triggers_interact(
+            &read(&nested_zone),
+            &read(&larger_nested_zone)
+        ));
+        assert_eq!(round_trip(&nested), nested);
+    }
+
+    #[test]
+    fn is_static_round_trips_and_defaults_to_dynamic() {
+        let floor = ColliderComponent {
+            is_static: true,
+            size: Some(Vec3::new(1.0, 1.0, 1.0)),
+            ..Default::default()
+        };
+        let buf = buffer(&floor);
+        assert!(read(&buf).is_static());
+        assert_eq!(round_trip(&floor), floor);
+        assert_eq!(read(&buf).to_string(), "Box(size=1,1,1, static)");
+        assert!(!read(&buffer(&ColliderComponent::default())).is_static());
+    }
+
+    fn converted(
+        collider: &ColliderComponent,
+        from: ColliderUnits,
+        to: ColliderUnits,
+    ) -> ColliderComponent {
+        let buf = buffer(collider);
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = convert_collider_units(&read(&buf), from, to, 16.0, &mut fbb);
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        ColliderComponent::from(read(fbb.finished_data()))
+    }
+
+    #[test]
+    fn units_round_trip_and_default_to_meters() {
+        let sprite = ColliderComponent {
+            units: ColliderUnits::Pixels,
+            size: Some(Vec3::new(32.0, 16.0, 8.0)),
+            ..Default::default()
+        };
+        assert_eq!(round_trip(&sprite), sprite);
+        assert_eq!(
+            read(&buffer(&ColliderComponent::default())).units(),
+            ColliderUnits::Meters
+        );
+    }
+
+    #[test]
+    fn converting_pixels_to_meters_scales_lengths() {
+        let sprite = ColliderComponent {
+            units: ColliderUnits::Pixels,
+            size: Some(Vec3::new(32.0, 16.0, 8.0)),
+            center: Some(Vec3::new(0.0, 8.0, 0.0)),
+            ..Default::default()
+        };
+        let meters = converted(&sprite, ColliderUnits::Pixels, ColliderUnits::Meters);
+        assert_eq!(meters.units, ColliderUnits::Meters);
+        assert_eq!(meters.size, Some(Vec3::new(2.0, 1.0, 0.5)));
+        assert_eq!(meters.center, Some(Vec3::new(0.0, 0.5, 0.0)));
+    }
+
+    #[test]
+    fn converting_to_unitless_keeps_lengths() {
+        let capsule = ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5,
+            height: 2.0,
+            ..Default::default()
+        };
+        let pixels = converted(&capsule, ColliderUnits::Meters, ColliderUnits::Pixels);
+        assert_eq!((pixels.radius, pixels.height), (8.0, 32.0));
+        assert_eq!(pixels.units, ColliderUnits::Pixels);
+        let unitless = converted(&capsule, ColliderUnits::Meters, ColliderUnits::Unitless);
+        assert_eq!(unitless.radius, 0.5);
+        assert_eq!(unitless.units, ColliderUnits::Unitless);
+    }
+
+    #[test]
+    fn surface_type_is_separate_from_the_material() {
+        let gravel = ColliderComponent {
+            material_name: Some("ice".into()),
+            surface_type: Some("gravel".into()),
+            ..Default::default()
+        };
+        let buf = buffer(&gravel);
+        let data = read(&buf);
+        assert_eq!(data.surface_type(), Some("gravel"));
+        assert_eq!(data.material_name(), Some("ice"));
+        assert_eq!(surface_type_or(&data, "default"), "gravel");
+        assert_eq!(round_trip(&gravel), gravel);
+
+        let old = buffer(&ColliderComponent::default());
+        assert_eq!(read(&old).surface_type(), None);
+        assert_eq!(surface_type_or(&read(&old), "default"), "default");
+    }
+
+    #[test]
+    fn trigger_debounce_round_trips_and_defaults_to_zero() {
+        let zone = ColliderComponent {
+            is_trigger: true,
+            trigger_debounce_seconds: 0.2,
+            ..Default::default()
+        };
+        assert_eq!(read(&buffer(&zone)).trigger_debounce_seconds(), 0.2);
+        assert_eq!(round_trip(&zone), zone);
+        assert_eq!(
+            read(&buffer(&ColliderComponent::default())).trigger_debounce_seconds(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn debouncer_suppresses_events_inside_the_window() {
+        let mut debouncer = TriggerDebouncer::new();
+        assert!(debouncer.should_fire(1.0, 0.2));
+        assert!(!debouncer.should_fire(1.05, 0.2));
+        assert!(!debouncer.should_fire(1.15, 0.2));
+        assert!(debouncer.should_fire(1.25, 0.2));
+        assert!(debouncer.should_fire(1.25, 0.0));
+        debouncer.reset();
+        assert!(debouncer.should_fire(1.3, 0.2));
+    }
+}
+
+/// Property test that every field survives `serialize` and
+/// `From<ColliderComponentData>` unchanged.
+///
+/// Floats are drawn from every bit pattern, so NaN payloads, infinities and
+/// subnormals all come up, and are compared by bits. The one exception is
+/// `-0.0` in a scalar field: it compares equal to a `0.0` default, so the
+/// builder leaves the field out and it reads back as `0.0`. Strings are any
+/// Unicode, including NUL, up to 32 characters.
+///
+/// On failure proptest shrinks the input before reporting it: floats move
+/// toward `0.0`, strings get shorter, optional fields become `None` and enums
+/// move toward their first variant, so the reported collider is close to the
+/// smallest one that still fails. The failing seed is saved under
+/// `proptest-regressions/` next to this file and replayed first on later
+/// runs; commit it along with the fix.
+#[cfg(test)]
+mod proptests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    fn any_f32() -> impl Strategy<Value = f32> {
+        prop_oneof![any::<f32>(), any::<u32>().prop_map(f32::from_bits)]
+    }
+
+    fn any_vec3() -> impl Strategy<Value = Vec3> {
+        (any_f32(), any_f32(), any_f32()).prop_map(|(x, y, z)| Vec3::new(x, y, z))
+    }
+
+    fn any_string() -> impl Strategy<Value = Option<String>> {
+        proptest::option::of(
+            proptest::collection::vec(any::<char>(), 0..=32).prop_map(String::from_iter),
+        )
+    }
+
+    prop_compose! {
+        fn any_collider()(
+            (collider_type, is_trigger, size, radius, height, is_2d) in (
+                any::<i8>(),
+                any::<bool>(),
+                proptest::option::of(any_vec3()),
+                any_f32(),
+                any_f32(),
+                any::<bool>(),
+            ),
+            (material_name, mesh_path, trigger_channel, surface_type) in (
+                any_string(),
+                any_string(),
+                any_string(),
+                any_string(),
+            ),
+            (layer, collision_mask, center, size_2d, debug_color) in (
+                any::<u32>(),
+                any::<u32>(),
+                proptest::option::of(any_vec3()),
+                proptest::option::of((any_f32(), any_f32()).prop_map(|(x, y)| Vec2::new(x, y))),
+                proptest::option::of(any::<[u8; 4]>().prop_map(|[r, g, b, a]| Color::new(r, g, b, a))),
+            ),
+            (density, mass_override, contact_margin, trigger_dwell_seconds, trigger_debounce_seconds) in (
+                any_f32(),
+                any_f32(),
+                any_f32(),
+                any_f32(),
+                any_f32(),
+            ),
+            (capsule_axis, units, mesh_is_convex, trigger_with_triggers, is_static) in (
+                any::<i8>(),
+                any::<i8>(),
+                any::<bool>(),
+                any::<bool>(),
+                any::<bool>(),
+            ),
+        ) -> ColliderComponent {
+            ColliderComponent {
+                collider_type: ColliderType(collider_type),
+                is_trigger,
+                size,
+                radius,
+                height,
+                material_name,
+                mesh_path,
+                layer,
+                collision_mask,
+                center,
+                is_2d,
+                size_2d,
+                density,
+                mass_override,
+                debug_color,
+                capsule_axis: CapsuleAxis(capsule_axis),
+                mesh_is_convex,
+                trigger_channel,
+                contact_margin,
+                trigger_dwell_seconds,
+                trigger_with_triggers,
+                is_static,
+                units: ColliderUnits(units),
+                surface_type,
+                trigger_debounce_seconds,
+            }
+        }
+    }
+
+    /// Bits of a scalar field, with `-0.0` read as the `0.0` it is stored as.
+    fn scalar_bits(value: f32) -> u32 {
+        if value == 0.0 {
+            0
+        } else {
+            value.to_bits()
+        }
+    }
+
+    fn vec3_bits(v: &Option<Vec3>) -> Option<[u32; 3]> {
+        v.map(|v| [v.x().to_bits(), v.y().to_bits(), v.z().to_bits()])
+    }
+
+    fn assert_lossless(a: &ColliderComponent, b: &ColliderComponent) -> Result<(), TestCaseError> {
+        prop_assert_eq!(a.collider_type, b.collider_type);
+        prop_assert_eq!(a.is_trigger, b.is_trigger);
+        prop_assert_eq!(vec3_bits(&a.size), vec3_bits(&b.size));
+        prop_assert_eq!(scalar_bits(a.radius), scalar_bits(b.radius));
+        prop_assert_eq!(scalar_bits(a.height), scalar_bits(b.height));
+        prop_assert_eq!(&a.material_name, &b.material_name);
+        prop_assert_eq!(&a.mesh_path, &b.mesh_path);
+        prop_assert_eq!(a.layer, b.layer);
+        prop_assert_eq!(a.collision_mask, b.collision_mask);
+        prop_assert_eq!(vec3_bits(&a.center), vec3_bits(&b.center));
+        prop_assert_eq!(a.is_2d, b.is_2d);
+        prop_assert_eq!(
+            a.size_2d.map(|v| [v.x().to_bits(), v.y().to_bits()]),
+            b.size_2d.map(|v| [v.x().to_bits(), v.y().to_bits()])
+        );
+        prop_assert_eq!(a.density.to_bits(), b.density.to_bits());
+        prop_assert_eq!(scalar_bits(a.mass_override), scalar_bits(b.mass_override));
+        prop_assert_eq!(a.debug_color, b.debug_color);
+        prop_assert_eq!(a.capsule_axis, b.capsule_axis);
+        prop_assert_eq!(a.mesh_is_convex, b.mesh_is_convex);
+        prop_assert_eq!(&a.trigger_channel, &b.trigger_channel);
+        prop_assert_eq!(a.contact_margin.to_bits(), b.contact_margin.to_bits());
+        prop_assert_eq!(
+            scalar_bits(a.trigger_dwell_seconds),
+            scalar_bits(b.trigger_dwell_seconds)
+        );
+        prop_assert_eq!(a.trigger_with_triggers, b.trigger_with_triggers);
+        prop_assert_eq!(a.is_static, b.is_static);
+        prop_assert_eq!(a.units, b.units);
+        prop_assert_eq!(&a.surface_type, &b.surface_type);
+        prop_assert_eq!(
+            scalar_bits(a.trigger_debounce_seconds),
+            scalar_bits(b.trigger_debounce_seconds)
+        );
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn serialize_round_trips_every_field(collider in any_collider()) {
+            let mut fbb = FlatBufferBuilder::new();
+            let offset = collider.serialize(&mut fbb);
+            finish_collider_component_data_buffer(&mut fbb, offset);
+            let data = root_as_collider_component_data(fbb.finished_data()).unwrap();
+            prop_assert_eq!(data.schema_version(), CURRENT_COLLIDER_SCHEMA_VERSION);
+            assert_lossless(&collider, &ColliderComponent::from(data))?;
+        }
+    }
+}
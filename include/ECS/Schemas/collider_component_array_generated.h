@@ -0,0 +1,123 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_COLLIDERCOMPONENTARRAY_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_COLLIDERCOMPONENTARRAY_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+#include "collider_component_generated.h"
+
+namespace PixelCraft {
+namespace ECS {
+
+struct ColliderComponentArrayData;
+struct ColliderComponentArrayDataBuilder;
+
+struct ColliderComponentArrayData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef ColliderComponentArrayDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_COLLIDERS = 4
+  };
+  const ::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>> *colliders() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>> *>(VT_COLLIDERS);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_COLLIDERS) &&
+           verifier.VerifyVector(colliders()) &&
+           verifier.VerifyVectorOfTables(colliders()) &&
+           verifier.EndTable();
+  }
+};
+
+struct ColliderComponentArrayDataBuilder {
+  typedef ColliderComponentArrayData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_colliders(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>>> colliders) {
+    fbb_.AddOffset(ColliderComponentArrayData::VT_COLLIDERS, colliders);
+  }
+  explicit ColliderComponentArrayDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<ColliderComponentArrayData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<ColliderComponentArrayData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<ColliderComponentArrayData> CreateColliderComponentArrayData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>>> colliders = 0) {
+  ColliderComponentArrayDataBuilder builder_(_fbb);
+  builder_.add_colliders(colliders);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<ColliderComponentArrayData> CreateColliderComponentArrayDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const std::vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>> *colliders = nullptr) {
+  auto colliders__ = colliders ? _fbb.CreateVector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>>(*colliders) : 0;
+  return PixelCraft::ECS::CreateColliderComponentArrayData(
+      _fbb,
+      colliders__);
+}
+
+inline const PixelCraft::ECS::ColliderComponentArrayData *GetColliderComponentArrayData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::ColliderComponentArrayData>(buf);
+}
+
+inline const PixelCraft::ECS::ColliderComponentArrayData *GetSizePrefixedColliderComponentArrayData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::ColliderComponentArrayData>(buf);
+}
+
+inline const char *ColliderComponentArrayDataIdentifier() {
+  return "CLDA";
+}
+
+inline bool ColliderComponentArrayDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, ColliderComponentArrayDataIdentifier());
+}
+
+inline bool SizePrefixedColliderComponentArrayDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, ColliderComponentArrayDataIdentifier(), true);
+}
+
+inline bool VerifyColliderComponentArrayDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::ColliderComponentArrayData>(ColliderComponentArrayDataIdentifier());
+}
+
+inline bool VerifySizePrefixedColliderComponentArrayDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::ColliderComponentArrayData>(ColliderComponentArrayDataIdentifier());
+}
+
+inline void FinishColliderComponentArrayDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentArrayData> root) {
+  fbb.Finish(root, ColliderComponentArrayDataIdentifier());
+}
+
+inline void FinishSizePrefixedColliderComponentArrayDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentArrayData> root) {
+  fbb.FinishSizePrefixed(root, ColliderComponentArrayDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_COLLIDERCOMPONENTARRAY_PIXELCRAFT_ECS_H_
@@ -0,0 +1,217 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use crate::common_types_generated::*;
+use crate::collider_component_generated::*;
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use crate::common_types_generated::*;
+  use crate::collider_component_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use crate::common_types_generated::*;
+  use crate::collider_component_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum ColliderComponentArrayDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ColliderComponentArrayData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ColliderComponentArrayData<'a> {
+  type Inner = ColliderComponentArrayData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ColliderComponentArrayData<'a> {
+  pub const VT_COLLIDERS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ColliderComponentArrayData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ColliderComponentArrayDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<ColliderComponentArrayData<'bldr>> {
+    let mut builder = ColliderComponentArrayDataBuilder::new(_fbb);
+    if let Some(x) = args.colliders { builder.add_colliders(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn colliders(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderComponentData<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderComponentData>>>>(ColliderComponentArrayData::VT_COLLIDERS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ColliderComponentArrayData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ColliderComponentData>>>>("colliders", Self::VT_COLLIDERS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ColliderComponentArrayDataArgs<'a> {
+    pub colliders: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderComponentData<'a>>>>>,
+}
+impl<'a> Default for ColliderComponentArrayDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ColliderComponentArrayDataArgs {
+      colliders: None,
+    }
+  }
+}
+
+pub struct ColliderComponentArrayDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ColliderComponentArrayDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_colliders(&mut self, colliders: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ColliderComponentData<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ColliderComponentArrayData::VT_COLLIDERS, colliders);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ColliderComponentArrayDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ColliderComponentArrayDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ColliderComponentArrayData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ColliderComponentArrayData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ColliderComponentArrayData");
+      ds.field("colliders", &self.colliders());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `ColliderComponentArrayData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_collider_component_array_data_unchecked`.
+pub fn root_as_collider_component_array_data(buf: &[u8]) -> Result<ColliderComponentArrayData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<ColliderComponentArrayData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `ColliderComponentArrayData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_collider_component_array_data_unchecked`.
+pub fn size_prefixed_root_as_collider_component_array_data(buf: &[u8]) -> Result<ColliderComponentArrayData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<ColliderComponentArrayData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `ColliderComponentArrayData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_collider_component_array_data_unchecked`.
+pub fn root_as_collider_component_array_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ColliderComponentArrayData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<ColliderComponentArrayData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `ColliderComponentArrayData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_collider_component_array_data_unchecked`.
+pub fn size_prefixed_root_as_collider_component_array_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ColliderComponentArrayData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<ColliderComponentArrayData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a ColliderComponentArrayData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `ColliderComponentArrayData`.
+pub unsafe fn root_as_collider_component_array_data_unchecked(buf: &[u8]) -> ColliderComponentArrayData {
+  flatbuffers::root_unchecked::<ColliderComponentArrayData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed ColliderComponentArrayData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `ColliderComponentArrayData`.
+pub unsafe fn size_prefixed_root_as_collider_component_array_data_unchecked(buf: &[u8]) -> ColliderComponentArrayData {
+  flatbuffers::size_prefixed_root_unchecked::<ColliderComponentArrayData>(buf)
+}
+pub const COLLIDER_COMPONENT_ARRAY_DATA_IDENTIFIER: &str = "CLDA";
+
+#[inline]
+pub fn collider_component_array_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, COLLIDER_COMPONENT_ARRAY_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn collider_component_array_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, COLLIDER_COMPONENT_ARRAY_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_collider_component_array_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<ColliderComponentArrayData<'a>>) {
+  fbb.finish(root, Some(COLLIDER_COMPONENT_ARRAY_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_collider_component_array_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<ColliderComponentArrayData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(COLLIDER_COMPONENT_ARRAY_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
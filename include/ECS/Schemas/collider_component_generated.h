@@ -26,37 +26,110 @@ enum ColliderType : int8_t {
   ColliderType_Sphere = 1,
   ColliderType_Capsule = 2,
   ColliderType_Mesh = 3,
+  ColliderType_Cylinder = 4,
+  ColliderType_Cone = 5,
   ColliderType_MIN = ColliderType_Box,
-  ColliderType_MAX = ColliderType_Mesh
+  ColliderType_MAX = ColliderType_Cone
 };
 
-inline const ColliderType (&EnumValuesColliderType())[4] {
+inline const ColliderType (&EnumValuesColliderType())[6] {
   static const ColliderType values[] = {
     ColliderType_Box,
     ColliderType_Sphere,
     ColliderType_Capsule,
-    ColliderType_Mesh
+    ColliderType_Mesh,
+    ColliderType_Cylinder,
+    ColliderType_Cone
   };
   return values;
 }
 
 inline const char * const *EnumNamesColliderType() {
-  static const char * const names[5] = {
+  static const char * const names[7] = {
     "Box",
     "Sphere",
     "Capsule",
     "Mesh",
+    "Cylinder",
+    "Cone",
     nullptr
   };
   return names;
 }
 
 inline const char *EnumNameColliderType(ColliderType e) {
-  if (::flatbuffers::IsOutRange(e, ColliderType_Box, ColliderType_Mesh)) return "";
+  if (::flatbuffers::IsOutRange(e, ColliderType_Box, ColliderType_Cone)) return "";
   const size_t index = static_cast<size_t>(e);
   return EnumNamesColliderType()[index];
 }
 
+enum CapsuleAxis : int8_t {
+  CapsuleAxis_X = 0,
+  CapsuleAxis_Y = 1,
+  CapsuleAxis_Z = 2,
+  CapsuleAxis_MIN = CapsuleAxis_X,
+  CapsuleAxis_MAX = CapsuleAxis_Z
+};
+
+inline const CapsuleAxis (&EnumValuesCapsuleAxis())[3] {
+  static const CapsuleAxis values[] = {
+    CapsuleAxis_X,
+    CapsuleAxis_Y,
+    CapsuleAxis_Z
+  };
+  return values;
+}
+
+inline const char * const *EnumNamesCapsuleAxis() {
+  static const char * const names[4] = {
+    "X",
+    "Y",
+    "Z",
+    nullptr
+  };
+  return names;
+}
+
+inline const char *EnumNameCapsuleAxis(CapsuleAxis e) {
+  if (::flatbuffers::IsOutRange(e, CapsuleAxis_X, CapsuleAxis_Z)) return "";
+  const size_t index = static_cast<size_t>(e);
+  return EnumNamesCapsuleAxis()[index];
+}
+
+/// Unit of the collider's lengths (size, size_2d, radius, height, center).
+enum ColliderUnits : int8_t {
+  ColliderUnits_Meters = 0,
+  ColliderUnits_Pixels = 1,
+  ColliderUnits_Unitless = 2,
+  ColliderUnits_MIN = ColliderUnits_Meters,
+  ColliderUnits_MAX = ColliderUnits_Unitless
+};
+
+inline const ColliderUnits (&EnumValuesColliderUnits())[3] {
+  static const ColliderUnits values[] = {
+    ColliderUnits_Meters,
+    ColliderUnits_Pixels,
+    ColliderUnits_Unitless
+  };
+  return values;
+}
+
+inline const char * const *EnumNamesColliderUnits() {
+  static const char * const names[4] = {
+    "Meters",
+    "Pixels",
+    "Unitless",
+    nullptr
+  };
+  return names;
+}
+
+inline const char *EnumNameColliderUnits(ColliderUnits e) {
+  if (::flatbuffers::IsOutRange(e, ColliderUnits_Meters, ColliderUnits_Unitless)) return "";
+  const size_t index = static_cast<size_t>(e);
+  return EnumNamesColliderUnits()[index];
+}
+
 struct ColliderComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
   typedef ColliderComponentDataBuilder Builder;
   enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
@@ -66,7 +139,26 @@ struct ColliderComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Ta
     VT_RADIUS = 10,
     VT_HEIGHT = 12,
     VT_MATERIAL_NAME = 14,
-    VT_MESH_PATH = 16
+    VT_MESH_PATH = 16,
+    VT_LAYER = 18,
+    VT_COLLISION_MASK = 20,
+    VT_CENTER = 22,
+    VT_IS_2D = 24,
+    VT_SIZE_2D = 26,
+    VT_DENSITY = 28,
+    VT_MASS_OVERRIDE = 30,
+    VT_SCHEMA_VERSION = 32,
+    VT_DEBUG_COLOR = 34,
+    VT_CAPSULE_AXIS = 36,
+    VT_MESH_IS_CONVEX = 38,
+    VT_TRIGGER_CHANNEL = 40,
+    VT_CONTACT_MARGIN = 42,
+    VT_TRIGGER_DWELL_SECONDS = 44,
+    VT_TRIGGER_WITH_TRIGGERS = 46,
+    VT_IS_STATIC = 48,
+    VT_UNITS = 50,
+    VT_SURFACE_TYPE = 52,
+    VT_TRIGGER_DEBOUNCE_SECONDS = 54
   };
   PixelCraft::ECS::ColliderType collider_type() const {
     return static_cast<PixelCraft::ECS::ColliderType>(GetField<int8_t>(VT_COLLIDER_TYPE, 0));
@@ -89,6 +181,100 @@ struct ColliderComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Ta
   const ::flatbuffers::String *mesh_path() const {
     return GetPointer<const ::flatbuffers::String *>(VT_MESH_PATH);
   }
+  uint32_t layer() const {
+    return GetField<uint32_t>(VT_LAYER, 0);
+  }
+  uint32_t collision_mask() const {
+    return GetField<uint32_t>(VT_COLLISION_MASK, 4294967295);
+  }
+  /// Local offset from the entity's transform origin. Absent means no
+  /// offset: the collider is centered on the entity origin.
+  const PixelCraft::ECS::Vec3 *center() const {
+    return GetStruct<const PixelCraft::ECS::Vec3 *>(VT_CENTER);
+  }
+  /// Marks a collider for the 2D physics world, which works in the XY plane.
+  /// A 2D Box takes its full extents from `size_2d` and ignores `size`; a 2D
+  /// Sphere is a circle and a 2D Capsule a stadium, both using `radius` and
+  /// `height` as in 3D. Mesh, Cylinder and Cone have no 2D form. Absent
+  /// (false) keeps the 3D behaviour, so existing buffers are unaffected.
+  bool is_2d() const {
+    return GetField<uint8_t>(VT_IS_2D, 0) != 0;
+  }
+  /// Full extents of a 2D box on X and Y. Only read when `is_2d` is set.
+  const PixelCraft::ECS::Vec2 *size_2d() const {
+    return GetStruct<const PixelCraft::ECS::Vec2 *>(VT_SIZE_2D);
+  }
+  /// Mass per unit volume (per unit area for 2D colliders), used to derive
+  /// the body's mass from the shape.
+  float density() const {
+    return GetField<float>(VT_DENSITY, 1.0f);
+  }
+  /// Explicit mass; values <= 0 mean unset, so mass comes from `density`.
+  float mass_override() const {
+    return GetField<float>(VT_MASS_OVERRIDE, 0.0f);
+  }
+  /// Version of this schema the buffer was written with. Buffers from before
+  /// the field existed read as 1; see CURRENT_COLLIDER_SCHEMA_VERSION.
+  uint16_t schema_version() const {
+    return GetField<uint16_t>(VT_SCHEMA_VERSION, 1);
+  }
+  /// Color for the debug physics overlay. Absent picks a default that tells
+  /// triggers and solid colliders apart.
+  const PixelCraft::ECS::Color *debug_color() const {
+    return GetStruct<const PixelCraft::ECS::Color *>(VT_DEBUG_COLOR);
+  }
+  /// Local axis a capsule's cylindrical section runs along.
+  PixelCraft::ECS::CapsuleAxis capsule_axis() const {
+    return static_cast<PixelCraft::ECS::CapsuleAxis>(GetField<int8_t>(VT_CAPSULE_AXIS, 1));
+  }
+  /// Whether a Mesh collider's mesh is convex. Concave meshes need cooking
+  /// into a triangle mesh before the physics backend can use them. Ignored
+  /// for other collider types.
+  bool mesh_is_convex() const {
+    return GetField<uint8_t>(VT_MESH_IS_CONVEX, 0) != 0;
+  }
+  /// Named channel a trigger's enter/exit events are routed to, e.g.
+  /// "checkpoint". Ignored unless `is_trigger` is set; absent means the
+  /// "default" channel.
+  const ::flatbuffers::String *trigger_channel() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_TRIGGER_CHANNEL);
+  }
+  /// Distance around the shape at which contacts start being generated, so
+  /// fast thin objects do not tunnel. Negative values are treated as 0.
+  float contact_margin() const {
+    return GetField<float>(VT_CONTACT_MARGIN, 0.01f);
+  }
+  /// Seconds an entity must stay inside a trigger before it fires, for "hold
+  /// to activate" zones. 0 fires on entry. Ignored unless `is_trigger` is set.
+  float trigger_dwell_seconds() const {
+    return GetField<float>(VT_TRIGGER_DWELL_SECONDS, 0.0f);
+  }
+  /// Whether this trigger also raises events against other triggers. Two
+  /// triggers only interact if both set this; see triggers_interact.
+  bool trigger_with_triggers() const {
+    return GetField<uint8_t>(VT_TRIGGER_WITH_TRIGGERS, 0) != 0;
+  }
+  /// Hint that the collider never moves, so the broadphase can keep it in its
+  /// static tree. Buffers written before the field existed read as dynamic.
+  bool is_static() const {
+    return GetField<uint8_t>(VT_IS_STATIC, 0) != 0;
+  }
+  /// Unit the lengths are authored in; see convert_collider_units.
+  PixelCraft::ECS::ColliderUnits units() const {
+    return static_cast<PixelCraft::ECS::ColliderUnits>(GetField<int8_t>(VT_UNITS, 0));
+  }
+  /// Surface the audio system picks footstep and impact sounds by, e.g.
+  /// "grass". Separate from material_name, which sets friction and bounce:
+  /// surfaces that sound different often share a physics material.
+  const ::flatbuffers::String *surface_type() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_SURFACE_TYPE);
+  }
+  /// Minimum seconds between two events from this trigger, so overlaps that
+  /// flicker at a boundary do not flood the event system. 0 never suppresses.
+  /// Ignored unless `is_trigger` is set; see TriggerDebouncer.
+  float trigger_debounce_seconds() const {
+    return GetField<float>(VT_TRIGGER_DEBOUNCE_SECONDS, 0.0f);
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyField<int8_t>(verifier, VT_COLLIDER_TYPE, 1) &&
@@ -100,6 +286,27 @@ struct ColliderComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Ta
            verifier.VerifyString(material_name()) &&
            VerifyOffset(verifier, VT_MESH_PATH) &&
            verifier.VerifyString(mesh_path()) &&
+           VerifyField<uint32_t>(verifier, VT_LAYER, 4) &&
+           VerifyField<uint32_t>(verifier, VT_COLLISION_MASK, 4) &&
+           VerifyField<PixelCraft::ECS::Vec3>(verifier, VT_CENTER, 4) &&
+           VerifyField<uint8_t>(verifier, VT_IS_2D, 1) &&
+           VerifyField<PixelCraft::ECS::Vec2>(verifier, VT_SIZE_2D, 4) &&
+           VerifyField<float>(verifier, VT_DENSITY, 4) &&
+           VerifyField<float>(verifier, VT_MASS_OVERRIDE, 4) &&
+           VerifyField<uint16_t>(verifier, VT_SCHEMA_VERSION, 2) &&
+           VerifyField<PixelCraft::ECS::Color>(verifier, VT_DEBUG_COLOR, 1) &&
+           VerifyField<int8_t>(verifier, VT_CAPSULE_AXIS, 1) &&
+           VerifyField<uint8_t>(verifier, VT_MESH_IS_CONVEX, 1) &&
+           VerifyOffset(verifier, VT_TRIGGER_CHANNEL) &&
+           verifier.VerifyString(trigger_channel()) &&
+           VerifyField<float>(verifier, VT_CONTACT_MARGIN, 4) &&
+           VerifyField<float>(verifier, VT_TRIGGER_DWELL_SECONDS, 4) &&
+           VerifyField<uint8_t>(verifier, VT_TRIGGER_WITH_TRIGGERS, 1) &&
+           VerifyField<uint8_t>(verifier, VT_IS_STATIC, 1) &&
+           VerifyField<int8_t>(verifier, VT_UNITS, 1) &&
+           VerifyOffset(verifier, VT_SURFACE_TYPE) &&
+           verifier.VerifyString(surface_type()) &&
+           VerifyField<float>(verifier, VT_TRIGGER_DEBOUNCE_SECONDS, 4) &&
            verifier.EndTable();
   }
 };
@@ -129,6 +336,63 @@ struct ColliderComponentDataBuilder {
   void add_mesh_path(::flatbuffers::Offset<::flatbuffers::String> mesh_path) {
     fbb_.AddOffset(ColliderComponentData::VT_MESH_PATH, mesh_path);
   }
+  void add_layer(uint32_t layer) {
+    fbb_.AddElement<uint32_t>(ColliderComponentData::VT_LAYER, layer, 0);
+  }
+  void add_collision_mask(uint32_t collision_mask) {
+    fbb_.AddElement<uint32_t>(ColliderComponentData::VT_COLLISION_MASK, collision_mask, 4294967295);
+  }
+  void add_center(const PixelCraft::ECS::Vec3 *center) {
+    fbb_.AddStruct(ColliderComponentData::VT_CENTER, center);
+  }
+  void add_is_2d(bool is_2d) {
+    fbb_.AddElement<uint8_t>(ColliderComponentData::VT_IS_2D, static_cast<uint8_t>(is_2d), 0);
+  }
+  void add_size_2d(const PixelCraft::ECS::Vec2 *size_2d) {
+    fbb_.AddStruct(ColliderComponentData::VT_SIZE_2D, size_2d);
+  }
+  void add_density(float density) {
+    fbb_.AddElement<float>(ColliderComponentData::VT_DENSITY, density, 1.0f);
+  }
+  void add_mass_override(float mass_override) {
+    fbb_.AddElement<float>(ColliderComponentData::VT_MASS_OVERRIDE, mass_override, 0.0f);
+  }
+  void add_schema_version(uint16_t schema_version) {
+    fbb_.AddElement<uint16_t>(ColliderComponentData::VT_SCHEMA_VERSION, schema_version, 1);
+  }
+  void add_debug_color(const PixelCraft::ECS::Color *debug_color) {
+    fbb_.AddStruct(ColliderComponentData::VT_DEBUG_COLOR, debug_color);
+  }
+  void add_capsule_axis(PixelCraft::ECS::CapsuleAxis capsule_axis) {
+    fbb_.AddElement<int8_t>(ColliderComponentData::VT_CAPSULE_AXIS, static_cast<int8_t>(capsule_axis), 1);
+  }
+  void add_mesh_is_convex(bool mesh_is_convex) {
+    fbb_.AddElement<uint8_t>(ColliderComponentData::VT_MESH_IS_CONVEX, static_cast<uint8_t>(mesh_is_convex), 0);
+  }
+  void add_trigger_channel(::flatbuffers::Offset<::flatbuffers::String> trigger_channel) {
+    fbb_.AddOffset(ColliderComponentData::VT_TRIGGER_CHANNEL, trigger_channel);
+  }
+  void add_contact_margin(float contact_margin) {
+    fbb_.AddElement<float>(ColliderComponentData::VT_CONTACT_MARGIN, contact_margin, 0.01f);
+  }
+  void add_trigger_dwell_seconds(float trigger_dwell_seconds) {
+    fbb_.AddElement<float>(ColliderComponentData::VT_TRIGGER_DWELL_SECONDS, trigger_dwell_seconds, 0.0f);
+  }
+  void add_trigger_with_triggers(bool trigger_with_triggers) {
+    fbb_.AddElement<uint8_t>(ColliderComponentData::VT_TRIGGER_WITH_TRIGGERS, static_cast<uint8_t>(trigger_with_triggers), 0);
+  }
+  void add_is_static(bool is_static) {
+    fbb_.AddElement<uint8_t>(ColliderComponentData::VT_IS_STATIC, static_cast<uint8_t>(is_static), 0);
+  }
+  void add_units(PixelCraft::ECS::ColliderUnits units) {
+    fbb_.AddElement<int8_t>(ColliderComponentData::VT_UNITS, static_cast<int8_t>(units), 0);
+  }
+  void add_surface_type(::flatbuffers::Offset<::flatbuffers::String> surface_type) {
+    fbb_.AddOffset(ColliderComponentData::VT_SURFACE_TYPE, surface_type);
+  }
+  void add_trigger_debounce_seconds(float trigger_debounce_seconds) {
+    fbb_.AddElement<float>(ColliderComponentData::VT_TRIGGER_DEBOUNCE_SECONDS, trigger_debounce_seconds, 0.0f);
+  }
   explicit ColliderComponentDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -148,13 +412,51 @@ inline ::flatbuffers::Offset<ColliderComponentData> CreateColliderComponentData(
     float radius = 0.0f,
     float height = 0.0f,
     ::flatbuffers::Offset<::flatbuffers::String> material_name = 0,
-    ::flatbuffers::Offset<::flatbuffers::String> mesh_path = 0) {
+    ::flatbuffers::Offset<::flatbuffers::String> mesh_path = 0,
+    uint32_t layer = 0,
+    uint32_t collision_mask = 4294967295,
+    const PixelCraft::ECS::Vec3 *center = nullptr,
+    bool is_2d = false,
+    const PixelCraft::ECS::Vec2 *size_2d = nullptr,
+    float density = 1.0f,
+    float mass_override = 0.0f,
+    uint16_t schema_version = 1,
+    const PixelCraft::ECS::Color *debug_color = nullptr,
+    PixelCraft::ECS::CapsuleAxis capsule_axis = PixelCraft::ECS::CapsuleAxis_Y,
+    bool mesh_is_convex = false,
+    ::flatbuffers::Offset<::flatbuffers::String> trigger_channel = 0,
+    float contact_margin = 0.01f,
+    float trigger_dwell_seconds = 0.0f,
+    bool trigger_with_triggers = false,
+    bool is_static = false,
+    PixelCraft::ECS::ColliderUnits units = PixelCraft::ECS::ColliderUnits_Meters,
+    ::flatbuffers::Offset<::flatbuffers::String> surface_type = 0,
+    float trigger_debounce_seconds = 0.0f) {
   ColliderComponentDataBuilder builder_(_fbb);
+  builder_.add_trigger_debounce_seconds(trigger_debounce_seconds);
+  builder_.add_surface_type(surface_type);
+  builder_.add_trigger_dwell_seconds(trigger_dwell_seconds);
+  builder_.add_contact_margin(contact_margin);
+  builder_.add_trigger_channel(trigger_channel);
+  builder_.add_debug_color(debug_color);
+  builder_.add_mass_override(mass_override);
+  builder_.add_density(density);
+  builder_.add_size_2d(size_2d);
+  builder_.add_center(center);
+  builder_.add_collision_mask(collision_mask);
+  builder_.add_layer(layer);
   builder_.add_mesh_path(mesh_path);
   builder_.add_material_name(material_name);
   builder_.add_height(height);
   builder_.add_radius(radius);
   builder_.add_size(size);
+  builder_.add_schema_version(schema_version);
+  builder_.add_units(units);
+  builder_.add_is_static(is_static);
+  builder_.add_trigger_with_triggers(trigger_with_triggers);
+  builder_.add_mesh_is_convex(mesh_is_convex);
+  builder_.add_capsule_axis(capsule_axis);
+  builder_.add_is_2d(is_2d);
   builder_.add_is_trigger(is_trigger);
   builder_.add_collider_type(collider_type);
   return builder_.Finish();
@@ -168,9 +470,30 @@ inline ::flatbuffers::Offset<ColliderComponentData> CreateColliderComponentDataD
     float radius = 0.0f,
     float height = 0.0f,
     const char *material_name = nullptr,
-    const char *mesh_path = nullptr) {
+    const char *mesh_path = nullptr,
+    uint32_t layer = 0,
+    uint32_t collision_mask = 4294967295,
+    const PixelCraft::ECS::Vec3 *center = nullptr,
+    bool is_2d = false,
+    const PixelCraft::ECS::Vec2 *size_2d = nullptr,
+    float density = 1.0f,
+    float mass_override = 0.0f,
+    uint16_t schema_version = 1,
+    const PixelCraft::ECS::Color *debug_color = nullptr,
+    PixelCraft::ECS::CapsuleAxis capsule_axis = PixelCraft::ECS::CapsuleAxis_Y,
+    bool mesh_is_convex = false,
+    const char *trigger_channel = nullptr,
+    float contact_margin = 0.01f,
+    float trigger_dwell_seconds = 0.0f,
+    bool trigger_with_triggers = false,
+    bool is_static = false,
+    PixelCraft::ECS::ColliderUnits units = PixelCraft::ECS::ColliderUnits_Meters,
+    const char *surface_type = nullptr,
+    float trigger_debounce_seconds = 0.0f) {
   auto material_name__ = material_name ? _fbb.CreateString(material_name) : 0;
   auto mesh_path__ = mesh_path ? _fbb.CreateString(mesh_path) : 0;
+  auto trigger_channel__ = trigger_channel ? _fbb.CreateString(trigger_channel) : 0;
+  auto surface_type__ = surface_type ? _fbb.CreateString(surface_type) : 0;
   return PixelCraft::ECS::CreateColliderComponentData(
       _fbb,
       collider_type,
@@ -179,7 +502,26 @@ inline ::flatbuffers::Offset<ColliderComponentData> CreateColliderComponentDataD
       radius,
       height,
       material_name__,
-      mesh_path__);
+      mesh_path__,
+      layer,
+      collision_mask,
+      center,
+      is_2d,
+      size_2d,
+      density,
+      mass_override,
+      schema_version,
+      debug_color,
+      capsule_axis,
+      mesh_is_convex,
+      trigger_channel__,
+      contact_margin,
+      trigger_dwell_seconds,
+      trigger_with_triggers,
+      is_static,
+      units,
+      surface_type__,
+      trigger_debounce_seconds);
 }
 
 inline const PixelCraft::ECS::ColliderComponentData *GetColliderComponentData(const void *buf) {
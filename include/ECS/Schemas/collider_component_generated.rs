@@ -32,14 +32,16 @@ pub mod ecs {
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 pub const ENUM_MIN_COLLIDER_TYPE: i8 = 0;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
-pub const ENUM_MAX_COLLIDER_TYPE: i8 = 3;
+pub const ENUM_MAX_COLLIDER_TYPE: i8 = 5;
 #[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_COLLIDER_TYPE: [ColliderType; 4] = [
+pub const ENUM_VALUES_COLLIDER_TYPE: [ColliderType; 6] = [
   ColliderType::Box,
   ColliderType::Sphere,
   ColliderType::Capsule,
   ColliderType::Mesh,
+  ColliderType::Cylinder,
+  ColliderType::Cone,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -51,14 +53,18 @@ impl ColliderType {
   pub const Sphere: Self = Self(1);
   pub const Capsule: Self = Self(2);
   pub const Mesh: Self = Self(3);
+  pub const Cylinder: Self = Self(4);
+  pub const Cone: Self = Self(5);
 
   pub const ENUM_MIN: i8 = 0;
-  pub const ENUM_MAX: i8 = 3;
+  pub const ENUM_MAX: i8 = 5;
   pub const ENUM_VALUES: &'static [Self] = &[
     Self::Box,
     Self::Sphere,
     Self::Capsule,
     Self::Mesh,
+    Self::Cylinder,
+    Self::Cone,
   ];
   /// Returns the variant's name or "" if unknown.
   pub fn variant_name(self) -> Option<&'static str> {
@@ -67,6 +73,8 @@ impl ColliderType {
       Self::Sphere => Some("Sphere"),
       Self::Capsule => Some("Capsule"),
       Self::Mesh => Some("Mesh"),
+      Self::Cylinder => Some("Cylinder"),
+      Self::Cone => Some("Cone"),
       _ => None,
     }
   }
@@ -122,6 +130,185 @@ impl<'a> flatbuffers::Verifiable for ColliderType {
 }
 
 impl flatbuffers::SimpleToVerifyInSlice for ColliderType {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_CAPSULE_AXIS: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_CAPSULE_AXIS: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_CAPSULE_AXIS: [CapsuleAxis; 3] = [
+  CapsuleAxis::X,
+  CapsuleAxis::Y,
+  CapsuleAxis::Z,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct CapsuleAxis(pub i8);
+#[allow(non_upper_case_globals)]
+impl CapsuleAxis {
+  pub const X: Self = Self(0);
+  pub const Y: Self = Self(1);
+  pub const Z: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::X,
+    Self::Y,
+    Self::Z,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::X => Some("X"),
+      Self::Y => Some("Y"),
+      Self::Z => Some("Z"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for CapsuleAxis {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for CapsuleAxis {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for CapsuleAxis {
+    type Output = CapsuleAxis;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for CapsuleAxis {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for CapsuleAxis {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for CapsuleAxis {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_COLLIDER_UNITS: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_COLLIDER_UNITS: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_COLLIDER_UNITS: [ColliderUnits; 3] = [
+  ColliderUnits::Meters,
+  ColliderUnits::Pixels,
+  ColliderUnits::Unitless,
+];
+
+/// Unit of the collider's lengths (size, size_2d, radius, height, center).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct ColliderUnits(pub i8);
+#[allow(non_upper_case_globals)]
+impl ColliderUnits {
+  pub const Meters: Self = Self(0);
+  pub const Pixels: Self = Self(1);
+  pub const Unitless: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Meters,
+    Self::Pixels,
+    Self::Unitless,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Meters => Some("Meters"),
+      Self::Pixels => Some("Pixels"),
+      Self::Unitless => Some("Unitless"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for ColliderUnits {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for ColliderUnits {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for ColliderUnits {
+    type Output = ColliderUnits;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for ColliderUnits {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for ColliderUnits {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ColliderUnits {}
 pub enum ColliderComponentDataOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -145,6 +332,25 @@ impl<'a> ColliderComponentData<'a> {
   pub const VT_HEIGHT: flatbuffers::VOffsetT = 12;
   pub const VT_MATERIAL_NAME: flatbuffers::VOffsetT = 14;
   pub const VT_MESH_PATH: flatbuffers::VOffsetT = 16;
+  pub const VT_LAYER: flatbuffers::VOffsetT = 18;
+  pub const VT_COLLISION_MASK: flatbuffers::VOffsetT = 20;
+  pub const VT_CENTER: flatbuffers::VOffsetT = 22;
+  pub const VT_IS_2D: flatbuffers::VOffsetT = 24;
+  pub const VT_SIZE_2D: flatbuffers::VOffsetT = 26;
+  pub const VT_DENSITY: flatbuffers::VOffsetT = 28;
+  pub const VT_MASS_OVERRIDE: flatbuffers::VOffsetT = 30;
+  pub const VT_SCHEMA_VERSION: flatbuffers::VOffsetT = 32;
+  pub const VT_DEBUG_COLOR: flatbuffers::VOffsetT = 34;
+  pub const VT_CAPSULE_AXIS: flatbuffers::VOffsetT = 36;
+  pub const VT_MESH_IS_CONVEX: flatbuffers::VOffsetT = 38;
+  pub const VT_TRIGGER_CHANNEL: flatbuffers::VOffsetT = 40;
+  pub const VT_CONTACT_MARGIN: flatbuffers::VOffsetT = 42;
+  pub const VT_TRIGGER_DWELL_SECONDS: flatbuffers::VOffsetT = 44;
+  pub const VT_TRIGGER_WITH_TRIGGERS: flatbuffers::VOffsetT = 46;
+  pub const VT_IS_STATIC: flatbuffers::VOffsetT = 48;
+  pub const VT_UNITS: flatbuffers::VOffsetT = 50;
+  pub const VT_SURFACE_TYPE: flatbuffers::VOffsetT = 52;
+  pub const VT_TRIGGER_DEBOUNCE_SECONDS: flatbuffers::VOffsetT = 54;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -156,11 +362,30 @@ impl<'a> ColliderComponentData<'a> {
     args: &'args ColliderComponentDataArgs<'args>
   ) -> flatbuffers::WIPOffset<ColliderComponentData<'bldr>> {
     let mut builder = ColliderComponentDataBuilder::new(_fbb);
+    builder.add_trigger_debounce_seconds(args.trigger_debounce_seconds);
+    if let Some(x) = args.surface_type { builder.add_surface_type(x); }
+    builder.add_trigger_dwell_seconds(args.trigger_dwell_seconds);
+    builder.add_contact_margin(args.contact_margin);
+    if let Some(x) = args.trigger_channel { builder.add_trigger_channel(x); }
+    if let Some(x) = args.debug_color { builder.add_debug_color(x); }
+    builder.add_mass_override(args.mass_override);
+    builder.add_density(args.density);
+    if let Some(x) = args.size_2d { builder.add_size_2d(x); }
+    if let Some(x) = args.center { builder.add_center(x); }
+    builder.add_collision_mask(args.collision_mask);
+    builder.add_layer(args.layer);
     if let Some(x) = args.mesh_path { builder.add_mesh_path(x); }
     if let Some(x) = args.material_name { builder.add_material_name(x); }
     builder.add_height(args.height);
     builder.add_radius(args.radius);
     if let Some(x) = args.size { builder.add_size(x); }
+    builder.add_schema_version(args.schema_version);
+    builder.add_units(args.units);
+    builder.add_is_static(args.is_static);
+    builder.add_trigger_with_triggers(args.trigger_with_triggers);
+    builder.add_mesh_is_convex(args.mesh_is_convex);
+    builder.add_capsule_axis(args.capsule_axis);
+    builder.add_is_2d(args.is_2d);
     builder.add_is_trigger(args.is_trigger);
     builder.add_collider_type(args.collider_type);
     builder.finish()
@@ -216,6 +441,176 @@ impl<'a> ColliderComponentData<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ColliderComponentData::VT_MESH_PATH, None)}
   }
+  #[inline]
+  pub fn layer(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ColliderComponentData::VT_LAYER, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn collision_mask(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ColliderComponentData::VT_COLLISION_MASK, Some(4294967295)).unwrap()}
+  }
+  /// Local offset from the entity's transform origin. Absent means no
+  /// offset: the collider is centered on the entity origin.
+  #[inline]
+  pub fn center(&self) -> Option<&'a Vec3> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec3>(ColliderComponentData::VT_CENTER, None)}
+  }
+  /// Marks a collider for the 2D physics world, which works in the XY plane.
+  /// A 2D Box takes its full extents from `size_2d` and ignores `size`; a 2D
+  /// Sphere is a circle and a 2D Capsule a stadium, both using `radius` and
+  /// `height` as in 3D. Mesh, Cylinder and Cone have no 2D form. Absent
+  /// (false) keeps the 3D behaviour, so existing buffers are unaffected.
+  #[inline]
+  pub fn is_2d(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ColliderComponentData::VT_IS_2D, Some(false)).unwrap()}
+  }
+  /// Full extents of a 2D box on X and Y. Only read when `is_2d` is set.
+  #[inline]
+  pub fn size_2d(&self) -> Option<&'a Vec2> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec2>(ColliderComponentData::VT_SIZE_2D, None)}
+  }
+  /// Mass per unit volume (per unit area for 2D colliders), used to derive
+  /// the body's mass from the shape.
+  #[inline]
+  pub fn density(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ColliderComponentData::VT_DENSITY, Some(1.0)).unwrap()}
+  }
+  /// Explicit mass; values <= 0 mean unset, so mass comes from `density`.
+  #[inline]
+  pub fn mass_override(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ColliderComponentData::VT_MASS_OVERRIDE, Some(0.0)).unwrap()}
+  }
+  /// Version of this schema the buffer was written with. Buffers from before
+  /// the field existed read as 1; see CURRENT_COLLIDER_SCHEMA_VERSION.
+  #[inline]
+  pub fn schema_version(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(ColliderComponentData::VT_SCHEMA_VERSION, Some(1)).unwrap()}
+  }
+  /// Color for the debug physics overlay. Absent picks a default that tells
+  /// triggers and solid colliders apart.
+  #[inline]
+  pub fn debug_color(&self) -> Option<&'a Color> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Color>(ColliderComponentData::VT_DEBUG_COLOR, None)}
+  }
+  /// Local axis a capsule's cylindrical section runs along.
+  #[inline]
+  pub fn capsule_axis(&self) -> CapsuleAxis {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<CapsuleAxis>(ColliderComponentData::VT_CAPSULE_AXIS, Some(CapsuleAxis::Y)).unwrap()}
+  }
+  /// Whether a Mesh collider's mesh is convex. Concave meshes need cooking
+  /// into a triangle mesh before the physics backend can use them. Ignored
+  /// for other collider types.
+  #[inline]
+  pub fn mesh_is_convex(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ColliderComponentData::VT_MESH_IS_CONVEX, Some(false)).unwrap()}
+  }
+  /// Named channel a trigger's enter/exit events are routed to, e.g.
+  /// "checkpoint". Ignored unless `is_trigger` is set; absent means the
+  /// "default" channel.
+  #[inline]
+  pub fn trigger_channel(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ColliderComponentData::VT_TRIGGER_CHANNEL, None)}
+  }
+  /// Distance around the shape at which contacts start being generated, so
+  /// fast thin objects do not tunnel. Negative values are treated as 0.
+  #[inline]
+  pub fn contact_margin(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ColliderComponentData::VT_CONTACT_MARGIN, Some(0.01)).unwrap()}
+  }
+  /// Seconds an entity must stay inside a trigger before it fires, for "hold
+  /// to activate" zones. 0 fires on entry. Ignored unless `is_trigger` is set.
+  #[inline]
+  pub fn trigger_dwell_seconds(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ColliderComponentData::VT_TRIGGER_DWELL_SECONDS, Some(0.0)).unwrap()}
+  }
+  /// Whether this trigger also raises events against other triggers. Two
+  /// triggers only interact if both set this; see triggers_interact.
+  #[inline]
+  pub fn trigger_with_triggers(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ColliderComponentData::VT_TRIGGER_WITH_TRIGGERS, Some(false)).unwrap()}
+  }
+  /// Hint that the collider never moves, so the broadphase can keep it in its
+  /// static tree. Buffers written before the field existed read as dynamic.
+  #[inline]
+  pub fn is_static(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ColliderComponentData::VT_IS_STATIC, Some(false)).unwrap()}
+  }
+  /// Unit the lengths are authored in; see convert_collider_units.
+  #[inline]
+  pub fn units(&self) -> ColliderUnits {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ColliderUnits>(ColliderComponentData::VT_UNITS, Some(ColliderUnits::Meters)).unwrap()}
+  }
+  /// Surface the audio system picks footstep and impact sounds by, e.g.
+  /// "grass". Separate from material_name, which sets friction and bounce:
+  /// surfaces that sound different often share a physics material.
+  #[inline]
+  pub fn surface_type(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ColliderComponentData::VT_SURFACE_TYPE, None)}
+  }
+  /// Minimum seconds between two events from this trigger, so overlaps that
+  /// flicker at a boundary do not flood the event system. 0 never suppresses.
+  /// Ignored unless `is_trigger` is set; see TriggerDebouncer.
+  #[inline]
+  pub fn trigger_debounce_seconds(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ColliderComponentData::VT_TRIGGER_DEBOUNCE_SECONDS, Some(0.0)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for ColliderComponentData<'_> {
@@ -232,6 +627,25 @@ impl flatbuffers::Verifiable for ColliderComponentData<'_> {
      .visit_field::<f32>("height", Self::VT_HEIGHT, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("material_name", Self::VT_MATERIAL_NAME, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<&str>>("mesh_path", Self::VT_MESH_PATH, false)?
+     .visit_field::<u32>("layer", Self::VT_LAYER, false)?
+     .visit_field::<u32>("collision_mask", Self::VT_COLLISION_MASK, false)?
+     .visit_field::<Vec3>("center", Self::VT_CENTER, false)?
+     .visit_field::<bool>("is_2d", Self::VT_IS_2D, false)?
+     .visit_field::<Vec2>("size_2d", Self::VT_SIZE_2D, false)?
+     .visit_field::<f32>("density", Self::VT_DENSITY, false)?
+     .visit_field::<f32>("mass_override", Self::VT_MASS_OVERRIDE, false)?
+     .visit_field::<u16>("schema_version", Self::VT_SCHEMA_VERSION, false)?
+     .visit_field::<Color>("debug_color", Self::VT_DEBUG_COLOR, false)?
+     .visit_field::<CapsuleAxis>("capsule_axis", Self::VT_CAPSULE_AXIS, false)?
+     .visit_field::<bool>("mesh_is_convex", Self::VT_MESH_IS_CONVEX, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("trigger_channel", Self::VT_TRIGGER_CHANNEL, false)?
+     .visit_field::<f32>("contact_margin", Self::VT_CONTACT_MARGIN, false)?
+     .visit_field::<f32>("trigger_dwell_seconds", Self::VT_TRIGGER_DWELL_SECONDS, false)?
+     .visit_field::<bool>("trigger_with_triggers", Self::VT_TRIGGER_WITH_TRIGGERS, false)?
+     .visit_field::<bool>("is_static", Self::VT_IS_STATIC, false)?
+     .visit_field::<ColliderUnits>("units", Self::VT_UNITS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("surface_type", Self::VT_SURFACE_TYPE, false)?
+     .visit_field::<f32>("trigger_debounce_seconds", Self::VT_TRIGGER_DEBOUNCE_SECONDS, false)?
      .finish();
     Ok(())
   }
@@ -244,6 +658,25 @@ pub struct ColliderComponentDataArgs<'a> {
     pub height: f32,
     pub material_name: Option<flatbuffers::WIPOffset<&'a str>>,
     pub mesh_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub layer: u32,
+    pub collision_mask: u32,
+    pub center: Option<&'a Vec3>,
+    pub is_2d: bool,
+    pub size_2d: Option<&'a Vec2>,
+    pub density: f32,
+    pub mass_override: f32,
+    pub schema_version: u16,
+    pub debug_color: Option<&'a Color>,
+    pub capsule_axis: CapsuleAxis,
+    pub mesh_is_convex: bool,
+    pub trigger_channel: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub contact_margin: f32,
+    pub trigger_dwell_seconds: f32,
+    pub trigger_with_triggers: bool,
+    pub is_static: bool,
+    pub units: ColliderUnits,
+    pub surface_type: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub trigger_debounce_seconds: f32,
 }
 impl<'a> Default for ColliderComponentDataArgs<'a> {
   #[inline]
@@ -256,6 +689,25 @@ impl<'a> Default for ColliderComponentDataArgs<'a> {
       height: 0.0,
       material_name: None,
       mesh_path: None,
+      layer: 0,
+      collision_mask: 4294967295,
+      center: None,
+      is_2d: false,
+      size_2d: None,
+      density: 1.0,
+      mass_override: 0.0,
+      schema_version: 1,
+      debug_color: None,
+      capsule_axis: CapsuleAxis::Y,
+      mesh_is_convex: false,
+      trigger_channel: None,
+      contact_margin: 0.01,
+      trigger_dwell_seconds: 0.0,
+      trigger_with_triggers: false,
+      is_static: false,
+      units: ColliderUnits::Meters,
+      surface_type: None,
+      trigger_debounce_seconds: 0.0,
     }
   }
 }
@@ -294,6 +746,82 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ColliderComponentDataBuilder<'a
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ColliderComponentData::VT_MESH_PATH, mesh_path);
   }
   #[inline]
+  pub fn add_layer(&mut self, layer: u32) {
+    self.fbb_.push_slot::<u32>(ColliderComponentData::VT_LAYER, layer, 0);
+  }
+  #[inline]
+  pub fn add_collision_mask(&mut self, collision_mask: u32) {
+    self.fbb_.push_slot::<u32>(ColliderComponentData::VT_COLLISION_MASK, collision_mask, 4294967295);
+  }
+  #[inline]
+  pub fn add_center(&mut self, center: &Vec3) {
+    self.fbb_.push_slot_always::<&Vec3>(ColliderComponentData::VT_CENTER, center);
+  }
+  #[inline]
+  pub fn add_is_2d(&mut self, is_2d: bool) {
+    self.fbb_.push_slot::<bool>(ColliderComponentData::VT_IS_2D, is_2d, false);
+  }
+  #[inline]
+  pub fn add_size_2d(&mut self, size_2d: &Vec2) {
+    self.fbb_.push_slot_always::<&Vec2>(ColliderComponentData::VT_SIZE_2D, size_2d);
+  }
+  #[inline]
+  pub fn add_density(&mut self, density: f32) {
+    self.fbb_.push_slot::<f32>(ColliderComponentData::VT_DENSITY, density, 1.0);
+  }
+  #[inline]
+  pub fn add_mass_override(&mut self, mass_override: f32) {
+    self.fbb_.push_slot::<f32>(ColliderComponentData::VT_MASS_OVERRIDE, mass_override, 0.0);
+  }
+  #[inline]
+  pub fn add_schema_version(&mut self, schema_version: u16) {
+    self.fbb_.push_slot::<u16>(ColliderComponentData::VT_SCHEMA_VERSION, schema_version, 1);
+  }
+  #[inline]
+  pub fn add_debug_color(&mut self, debug_color: &Color) {
+    self.fbb_.push_slot_always::<&Color>(ColliderComponentData::VT_DEBUG_COLOR, debug_color);
+  }
+  #[inline]
+  pub fn add_capsule_axis(&mut self, capsule_axis: CapsuleAxis) {
+    self.fbb_.push_slot::<CapsuleAxis>(ColliderComponentData::VT_CAPSULE_AXIS, capsule_axis, CapsuleAxis::Y);
+  }
+  #[inline]
+  pub fn add_mesh_is_convex(&mut self, mesh_is_convex: bool) {
+    self.fbb_.push_slot::<bool>(ColliderComponentData::VT_MESH_IS_CONVEX, mesh_is_convex, false);
+  }
+  #[inline]
+  pub fn add_trigger_channel(&mut self, trigger_channel: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ColliderComponentData::VT_TRIGGER_CHANNEL, trigger_channel);
+  }
+  #[inline]
+  pub fn add_contact_margin(&mut self, contact_margin: f32) {
+    self.fbb_.push_slot::<f32>(ColliderComponentData::VT_CONTACT_MARGIN, contact_margin, 0.01);
+  }
+  #[inline]
+  pub fn add_trigger_dwell_seconds(&mut self, trigger_dwell_seconds: f32) {
+    self.fbb_.push_slot::<f32>(ColliderComponentData::VT_TRIGGER_DWELL_SECONDS, trigger_dwell_seconds, 0.0);
+  }
+  #[inline]
+  pub fn add_trigger_with_triggers(&mut self, trigger_with_triggers: bool) {
+    self.fbb_.push_slot::<bool>(ColliderComponentData::VT_TRIGGER_WITH_TRIGGERS, trigger_with_triggers, false);
+  }
+  #[inline]
+  pub fn add_is_static(&mut self, is_static: bool) {
+    self.fbb_.push_slot::<bool>(ColliderComponentData::VT_IS_STATIC, is_static, false);
+  }
+  #[inline]
+  pub fn add_units(&mut self, units: ColliderUnits) {
+    self.fbb_.push_slot::<ColliderUnits>(ColliderComponentData::VT_UNITS, units, ColliderUnits::Meters);
+  }
+  #[inline]
+  pub fn add_surface_type(&mut self, surface_type: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ColliderComponentData::VT_SURFACE_TYPE, surface_type);
+  }
+  #[inline]
+  pub fn add_trigger_debounce_seconds(&mut self, trigger_debounce_seconds: f32) {
+    self.fbb_.push_slot::<f32>(ColliderComponentData::VT_TRIGGER_DEBOUNCE_SECONDS, trigger_debounce_seconds, 0.0);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ColliderComponentDataBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     ColliderComponentDataBuilder {
@@ -318,6 +846,25 @@ impl core::fmt::Debug for ColliderComponentData<'_> {
       ds.field("height", &self.height());
       ds.field("material_name", &self.material_name());
       ds.field("mesh_path", &self.mesh_path());
+      ds.field("layer", &self.layer());
+      ds.field("collision_mask", &self.collision_mask());
+      ds.field("center", &self.center());
+      ds.field("is_2d", &self.is_2d());
+      ds.field("size_2d", &self.size_2d());
+      ds.field("density", &self.density());
+      ds.field("mass_override", &self.mass_override());
+      ds.field("schema_version", &self.schema_version());
+      ds.field("debug_color", &self.debug_color());
+      ds.field("capsule_axis", &self.capsule_axis());
+      ds.field("mesh_is_convex", &self.mesh_is_convex());
+      ds.field("trigger_channel", &self.trigger_channel());
+      ds.field("contact_margin", &self.contact_margin());
+      ds.field("trigger_dwell_seconds", &self.trigger_dwell_seconds());
+      ds.field("trigger_with_triggers", &self.trigger_with_triggers());
+      ds.field("is_static", &self.is_static());
+      ds.field("units", &self.units());
+      ds.field("surface_type", &self.surface_type());
+      ds.field("trigger_debounce_seconds", &self.trigger_debounce_seconds());
       ds.finish()
   }
 }
@@ -0,0 +1,258 @@
+// Migration aid for collider buffers written big-endian by a misconfigured
+// producer.
+
+use crate::collider_component::*;
+use crate::collider_component_generated::*;
+use crate::collider_validation::*;
+
+use flatbuffers::{InvalidFlatbuffer, VOffsetT, SIZE_UOFFSET, SIZE_VOFFSET};
+
+/// Loads a collider from a buffer whose multi-byte values were all written
+/// big-endian, and returns it as an owned component.
+///
+/// FlatBuffers is little-endian on the wire, but the toolchain on one of our
+/// embedded devices wrote everything big-endian: the root offset, the table's
+/// vtable offset and vtable entries, string offsets and lengths, and every
+/// scalar and struct component. This copies `buf`, converts each of those
+/// values back to little-endian (one-byte fields, string bytes and the file
+/// identifier are left alone), then verifies the copy like
+/// `root_as_collider_component_data` and converts it. Only use it on buffers
+/// known to come from that producer; a correct buffer loaded this way is
+/// garbled and almost always rejected.
+pub fn root_as_collider_from_be(buf: &[u8]) -> Result<ColliderComponent, ColliderLoadError> {
+    let mut le = buf.to_vec();
+    swap_collider_from_be(&mut le)?;
+    let data = root_as_collider_component_data(&le)?;
+    Ok(ColliderComponent::from(data))
+}
+
+/// How the value of a field is converted.
+enum Swap {
+    U16,
+    U32,
+    /// A struct of this many consecutive 4-byte floats.
+    F32s(usize),
+    String,
+}
+
+/// Fields holding multi-byte values, by vtable slot. The one-byte fields
+/// (the enums, the bools and `debug_color`) and fields unknown to this schema
+/// are left alone. Add new multi-byte fields here as the schema grows.
+const SWAPPED_FIELDS: [(VOffsetT, Swap); 17] = [
+    (ColliderComponentData::VT_SIZE, Swap::F32s(3)),
+    (ColliderComponentData::VT_RADIUS, Swap::U32),
+    (ColliderComponentData::VT_HEIGHT, Swap::U32),
+    (ColliderComponentData::VT_MATERIAL_NAME, Swap::String),
+    (ColliderComponentData::VT_MESH_PATH, Swap::String),
+    (ColliderComponentData::VT_LAYER, Swap::U32),
+    (ColliderComponentData::VT_COLLISION_MASK, Swap::U32),
+    (ColliderComponentData::VT_CENTER, Swap::F32s(3)),
+    (ColliderComponentData::VT_SIZE_2D, Swap::F32s(2)),
+    (ColliderComponentData::VT_DENSITY, Swap::U32),
+    (ColliderComponentData::VT_MASS_OVERRIDE, Swap::U32),
+    (ColliderComponentData::VT_SCHEMA_VERSION, Swap::U16),
+    (ColliderComponentData::VT_TRIGGER_CHANNEL, Swap::String),
+    (ColliderComponentData::VT_CONTACT_MARGIN, Swap::U32),
+    (ColliderComponentData::VT_TRIGGER_DWELL_SECONDS, Swap::U32),
+    (ColliderComponentData::VT_SURFACE_TYPE, Swap::String),
+    (
+        ColliderComponentData::VT_TRIGGER_DEBOUNCE_SECONDS,
+        Swap::U32,
+    ),
+];
+
+/// Rewrites the big-endian values of a collider buffer in place.
+fn swap_collider_from_be(buf: &mut [u8]) -> Result<(), InvalidFlatbuffer> {
+    let table = swap_u32(buf, 0)? as usize;
+    let soffset = swap_u32(buf, table)? as i32;
+    let vtable = (table as i64 - soffset as i64)
+        .try_into()
+        .map_err(|_| out_of_bounds(table, SIZE_UOFFSET))?;
+    let vtable_len = swap_u16(buf, vtable)? as usize;
+    swap_u16(buf, vtable + SIZE_VOFFSET)?;
+    let mut slots = Vec::new();
+    for slot in (2 * SIZE_VOFFSET..vtable_len).step_by(SIZE_VOFFSET) {
+        slots.push((slot as VOffsetT, swap_u16(buf, vtable + slot)?));
+    }
+
+    // Strings may be shared between fields; swap each length only once.
+    let mut swapped_strings = Vec::new();
+    for (slot, field) in slots {
+        let Some((_, swap)) = SWAPPED_FIELDS.iter().find(|(s, _)| *s == slot) else {
+            continue;
+        };
+        if field == 0 {
+            continue;
+        }
+        let pos = table + field as usize;
+        match swap {
+            Swap::U16 => {
+                swap_u16(buf, pos)?;
+            }
+            Swap::U32 => {
+                swap_u32(buf, pos)?;
+            }
+            Swap::F32s(count) => {
+                for i in 0..*count {
+                    swap_u32(buf, pos + 4 * i)?;
+                }
+            }
+            Swap::String => {
+                let string = pos + swap_u32(buf, pos)? as usize;
+                if !swapped_strings.contains(&string) {
+                    swap_u32(buf, string)?;
+                    swapped_strings.push(string);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Converts the big-endian `u16` at `pos` to little-endian and returns it.
+fn swap_u16(buf: &mut [u8], pos: usize) -> Result<u16, InvalidFlatbuffer> {
+    let bytes: &mut [u8; 2] = buf
+        .get_mut(pos..)
+        .and_then(|b| b.get_mut(..2))
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| out_of_bounds(pos, 2))?;
+    let value = u16::from_be_bytes(*bytes);
+    *bytes = value.to_le_bytes();
+    Ok(value)
+}
+
+/// Converts the big-endian 4-byte value at `pos` to little-endian and returns
+/// it as a `u32`; floats are swapped by their bit pattern.
+fn swap_u32(buf: &mut [u8], pos: usize) -> Result<u32, InvalidFlatbuffer> {
+    let bytes: &mut [u8; 4] = buf
+        .get_mut(pos..)
+        .and_then(|b| b.get_mut(..4))
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| out_of_bounds(pos, 4))?;
+    let value = u32::from_be_bytes(*bytes);
+    *bytes = value.to_le_bytes();
+    Ok(value)
+}
+
+fn out_of_bounds(pos: usize, len: usize) -> InvalidFlatbuffer {
+    InvalidFlatbuffer::RangeOutOfBounds {
+        range: pos..pos.saturating_add(len),
+        error_trace: Default::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::common_types_generated::*;
+
+    use flatbuffers::FlatBufferBuilder;
+
+    type Data = ColliderComponentData<'static>;
+
+    /// Rewrites a little-endian collider buffer the way the misconfigured
+    /// producer writes it, independently of `SWAPPED_FIELDS`.
+    fn to_be(le: &[u8]) -> Vec<u8> {
+        let read_u32 = |pos: usize| u32::from_le_bytes(le[pos..pos + 4].try_into().unwrap());
+        let read_u16 = |pos: usize| u16::from_le_bytes(le[pos..pos + 2].try_into().unwrap());
+        let mut be = le.to_vec();
+        let mut reverse = |pos: usize, len: usize| be[pos..pos + len].reverse();
+
+        let table = read_u32(0) as usize;
+        let vtable = (table as i64 - read_u32(table) as i32 as i64) as usize;
+        let vtable_len = read_u16(vtable) as usize;
+        reverse(0, 4);
+        reverse(table, 4);
+        let mut strings = Vec::new();
+        for slot in (0..vtable_len).step_by(2) {
+            reverse(vtable + slot, 2);
+            let field = read_u16(vtable + slot) as usize;
+            if slot < 4 || field == 0 {
+                continue;
+            }
+            let pos = table + field;
+            match slot as VOffsetT {
+                Data::VT_SIZE | Data::VT_CENTER => (0..3).for_each(|i| reverse(pos + 4 * i, 4)),
+                Data::VT_SIZE_2D => (0..2).for_each(|i| reverse(pos + 4 * i, 4)),
+                Data::VT_SCHEMA_VERSION => reverse(pos, 2),
+                Data::VT_MATERIAL_NAME
+                | Data::VT_MESH_PATH
+                | Data::VT_TRIGGER_CHANNEL
+                | Data::VT_SURFACE_TYPE => {
+                    let string = pos + read_u32(pos) as usize;
+                    reverse(pos, 4);
+                    if !strings.contains(&string) {
+                        reverse(string, 4);
+                        strings.push(string);
+                    }
+                }
+                Data::VT_RADIUS
+                | Data::VT_HEIGHT
+                | Data::VT_LAYER
+                | Data::VT_COLLISION_MASK
+                | Data::VT_DENSITY
+                | Data::VT_MASS_OVERRIDE
+                | Data::VT_CONTACT_MARGIN
+                | Data::VT_TRIGGER_DWELL_SECONDS
+                | Data::VT_TRIGGER_DEBOUNCE_SECONDS => reverse(pos, 4),
+                _ => {}
+            }
+        }
+        be
+    }
+
+    #[test]
+    fn big_endian_buffer_loads_as_the_original_collider() {
+        let collider = ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            is_trigger: true,
+            size: Some(Vec3::new(1.0, 2.5, -3.0)),
+            radius: 0.25,
+            height: 1.5,
+            material_name: Some("ice".into()),
+            mesh_path: Some("m.obj".into()),
+            layer: 3,
+            collision_mask: 0x1234_5678,
+            center: Some(Vec3::new(0.1, 0.2, 0.3)),
+            size_2d: Some(Vec2::new(4.0, 5.0)),
+            density: 2.0,
+            mass_override: 7.0,
+            debug_color: Some(Color::new(1, 2, 3, 4)),
+            capsule_axis: CapsuleAxis::X,
+            trigger_channel: Some("zone".into()),
+            contact_margin: 0.5,
+            trigger_dwell_seconds: 2.0,
+            surface_type: Some("gravel".into()),
+            trigger_debounce_seconds: 0.25,
+            ..Default::default()
+        };
+        let le = make_collider_buffer(&collider);
+        let be = to_be(&le);
+        assert_ne!(be, le);
+        assert_eq!(root_as_collider_from_be(&be).unwrap(), collider);
+    }
+
+    #[test]
+    fn shared_strings_are_swapped_once() {
+        let mut fbb = FlatBufferBuilder::new();
+        let rock = fbb.create_shared_string("rock");
+        let offset = ColliderComponentData::create(
+            &mut fbb,
+            &ColliderComponentDataArgs {
+                material_name: Some(rock),
+                mesh_path: Some(rock),
+                ..Default::default()
+            },
+        );
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        let collider = root_as_collider_from_be(&to_be(fbb.finished_data())).unwrap();
+        assert_eq!(collider.material_name.as_deref(), Some("rock"));
+        assert_eq!(collider.mesh_path.as_deref(), Some("rock"));
+    }
+
+    #[test]
+    fn truncated_buffer_is_rejected() {
+        assert!(root_as_collider_from_be(&[0, 0, 0, 200]).is_err());
+    }
+}
@@ -0,0 +1,525 @@
+// Generic field enumeration for property-grid style editors.
+
+use crate::collider_component_generated::*;
+use crate::common_types_generated::*;
+
+use std::fmt;
+
+use flatbuffers::VOffsetT;
+
+/// The value of one collider field, as listed by `collider_fields`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldValue<'a> {
+    Bool(bool),
+    U16(u16),
+    U32(u32),
+    F32(f32),
+    /// An enum field's variant name, or `"UNKNOWN"` for a value this build
+    /// does not know.
+    Enum(&'static str),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Color(Color),
+    Str(Option<&'a str>),
+}
+
+/// Lists the collider's fields in schema order as `(name, value)` pairs, so an
+/// editor can render one row per field without knowing the schema.
+///
+/// Scalar, enum and string fields always have a value (their default when
+/// missing from the buffer; strings as `Str(None)`) and are always listed.
+/// Struct fields such as `size` or `center` have no default and are listed
+/// only when present.
+pub fn collider_fields<'a>(
+    data: &ColliderComponentData<'a>,
+) -> Vec<(&'static str, FieldValue<'a>)> {
+    let mut fields = vec![
+        (
+            "collider_type",
+            FieldValue::Enum(enum_name(data.collider_type().variant_name())),
+        ),
+        ("is_trigger", FieldValue::Bool(data.is_trigger())),
+    ];
+    if let Some(size) = data.size() {
+        fields.push(("size", FieldValue::Vec3(*size)));
+    }
+    fields.extend([
+        ("radius", FieldValue::F32(data.radius())),
+        ("height", FieldValue::F32(data.height())),
+        ("material_name", FieldValue::Str(data.material_name())),
+        ("mesh_path", FieldValue::Str(data.mesh_path())),
+        ("layer", FieldValue::U32(data.layer())),
+        ("collision_mask", FieldValue::U32(data.collision_mask())),
+    ]);
+    if let Some(center) = data.center() {
+        fields.push(("center", FieldValue::Vec3(*center)));
+    }
+    fields.push(("is_2d", FieldValue::Bool(data.is_2d())));
+    if let Some(size_2d) = data.size_2d() {
+        fields.push(("size_2d", FieldValue::Vec2(*size_2d)));
+    }
+    fields.extend([
+        ("density", FieldValue::F32(data.density())),
+        ("mass_override", FieldValue::F32(data.mass_override())),
+        ("schema_version", FieldValue::U16(data.schema_version())),
+    ]);
+    if let Some(debug_color) = data.debug_color() {
+        fields.push(("debug_color", FieldValue::Color(*debug_color)));
+    }
+    fields.extend([
+        (
+            "capsule_axis",
+            FieldValue::Enum(enum_name(data.capsule_axis().variant_name())),
+        ),
+        ("mesh_is_convex", FieldValue::Bool(data.mesh_is_convex())),
+        ("trigger_channel", FieldValue::Str(data.trigger_channel())),
+        ("contact_margin", FieldValue::F32(data.contact_margin())),
+        (
+            "trigger_dwell_seconds",
+            FieldValue::F32(data.trigger_dwell_seconds()),
+        ),
+        (
+            "trigger_with_triggers",
+            FieldValue::Bool(data.trigger_with_triggers()),
+        ),
+        ("is_static", FieldValue::Bool(data.is_static())),
+        (
+            "units",
+            FieldValue::Enum(enum_name(data.units().variant_name())),
+        ),
+        ("surface_type", FieldValue::Str(data.surface_type())),
+        (
+            "trigger_debounce_seconds",
+            FieldValue::F32(data.trigger_debounce_seconds()),
+        ),
+    ]);
+    fields
+}
+
+fn enum_name(name: Option<&'static str>) -> &'static str {
+    name.unwrap_or("UNKNOWN")
+}
+
+flatbuffers::bitflags::bitflags! {
+    /// One bit per `ColliderComponentData` field, numbered by field id.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct ColliderFieldMask: u32 {
+        const COLLIDER_TYPE = 1 << 0;
+        const IS_TRIGGER = 1 << 1;
+        const SIZE = 1 << 2;
+        const RADIUS = 1 << 3;
+        const HEIGHT = 1 << 4;
+        const MATERIAL_NAME = 1 << 5;
+        const MESH_PATH = 1 << 6;
+        const LAYER = 1 << 7;
+        const COLLISION_MASK = 1 << 8;
+        const CENTER = 1 << 9;
+        const IS_2D = 1 << 10;
+        const SIZE_2D = 1 << 11;
+        const DENSITY = 1 << 12;
+        const MASS_OVERRIDE = 1 << 13;
+        const SCHEMA_VERSION = 1 << 14;
+        const DEBUG_COLOR = 1 << 15;
+        const CAPSULE_AXIS = 1 << 16;
+        const MESH_IS_CONVEX = 1 << 17;
+        const TRIGGER_CHANNEL = 1 << 18;
+        const CONTACT_MARGIN = 1 << 19;
+        const TRIGGER_DWELL_SECONDS = 1 << 20;
+        const TRIGGER_WITH_TRIGGERS = 1 << 21;
+        const IS_STATIC = 1 << 22;
+        const UNITS = 1 << 23;
+        const SURFACE_TYPE = 1 << 24;
+        const TRIGGER_DEBOUNCE_SECONDS = 1 << 25;
+    }
+}
+
+/// Which fields were actually written to the buffer.
+///
+/// Accessors return the schema default for a missing field, so `radius() ==
+/// 0.0` cannot tell an explicit 0 from an omitted field; this reads the vtable
+/// instead. Note that builders omit scalars equal to their default unless
+/// `force_defaults` is set, so an explicit default is usually absent too.
+pub fn collider_present_fields(data: &ColliderComponentData) -> ColliderFieldMask {
+    let vtable = data._tab.vtable();
+    FIELD_SLOTS
+        .iter()
+        .filter(|(_, _, slot)| vtable.get(*slot) != 0)
+        .fold(ColliderFieldMask::empty(), |mask, (_, field, _)| {
+            mask | *field
+        })
+}
+
+/// Human-readable breakdown of a collider buffer for diagnosing bad content:
+/// the identifier and root offset, then one line per field in schema order
+/// with its vtable slot, byte position and value.
+///
+/// Absent fields show `-` for the position and their default value. The
+/// header is printed even for a buffer that fails verification, followed by
+/// the verifier error instead of the fields. The format is stable, so dumps
+/// can be diffed.
+pub fn debug_dump_collider(buf: &[u8]) -> String {
+    let mut out = String::new();
+    match buf.get(4..8) {
+        Some(id) => out.push_str(&format!(
+            "identifier: {}\n",
+            String::from_utf8_lossy(id).escape_debug()
+        )),
+        None => out.push_str("identifier: <missing>\n"),
+    }
+    if let Some(root) = buf.get(..4) {
+        let root = u32::from_le_bytes(root.try_into().unwrap());
+        out.push_str(&format!("root offset: {root}\n"));
+    }
+    let data = match root_as_collider_component_data(buf) {
+        Ok(data) => data,
+        Err(err) => {
+            out.push_str(&format!("invalid: {err}\n"));
+            return out;
+        }
+    };
+    let table = data._tab;
+    let vtable = table.vtable();
+    let soffset = i32::from_le_bytes(buf[table.loc()..table.loc() + 4].try_into().unwrap());
+    out.push_str(&format!(
+        "table at {}, vtable at {} ({} bytes)\n",
+        table.loc(),
+        table.loc() as i64 - i64::from(soffset),
+        vtable.num_bytes()
+    ));
+    let values = collider_fields(&data);
+    for (name, _, slot) in FIELD_SLOTS {
+        let value = values
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map_or_else(|| "none".to_owned(), |(_, value)| value.to_string());
+        let position = match vtable.get(slot) {
+            0 => "-".to_owned(),
+            offset => (table.loc() + offset as usize).to_string(),
+        };
+        out.push_str(&format!(
+            "  {name:<24} vt {slot:<3} @ {position:<5} {value}\n"
+        ));
+    }
+    out
+}
+
+impl fmt::Display for FieldValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(v) => write!(f, "{v}"),
+            Self::U16(v) => write!(f, "{v}"),
+            Self::U32(v) => write!(f, "{v}"),
+            Self::F32(v) => write!(f, "{v}"),
+            Self::Enum(name) => f.write_str(name),
+            Self::Vec2(v) => write!(f, "({}, {})", v.x(), v.y()),
+            Self::Vec3(v) => write!(f, "({}, {}, {})", v.x(), v.y(), v.z()),
+            Self::Color(c) => write!(f, "rgba({}, {}, {}, {})", c.r(), c.g(), c.b(), c.a()),
+            Self::Str(Some(s)) => write!(f, "{s:?}"),
+            Self::Str(None) => f.write_str("none"),
+        }
+    }
+}
+
+/// Name, mask bit and vtable slot of every field, in schema order.
+const FIELD_SLOTS: [(&str, ColliderFieldMask, VOffsetT); 26] = [
+    (
+        "collider_type",
+        ColliderFieldMask::COLLIDER_TYPE,
+        ColliderComponentData::VT_COLLIDER_TYPE,
+    ),
+    (
+        "is_trigger",
+        ColliderFieldMask::IS_TRIGGER,
+        ColliderComponentData::VT_IS_TRIGGER,
+    ),
+    (
+        "size",
+        ColliderFieldMask::SIZE,
+        ColliderComponentData::VT_SIZE,
+    ),
+    (
+        "radius",
+        ColliderFieldMask::RADIUS,
+        ColliderComponentData::VT_RADIUS,
+    ),
+    (
+        "height",
+        ColliderFieldMask::HEIGHT,
+        ColliderComponentData::VT_HEIGHT,
+    ),
+    (
+        "material_name",
+        ColliderFieldMask::MATERIAL_NAME,
+        ColliderComponentData::VT_MATERIAL_NAME,
+    ),
+    (
+        "mesh_path",
+        ColliderFieldMask::MESH_PATH,
+        ColliderComponentData::VT_MESH_PATH,
+    ),
+    (
+        "layer",
+        ColliderFieldMask::LAYER,
+        ColliderComponentData::VT_LAYER,
+    ),
+    (
+        "collision_mask",
+        ColliderFieldMask::COLLISION_MASK,
+        ColliderComponentData::VT_COLLISION_MASK,
+    ),
+    (
+        "center",
+        ColliderFieldMask::CENTER,
+        ColliderComponentData::VT_CENTER,
+    ),
+    (
+        "is_2d",
+        ColliderFieldMask::IS_2D,
+        ColliderComponentData::VT_IS_2D,
+    ),
+    (
+        "size_2d",
+        ColliderFieldMask::SIZE_2D,
+        ColliderComponentData::VT_SIZE_2D,
+    ),
+    (
+        "density",
+        ColliderFieldMask::DENSITY,
+        ColliderComponentData::VT_DENSITY,
+    ),
+    (
+        "mass_override",
+        ColliderFieldMask::MASS_OVERRIDE,
+        ColliderComponentData::VT_MASS_OVERRIDE,
+    ),
+    (
+        "schema_version",
+        ColliderFieldMask::SCHEMA_VERSION,
+        ColliderComponentData::VT_SCHEMA_VERSION,
+    ),
+    (
+        "debug_color",
+        ColliderFieldMask::DEBUG_COLOR,
+        ColliderComponentData::VT_DEBUG_COLOR,
+    ),
+    (
+        "capsule_axis",
+        ColliderFieldMask::CAPSULE_AXIS,
+        ColliderComponentData::VT_CAPSULE_AXIS,
+    ),
+    (
+        "mesh_is_convex",
+        ColliderFieldMask::MESH_IS_CONVEX,
+        ColliderComponentData::VT_MESH_IS_CONVEX,
+    ),
+    (
+        "trigger_channel",
+        ColliderFieldMask::TRIGGER_CHANNEL,
+        ColliderComponentData::VT_TRIGGER_CHANNEL,
+    ),
+    (
+        "contact_margin",
+        ColliderFieldMask::CONTACT_MARGIN,
+        ColliderComponentData::VT_CONTACT_MARGIN,
+    ),
+    (
+        "trigger_dwell_seconds",
+        ColliderFieldMask::TRIGGER_DWELL_SECONDS,
+        ColliderComponentData::VT_TRIGGER_DWELL_SECONDS,
+    ),
+    (
+        "trigger_with_triggers",
+        ColliderFieldMask::TRIGGER_WITH_TRIGGERS,
+        ColliderComponentData::VT_TRIGGER_WITH_TRIGGERS,
+    ),
+    (
+        "is_static",
+        ColliderFieldMask::IS_STATIC,
+        ColliderComponentData::VT_IS_STATIC,
+    ),
+    (
+        "units",
+        ColliderFieldMask::UNITS,
+        ColliderComponentData::VT_UNITS,
+    ),
+    (
+        "surface_type",
+        ColliderFieldMask::SURFACE_TYPE,
+        ColliderComponentData::VT_SURFACE_TYPE,
+    ),
+    (
+        "trigger_debounce_seconds",
+        ColliderFieldMask::TRIGGER_DEBOUNCE_SECONDS,
+        ColliderComponentData::VT_TRIGGER_DEBOUNCE_SECONDS,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+    use flatbuffers::{DefaultAllocator, FlatBufferBuilder};
+
+    #[test]
+    fn fields_are_listed_in_schema_order() {
+        let buf = make_collider_buffer(&ColliderComponent {
+            size: Some(Vec3::new(1.0, 2.0, 3.0)),
+            is_trigger: true,
+            ..Default::default()
+        });
+        let fields = collider_fields(&root_as_collider_component_data(&buf).unwrap());
+        let names: Vec<_> = fields.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            [
+                "collider_type",
+                "is_trigger",
+                "size",
+                "radius",
+                "height",
+                "material_name",
+                "mesh_path",
+                "layer",
+                "collision_mask",
+                "is_2d",
+                "density",
+                "mass_override",
+                "schema_version",
+                "capsule_axis",
+                "mesh_is_convex",
+                "trigger_channel",
+                "contact_margin",
+                "trigger_dwell_seconds",
+                "trigger_with_triggers",
+                "is_static",
+                "units",
+                "surface_type",
+                "trigger_debounce_seconds",
+            ]
+        );
+        assert_eq!(fields[0].1, FieldValue::Enum("Box"));
+        assert_eq!(fields[1].1, FieldValue::Bool(true));
+        assert_eq!(fields[2].1, FieldValue::Vec3(Vec3::new(1.0, 2.0, 3.0)));
+        assert_eq!(fields[5].1, FieldValue::Str(None));
+        assert_eq!(fields[8].1, FieldValue::U32(u32::MAX));
+    }
+
+    #[test]
+    fn present_struct_fields_are_listed() {
+        let buf = make_collider_buffer(&ColliderComponent {
+            center: Some(Vec3::new(0.0, 1.0, 0.0)),
+            debug_color: Some(Color::new(1, 2, 3, 4)),
+            ..Default::default()
+        });
+        let fields = collider_fields(&root_as_collider_component_data(&buf).unwrap());
+        assert!(fields.contains(&("center", FieldValue::Vec3(Vec3::new(0.0, 1.0, 0.0)))));
+        assert!(fields.contains(&("debug_color", FieldValue::Color(Color::new(1, 2, 3, 4)))));
+        assert!(!fields.iter().any(|(name, _)| *name == "size"));
+    }
+
+    fn finish_with<'a>(
+        fbb: &mut FlatBufferBuilder<'a>,
+        build: impl FnOnce(&mut ColliderComponentDataBuilder<'a, '_, DefaultAllocator>),
+    ) {
+        let mut builder = ColliderComponentDataBuilder::new(fbb);
+        build(&mut builder);
+        let offset = builder.finish();
+        finish_collider_component_data_buffer(fbb, offset);
+    }
+
+    #[test]
+    fn present_fields_reflect_what_was_written() {
+        let mut fbb = FlatBufferBuilder::new();
+        finish_with(&mut fbb, |b| b.add_collider_type(ColliderType::Sphere));
+        let collider = root_as_collider_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(
+            collider_present_fields(&collider),
+            ColliderFieldMask::COLLIDER_TYPE
+        );
+
+        let mut fbb = FlatBufferBuilder::new();
+        fbb.force_defaults(true);
+        let size = Vec3::new(1.0, 1.0, 1.0);
+        finish_with(&mut fbb, |b| {
+            b.add_radius(0.0);
+            b.add_size(&size);
+        });
+        let collider = root_as_collider_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(
+            collider_present_fields(&collider),
+            ColliderFieldMask::RADIUS | ColliderFieldMask::SIZE
+        );
+    }
+
+    #[test]
+    fn default_collider_writes_only_its_version() {
+        let buf = make_collider_buffer(&ColliderComponent::default());
+        let collider = root_as_collider_component_data(&buf).unwrap();
+        assert_eq!(
+            collider_present_fields(&collider),
+            ColliderFieldMask::SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn dump_lists_slots_positions_and_values() {
+        let buf = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 0.5,
+            material_name: Some("ice".into()),
+            ..Default::default()
+        });
+        let dump = debug_dump_collider(&buf);
+        let lines: Vec<_> = dump.lines().collect();
+        assert_eq!(
+            lines[..7],
+            [
+                "identifier: CLDR",
+                "root offset: 44",
+                "table at 44, vtable at 10 (34 bytes)",
+                "  collider_type            vt 4   @ 49    Sphere",
+                "  is_trigger               vt 6   @ -     false",
+                "  size                     vt 8   @ -     none",
+                "  radius                   vt 10  @ 52    0.5",
+            ]
+        );
+        assert!(lines.contains(&"  material_name            vt 14  @ 56    \"ice\""));
+        assert!(lines.contains(&"  collision_mask           vt 20  @ -     4294967295"));
+    }
+
+    #[test]
+    fn dump_of_an_invalid_buffer_reports_the_error() {
+        let buf = make_collider_buffer(&ColliderComponent::default());
+        let dump = debug_dump_collider(&buf[..10]);
+        assert!(
+            dump.starts_with("identifier: CLDR\nroot offset: "),
+            "{dump}"
+        );
+        assert!(dump.contains("\ninvalid: "), "{dump}");
+
+        let err = root_as_collider_component_data(&[]).unwrap_err();
+        assert_eq!(
+            debug_dump_collider(&[]),
+            format!("identifier: <missing>\ninvalid: {err}\n")
+        );
+    }
+
+    #[test]
+    fn dump_columns_line_up_for_every_field() {
+        let buf = make_collider_buffer(&ColliderComponent {
+            is_trigger: true,
+            trigger_debounce_seconds: 0.2,
+            ..Default::default()
+        });
+        let dump = debug_dump_collider(&buf);
+        let columns: Vec<_> = dump
+            .lines()
+            .filter(|line| line.starts_with("  "))
+            .map(|line| line.find(" vt ").unwrap())
+            .collect();
+        assert_eq!(columns.len(), FIELD_SLOTS.len());
+        assert!(columns.iter().all(|&column| column == columns[0]));
+        assert!(dump.contains("  trigger_debounce_seconds vt "));
+    }
+}
@@ -0,0 +1,75 @@
+// Reading colliders that arrive split across several network fragments.
+
+use crate::collider_component_generated::*;
+
+use flatbuffers::InvalidFlatbuffer;
+
+/// A collider buffer reassembled from fragments and already verified.
+///
+/// The reader borrows the buffer, so the two cannot be returned side by side;
+/// this owns the bytes and hands out the reader with `collider`, which is
+/// cheap because verification happened once in `reassemble_and_read`.
+pub struct ReassembledCollider {
+    buf: Vec<u8>,
+}
+
+impl ReassembledCollider {
+    pub fn collider(&self) -> ColliderComponentData<'_> {
+        // Safety:
+        // `buf` was verified as a `ColliderComponentData` when this value was
+        // created and is never modified afterwards.
+        unsafe { root_as_collider_component_data_unchecked(&self.buf) }
+    }
+
+    /// The reassembled bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+/// Concatenates `fragments` in order with a single allocation and verifies the
+/// result as a collider buffer.
+///
+/// No fragments, or fragments that do not add up to a valid buffer, are
+/// reported as the verifier's error.
+pub fn reassemble_and_read(fragments: &[&[u8]]) -> Result<ReassembledCollider, InvalidFlatbuffer> {
+    let buf = fragments.concat();
+    root_as_collider_component_data(&buf)?;
+    Ok(ReassembledCollider { buf })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+
+    #[test]
+    fn fragments_are_joined_and_verified() {
+        let buf = make_collider_buffer(&ColliderComponent {
+            radius: 2.5,
+            material_name: Some("ice".into()),
+            ..Default::default()
+        });
+        let (head, rest) = buf.split_at(5);
+        let (middle, tail) = rest.split_at(rest.len() / 2);
+        let collider = reassemble_and_read(&[head, middle, tail]).unwrap();
+        assert_eq!(collider.collider().radius(), 2.5);
+        assert_eq!(collider.collider().material_name(), Some("ice"));
+        assert_eq!(collider.bytes(), &buf[..]);
+        assert_eq!(collider.into_bytes(), buf);
+    }
+
+    #[test]
+    fn missing_fragments_are_rejected() {
+        let buf = make_collider_buffer(&ColliderComponent::default());
+        let (head, rest) = buf.split_at(5);
+        let (_, tail) = rest.split_at(rest.len() / 2);
+        assert!(reassemble_and_read(&[]).is_err());
+        assert!(reassemble_and_read(&[head, tail]).is_err());
+    }
+}
@@ -0,0 +1,118 @@
+// Fuzzing entry point and seed corpus for collider buffers.
+
+use std::hint::black_box;
+
+use crate::collider_component::*;
+use crate::collider_component_generated::*;
+use crate::collider_geometry::*;
+use crate::collider_validation::*;
+use crate::common_types_generated::*;
+
+use flatbuffers::FlatBufferBuilder;
+
+/// Runs `data` through the verifier and, if it is accepted, reads every field.
+///
+/// This is the body for a `cargo fuzz` target (or a loop replaying a corpus of
+/// truncated or mutated level files) and must not panic for any input. Once
+/// `root_as_collider_component_data` accepts a buffer, the verifier has
+/// bounds-checked every field, string and struct the accessors can reach, so
+/// the accessors are total; any panic found here is a bug in a helper built on
+/// top of them.
+pub fn fuzz_verify_collider(data: &[u8]) {
+    let Ok(collider) = root_as_collider_component_data(data) else {
+        return;
+    };
+    black_box(ColliderComponent::from(collider));
+    let _ = black_box(validate_collider(&collider));
+    black_box(collider_local_aabb(&collider));
+    black_box(collider_computed_mass(&collider));
+    black_box(collider.to_string());
+}
+
+/// Small set of valid collider buffers, one or more per shape, to start a
+/// fuzzing corpus from.
+pub fn collider_seed_corpus() -> Vec<Vec<u8>> {
+    let seeds = [
+        ColliderComponent::default(),
+        ColliderComponent {
+            size: Some(Vec3::new(1.0, 2.0, 1.0)),
+            center: Some(Vec3::new(0.0, 1.0, 0.0)),
+            material_name: Some("stone".to_owned()),
+            ..Default::default()
+        },
+        ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 0.5,
+            is_trigger: true,
+            debug_color: Some(Color::new(255, 0, 0, 255)),
+            trigger_channel: Some("checkpoint".to_owned()),
+            ..Default::default()
+        },
+        ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5,
+            height: 2.0,
+            layer: 2,
+            collision_mask: 0b101,
+            ..Default::default()
+        },
+        ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("level/rock.obj".to_owned()),
+            mass_override: 10.0,
+            ..Default::default()
+        },
+        ColliderComponent {
+            is_2d: true,
+            size_2d: Some(Vec2::new(1.0, 1.0)),
+            density: 2.0,
+            ..Default::default()
+        },
+    ];
+    seeds
+        .iter()
+        .map(|seed| {
+            let mut fbb = FlatBufferBuilder::new();
+            let offset = seed.serialize(&mut fbb);
+            finish_collider_component_data_buffer(&mut fbb, offset);
+            fbb.finished_data().to_vec()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seed_corpus_verifies() {
+        for seed in collider_seed_corpus() {
+            assert!(root_as_collider_component_data(&seed).is_ok());
+        }
+    }
+
+    #[test]
+    fn truncated_and_mutated_seeds_do_not_panic() {
+        // Fixed LCG so every run replays the same mutations.
+        let mut state = 12345u64;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            state >> 24
+        };
+        for seed in collider_seed_corpus() {
+            for len in 0..=seed.len() {
+                fuzz_verify_collider(&seed[..len]);
+            }
+            for _ in 0..2000 {
+                let mut mutated = seed.clone();
+                for _ in 0..3 {
+                    let i = next() as usize % mutated.len();
+                    mutated[i] = next() as u8;
+                }
+                fuzz_verify_collider(&mutated);
+            }
+        }
+    }
+}
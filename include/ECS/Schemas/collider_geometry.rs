@@ -0,0 +1,683 @@
+// Shape-derived geometry for colliders: bounds and related queries.
+
+use crate::collider_component_generated::*;
+use crate::common_types_generated::*;
+use crate::transform_component::*;
+use crate::transform_component_generated::*;
+
+use std::f32::consts::PI;
+
+/// Local-space bounds of `data` as `(min, max)`, including its `center` offset.
+///
+/// Cylinder and Cone are Y-up and a capsule runs along its `capsule_axis`. A
+/// capsule's `height` is the length of its cylindrical section, so with the
+/// caps it spans `height / 2 + radius` along that axis. 2D colliders are flat
+/// on Z. Mesh returns `None` because mesh bounds are not stored in the schema,
+/// as does a Box without a size or an unknown collider type.
+pub fn collider_local_aabb(data: &ColliderComponentData) -> Option<(Vec3, Vec3)> {
+    let (hx, hy, hz) = local_half_extents(data)?;
+    let (cx, cy, cz) = data
+        .center()
+        .map_or((0.0, 0.0, 0.0), |c| (c.x(), c.y(), c.z()));
+    Some((
+        Vec3::new(cx - hx, cy - hy, cz - hz),
+        Vec3::new(cx + hx, cy + hy, cz + hz),
+    ))
+}
+
+/// World-space bounds of `collider` placed by `transform`, as `(min, max)`.
+///
+/// The eight corners of the local AABB are scaled, rotated and translated, and
+/// the result encloses them. Scale is applied per axis before rotation, so the
+/// bounds are exact for boxes under non-uniform scale and conservative for the
+/// rounded shapes. The rotation is normalized first, since authored or
+/// accumulated rotations drift off unit length. Returns `None` wherever
+/// `collider_local_aabb` does.
+pub fn collider_world_aabb(
+    collider: &ColliderComponentData,
+    transform: &TransformComponentData,
+) -> Option<(Vec3, Vec3)> {
+    let (local_min, local_max) = collider_local_aabb(collider)?;
+    let position = transform_position(transform);
+    let rotation = transform_rotation(transform).normalized();
+    let scale = transform_scale(transform);
+
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [f32::NEG_INFINITY; 3];
+    for corner in 0..8 {
+        let pick = |bit: usize, lo: f32, hi: f32| if corner & bit == 0 { lo } else { hi };
+        let local = [
+            pick(1, local_min.x(), local_max.x()) * scale.x(),
+            pick(2, local_min.y(), local_max.y()) * scale.y(),
+            pick(4, local_min.z(), local_max.z()) * scale.z(),
+        ];
+        let rotated = rotate(&rotation, local);
+        let world = [
+            rotated[0] + position.x(),
+            rotated[1] + position.y(),
+            rotated[2] + position.z(),
+        ];
+        for axis in 0..3 {
+            min[axis] = min[axis].min(world[axis]);
+            max[axis] = max[axis].max(world[axis]);
+        }
+    }
+    Some((
+        Vec3::new(min[0], min[1], min[2]),
+        Vec3::new(max[0], max[1], max[2]),
+    ))
+}
+
+/// Rotates `v` by the unit quaternion `q`.
+fn rotate(q: &Quat, v: [f32; 3]) -> [f32; 3] {
+    let (qx, qy, qz, qw) = (q.x(), q.y(), q.z(), q.w());
+    // v' = v + 2w (q × v) + 2 q × (q × v)
+    let tx = 2.0 * (qy * v[2] - qz * v[1]);
+    let ty = 2.0 * (qz * v[0] - qx * v[2]);
+    let tz = 2.0 * (qx * v[1] - qy * v[0]);
+    [
+        v[0] + qw * tx + (qy * tz - qz * ty),
+        v[1] + qw * ty + (qz * tx - qx * tz),
+        v[2] + qw * tz + (qx * ty - qy * tx),
+    ]
+}
+
+/// Half extents of the shape around its own center, before the `center` offset.
+fn local_half_extents(data: &ColliderComponentData) -> Option<(f32, f32, f32)> {
+    let radius = data.radius();
+    let half_height = data.height() * 0.5;
+    match data.collider_type() {
+        ColliderType::Box if data.is_2d() => data
+            .size_2d()
+            .map(|size| (size.x() * 0.5, size.y() * 0.5, 0.0)),
+        ColliderType::Box => data
+            .size()
+            .map(|size| (size.x() * 0.5, size.y() * 0.5, size.z() * 0.5)),
+        ColliderType::Sphere if data.is_2d() => Some((radius, radius, 0.0)),
+        ColliderType::Sphere => Some((radius, radius, radius)),
+        ColliderType::Capsule => {
+            let long = half_height + radius;
+            let (x, y, z) = match data.capsule_axis() {
+                CapsuleAxis::X => (long, radius, radius),
+                CapsuleAxis::Z => (radius, radius, long),
+                _ => (radius, long, radius),
+            };
+            Some((x, y, if data.is_2d() { 0.0 } else { z }))
+        }
+        ColliderType::Cylinder | ColliderType::Cone => Some((radius, half_height, radius)),
+        _ => None,
+    }
+}
+
+/// Mass of the collider: `mass_override` when it is positive, otherwise the
+/// shape's volume times `density` (area times `density` for 2D colliders).
+///
+/// Returns `None` when there is no override and the volume is unknown, i.e. for
+/// Mesh, a Box without a size, or an unknown collider type.
+pub fn collider_computed_mass(data: &ColliderComponentData) -> Option<f32> {
+    if data.mass_override() > 0.0 {
+        return Some(data.mass_override());
+    }
+    collider_volume(data).map(|volume| volume * data.density())
+}
+
+/// Volume of the shape, or its area for 2D colliders.
+///
+/// A capsule is a cylinder of length `height` plus two hemispherical caps,
+/// i.e. `πr²h + 4/3 πr³`. Returns `None` for Mesh, a Box without a size, and
+/// unknown collider types.
+pub fn collider_volume(data: &ColliderComponentData) -> Option<f32> {
+    let r = data.radius();
+    let h = data.height();
+    match data.collider_type() {
+        ColliderType::Box if data.is_2d() => data.size_2d().map(|s| s.x() * s.y()),
+        ColliderType::Box => data.size().map(|s| s.x() * s.y() * s.z()),
+        ColliderType::Sphere if data.is_2d() => Some(PI * r * r),
+        ColliderType::Sphere => Some(4.0 / 3.0 * PI * r * r * r),
+        ColliderType::Capsule if data.is_2d() => Some(2.0 * r * h + PI * r * r),
+        ColliderType::Capsule => Some(PI * r * r * h + 4.0 / 3.0 * PI * r * r * r),
+        ColliderType::Cylinder => Some(PI * r * r * h),
+        ColliderType::Cone => Some(PI * r * r * h / 3.0),
+        _ => None,
+    }
+}
+
+/// Surface area of the shape, or its perimeter for 2D colliders.
+///
+/// A capsule's surface is the cylinder's side plus one full sphere from the two
+/// caps, `2πrh + 4πr²`; its flat ends are not part of the surface. Returns
+/// `None` wherever `collider_volume` does.
+pub fn collider_surface_area(data: &ColliderComponentData) -> Option<f32> {
+    let r = data.radius();
+    let h = data.height();
+    match data.collider_type() {
+        ColliderType::Box if data.is_2d() => data.size_2d().map(|s| 2.0 * (s.x() + s.y())),
+        ColliderType::Box => data
+            .size()
+            .map(|s| 2.0 * (s.x() * s.y() + s.y() * s.z() + s.z() * s.x())),
+        ColliderType::Sphere if data.is_2d() => Some(2.0 * PI * r),
+        ColliderType::Sphere => Some(4.0 * PI * r * r),
+        ColliderType::Capsule if data.is_2d() => Some(2.0 * h + 2.0 * PI * r),
+        ColliderType::Capsule => Some(2.0 * PI * r * h + 4.0 * PI * r * r),
+        ColliderType::Cylinder => Some(2.0 * PI * r * h + 2.0 * PI * r * r),
+        ColliderType::Cone => Some(PI * r * r + PI * r * (r * r + h * h).sqrt()),
+        _ => None,
+    }
+}
+
+/// Radius of a sphere around the entity origin that encloses the collider, for
+/// spatial hashing.
+///
+/// Box uses half the diagonal of `size` (`size_2d` for 2D colliders), Sphere
+/// its `radius` and Capsule `radius + height / 2`. Cylinder and Cone reach
+/// farthest at their rims, `sqrt(radius² + (height / 2)²)`. The length of the
+/// `center` offset is added, so the sphere stays centered on the entity.
+/// Returns `None` for Mesh, a Box without a size, and unknown collider types.
+pub fn collider_bounding_radius(data: &ColliderComponentData) -> Option<f32> {
+    let r = data.radius();
+    let half_height = data.height() * 0.5;
+    let shape_radius = match data.collider_type() {
+        ColliderType::Box if data.is_2d() => data.size_2d().map(|s| s.x().hypot(s.y()) * 0.5)?,
+        ColliderType::Box => data
+            .size()
+            .map(|s| (s.x() * s.x() + s.y() * s.y() + s.z() * s.z()).sqrt() * 0.5)?,
+        ColliderType::Sphere => r,
+        ColliderType::Capsule => r + half_height,
+        ColliderType::Cylinder | ColliderType::Cone => r.hypot(half_height),
+        _ => return None,
+    };
+    let offset = data.center().map_or(0.0, |c| {
+        (c.x() * c.x() + c.y() * c.y() + c.z() * c.z()).sqrt()
+    });
+    Some(shape_radius + offset)
+}
+
+/// Whether `a` placed by `ta` and `b` placed by `tb` overlap, for simple
+/// gameplay checks such as whether the player is inside a zone.
+///
+/// Sphere-Sphere is exact, and Sphere-Box tests the sphere against the box
+/// with its rotation and scale. Box-Box and every other pair compare world
+/// AABBs from `collider_world_aabb`, so they can report an overlap for shapes
+/// that only come close. A sphere under non-uniform scale is treated as a
+/// sphere of its largest scaled radius; a sphere never overlaps a Box whose
+/// size is NaN or infinite. When either collider has no bounds
+/// (Mesh, a Box without a size, an unknown collider type) this returns `true`,
+/// since an overlap cannot be ruled out.
+pub fn primitives_overlap(
+    a: &ColliderComponentData,
+    ta: &TransformComponentData,
+    b: &ColliderComponentData,
+    tb: &TransformComponentData,
+) -> bool {
+    let exact = match (world_sphere(a, ta), world_sphere(b, tb)) {
+        (Some((ca, ra)), Some((cb, rb))) => Some(distance_sq(ca, cb) <= (ra + rb) * (ra + rb)),
+        (Some(sphere), None) => sphere_box_overlap(sphere, b, tb),
+        (None, Some(sphere)) => sphere_box_overlap(sphere, a, ta),
+        (None, None) => None,
+    };
+    exact.unwrap_or_else(
+        || match (collider_world_aabb(a, ta), collider_world_aabb(b, tb)) {
+            (Some(a), Some(b)) => aabbs_overlap(a, b),
+            _ => true,
+        },
+    )
+}
+
+/// Whether two `(min, max)` boxes overlap; touching counts.
+fn aabbs_overlap((a_min, a_max): (Vec3, Vec3), (b_min, b_max): (Vec3, Vec3)) -> bool {
+    a_min.x() <= b_max.x()
+        && b_min.x() <= a_max.x()
+        && a_min.y() <= b_max.y()
+        && b_min.y() <= a_max.y()
+        && a_min.z() <= b_max.z()
+        && b_min.z() <= a_max.z()
+}
+
+/// World-space center and radius of a Sphere collider; `None` for other types.
+fn world_sphere(
+    data: &ColliderComponentData,
+    transform: &TransformComponentData,
+) -> Option<([f32; 3], f32)> {
+    if data.collider_type() != ColliderType::Sphere {
+        return None;
+    }
+    let scale = transform_scale(transform);
+    let max_scale = scale.x().abs().max(scale.y().abs()).max(scale.z().abs());
+    Some((world_center(data, transform), data.radius() * max_scale))
+}
+
+/// Whether the world-space sphere overlaps `data`, tested in the box's own
+/// frame. `None` unless `data` is a Box with a size.
+fn sphere_box_overlap(
+    (center, radius): ([f32; 3], f32),
+    data: &ColliderComponentData,
+    transform: &TransformComponentData,
+) -> Option<bool> {
+    if data.collider_type() != ColliderType::Box {
+        return None;
+    }
+    let (hx, hy, hz) = local_half_extents(data)?;
+    let position = transform_position(transform);
+    let q = transform_rotation(transform).normalized();
+    let inverse = Quat::new(-q.x(), -q.y(), -q.z(), q.w());
+    let scale = transform_scale(transform);
+    let offset = data.center().copied().unwrap_or_default();
+
+    // Undo the translation and rotation only; the box is scaled in place.
+    let local = rotate(
+        &inverse,
+        [
+            center[0] - position.x(),
+            center[1] - position.y(),
+            center[2] - position.z(),
+        ],
+    );
+    let box_center = [
+        offset.x() * scale.x(),
+        offset.y() * scale.y(),
+        offset.z() * scale.z(),
+    ];
+    // `size` comes from the buffer and may be negative or NaN, which would
+    // make `f32::clamp` panic; `max`/`min` never do.
+    let half = [
+        (hx * scale.x()).abs(),
+        (hy * scale.y()).abs(),
+        (hz * scale.z()).abs(),
+    ];
+    if !half.iter().all(|h| h.is_finite()) {
+        return Some(false);
+    }
+    let closest: [f32; 3] = std::array::from_fn(|i| {
+        local[i]
+            .max(box_center[i] - half[i])
+            .min(box_center[i] + half[i])
+    });
+    Some(distance_sq(local, closest) <= radius * radius)
+}
+
+/// The collider's `center` offset in world space.
+fn world_center(data: &ColliderComponentData, transform: &TransformComponentData) -> [f32; 3] {
+    let offset = data.center().copied().unwrap_or_default();
+    let scale = transform_scale(transform);
+    let position = transform_position(transform);
+    let rotated = rotate(
+        &transform_rotation(transform).normalized(),
+        [
+            offset.x() * scale.x(),
+            offset.y() * scale.y(),
+            offset.z() * scale.z(),
+        ],
+    );
+    [
+        rotated[0] + position.x(),
+        rotated[1] + position.y(),
+        rotated[2] + position.z(),
+    ]
+}
+
+fn distance_sq(a: [f32; 3], b: [f32; 3]) -> f32 {
+    (0..3).map(|i| (a[i] - b[i]) * (a[i] - b[i])).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+
+    use flatbuffers::FlatBufferBuilder;
+
+    fn transform_at(x: f32, y: f32, z: f32) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let position = Vec3::new(x, y, z);
+        let offset = TransformComponentData::create(
+            &mut fbb,
+            &TransformComponentDataArgs {
+                local_position: Some(&position),
+                ..Default::default()
+            },
+        );
+        fbb.finish(offset, None);
+        fbb.finished_data().to_vec()
+    }
+
+    fn sphere(radius: f32) -> Vec<u8> {
+        make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius,
+            ..Default::default()
+        })
+    }
+
+    fn cube(size: f32) -> Vec<u8> {
+        make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Box,
+            size: Some(Vec3::new(size, size, size)),
+            ..Default::default()
+        })
+    }
+
+    fn overlap(a: &[u8], ta: &[u8], b: &[u8], tb: &[u8]) -> bool {
+        primitives_overlap(
+            &root_as_collider_component_data(a).unwrap(),
+            &flatbuffers::root::<TransformComponentData>(ta).unwrap(),
+            &root_as_collider_component_data(b).unwrap(),
+            &flatbuffers::root::<TransformComponentData>(tb).unwrap(),
+        )
+    }
+
+    #[test]
+    fn overlapping_spheres_overlap() {
+        let s = sphere(1.0);
+        assert!(overlap(
+            &s,
+            &transform_at(0.0, 0.0, 0.0),
+            &s,
+            &transform_at(1.5, 0.0, 0.0)
+        ));
+        assert!(!overlap(
+            &s,
+            &transform_at(0.0, 0.0, 0.0),
+            &s,
+            &transform_at(2.5, 0.0, 0.0)
+        ));
+    }
+
+    #[test]
+    fn separated_boxes_do_not_overlap() {
+        let b = cube(2.0);
+        assert!(!overlap(
+            &b,
+            &transform_at(0.0, 0.0, 0.0),
+            &b,
+            &transform_at(0.0, 2.5, 0.0)
+        ));
+        assert!(overlap(
+            &b,
+            &transform_at(0.0, 0.0, 0.0),
+            &b,
+            &transform_at(0.0, 1.5, 0.0)
+        ));
+    }
+
+    #[test]
+    fn sphere_off_a_box_corner_does_not_overlap() {
+        // The bounding boxes overlap, but the sphere misses the corner.
+        let origin = transform_at(0.0, 0.0, 0.0);
+        assert!(!overlap(
+            &sphere(1.0),
+            &transform_at(1.8, 1.8, 1.8),
+            &cube(2.0),
+            &origin
+        ));
+        assert!(overlap(
+            &sphere(1.0),
+            &transform_at(1.8, 0.0, 0.0),
+            &cube(2.0),
+            &origin
+        ));
+    }
+
+    #[test]
+    fn mesh_is_conservative() {
+        let mesh = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("rock.obj".into()),
+            ..Default::default()
+        });
+        let far = transform_at(100.0, 0.0, 0.0);
+        assert!(overlap(
+            &mesh,
+            &far,
+            &sphere(1.0),
+            &transform_at(0.0, 0.0, 0.0)
+        ));
+    }
+
+    #[test]
+    fn bad_box_sizes_do_not_panic() {
+        let origin = transform_at(0.0, 0.0, 0.0);
+        assert!(overlap(
+            &sphere(1.0),
+            &transform_at(1.5, 0.0, 0.0),
+            &cube(-2.0),
+            &origin
+        ));
+        assert!(!overlap(
+            &sphere(1.0),
+            &transform_at(1.5, 0.0, 0.0),
+            &cube(f32::NAN),
+            &origin
+        ));
+    }
+
+    fn local_aabb(collider: &ColliderComponent) -> Option<(Vec3, Vec3)> {
+        let buf = make_collider_buffer(collider);
+        collider_local_aabb(&root_as_collider_component_data(&buf).unwrap())
+    }
+
+    #[test]
+    fn local_aabb_of_an_offset_box() {
+        let cube = ColliderComponent {
+            size: Some(Vec3::new(2.0, 4.0, 6.0)),
+            center: Some(Vec3::new(1.0, 0.0, 0.0)),
+            ..Default::default()
+        };
+        assert_eq!(
+            local_aabb(&cube),
+            Some((Vec3::new(0.0, -2.0, -3.0), Vec3::new(2.0, 2.0, 3.0)))
+        );
+    }
+
+    #[test]
+    fn local_aabb_of_a_capsule_includes_its_caps() {
+        let capsule = ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5,
+            height: 2.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            local_aabb(&capsule),
+            Some((Vec3::new(-0.5, -1.5, -0.5), Vec3::new(0.5, 1.5, 0.5)))
+        );
+    }
+
+    #[test]
+    fn mesh_has_no_local_aabb() {
+        let mesh = ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("rock.obj".into()),
+            ..Default::default()
+        };
+        assert_eq!(local_aabb(&mesh), None);
+    }
+
+    fn computed_mass(collider: &ColliderComponent) -> Option<f32> {
+        let buf = make_collider_buffer(collider);
+        collider_computed_mass(&root_as_collider_component_data(&buf).unwrap())
+    }
+
+    #[test]
+    fn mass_is_volume_times_density_unless_overridden() {
+        let cube = ColliderComponent {
+            size: Some(Vec3::new(1.0, 2.0, 1.0)),
+            density: 3.0,
+            ..Default::default()
+        };
+        assert_eq!(computed_mass(&cube), Some(6.0));
+        let overridden = ColliderComponent {
+            mass_override: 5.0,
+            ..cube
+        };
+        assert_eq!(computed_mass(&overridden), Some(5.0));
+    }
+
+    #[test]
+    fn mesh_mass_is_unknown_without_an_override() {
+        let mesh = ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("rock.obj".into()),
+            ..Default::default()
+        };
+        assert_eq!(computed_mass(&mesh), None);
+        let overridden = ColliderComponent {
+            mass_override: 2.0,
+            ..mesh
+        };
+        assert_eq!(computed_mass(&overridden), Some(2.0));
+    }
+
+    fn assert_vec3_near(actual: Vec3, expected: Vec3) {
+        let close = (actual.x() - expected.x()).abs() < 1e-5
+            && (actual.y() - expected.y()).abs() < 1e-5
+            && (actual.z() - expected.z()).abs() < 1e-5;
+        assert!(close, "{actual:?} != {expected:?}");
+    }
+
+    #[test]
+    fn world_aabb_of_a_rotated_unit_cube() {
+        let mut fbb = FlatBufferBuilder::new();
+        let half_angle = std::f32::consts::FRAC_PI_8;
+        let rotation = Quat::new(0.0, half_angle.sin(), 0.0, half_angle.cos());
+        let position = Vec3::new(10.0, 0.0, 0.0);
+        let offset = TransformComponentData::create(
+            &mut fbb,
+            &TransformComponentDataArgs {
+                local_position: Some(&position),
+                local_rotation: Some(&rotation),
+                ..Default::default()
+            },
+        );
+        fbb.finish(offset, None);
+        let transform = flatbuffers::root::<TransformComponentData>(fbb.finished_data()).unwrap();
+        let buf = cube(1.0);
+        let (min, max) =
+            collider_world_aabb(&root_as_collider_component_data(&buf).unwrap(), &transform)
+                .unwrap();
+        // A quarter-turn about Y puts the cube's corners on the X and Z axes.
+        let half_diagonal = std::f32::consts::FRAC_1_SQRT_2;
+        assert_vec3_near(min, Vec3::new(10.0 - half_diagonal, -0.5, -half_diagonal));
+        assert_vec3_near(max, Vec3::new(10.0 + half_diagonal, 0.5, half_diagonal));
+    }
+
+    #[test]
+    fn world_aabb_applies_scale_before_translation() {
+        let mut fbb = FlatBufferBuilder::new();
+        let position = Vec3::new(0.0, 5.0, 0.0);
+        let scale = Vec3::new(2.0, 1.0, 3.0);
+        let offset = TransformComponentData::create(
+            &mut fbb,
+            &TransformComponentDataArgs {
+                local_position: Some(&position),
+                local_scale: Some(&scale),
+                ..Default::default()
+            },
+        );
+        fbb.finish(offset, None);
+        let transform = flatbuffers::root::<TransformComponentData>(fbb.finished_data()).unwrap();
+        let buf = cube(1.0);
+        let (min, max) =
+            collider_world_aabb(&root_as_collider_component_data(&buf).unwrap(), &transform)
+                .unwrap();
+        assert_vec3_near(min, Vec3::new(-1.0, 4.5, -1.5));
+        assert_vec3_near(max, Vec3::new(1.0, 5.5, 1.5));
+    }
+
+    fn volume_and_area(collider: &ColliderComponent) -> (f32, f32) {
+        let buf = make_collider_buffer(collider);
+        let data = root_as_collider_component_data(&buf).unwrap();
+        (
+            collider_volume(&data).unwrap(),
+            collider_surface_area(&data).unwrap(),
+        )
+    }
+
+    fn assert_near(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn unit_sphere_volume_and_area() {
+        let (volume, area) = volume_and_area(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.0,
+            ..Default::default()
+        });
+        assert_near(volume, 4.0 / 3.0 * PI);
+        assert_near(area, 4.0 * PI);
+    }
+
+    #[test]
+    fn capsule_volume_and_area_include_both_caps() {
+        let (volume, area) = volume_and_area(&ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 1.0,
+            height: 2.0,
+            ..Default::default()
+        });
+        assert_near(volume, 2.0 * PI + 4.0 / 3.0 * PI);
+        assert_near(area, 4.0 * PI + 4.0 * PI);
+    }
+
+    #[test]
+    fn box_volume_and_area() {
+        let (volume, area) = volume_and_area(&ColliderComponent {
+            size: Some(Vec3::new(1.0, 2.0, 3.0)),
+            ..Default::default()
+        });
+        assert_near(volume, 6.0);
+        assert_near(area, 22.0);
+    }
+
+    #[test]
+    fn local_aabb_of_a_capsule_follows_its_axis() {
+        let capsule = ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5,
+            height: 2.0,
+            capsule_axis: CapsuleAxis::Z,
+            ..Default::default()
+        };
+        assert_eq!(
+            local_aabb(&capsule),
+            Some((Vec3::new(-0.5, -0.5, -1.5), Vec3::new(0.5, 0.5, 1.5)))
+        );
+    }
+
+    fn bounding_radius(collider: &ColliderComponent) -> Option<f32> {
+        let buf = make_collider_buffer(collider);
+        collider_bounding_radius(&root_as_collider_component_data(&buf).unwrap())
+    }
+
+    #[test]
+    fn bounding_radius_includes_the_center_offset() {
+        let slab = ColliderComponent {
+            size: Some(Vec3::new(2.0, 2.0, 1.0)),
+            ..Default::default()
+        };
+        assert_near(bounding_radius(&slab).unwrap(), 1.5);
+        let offset = ColliderComponent {
+            center: Some(Vec3::new(0.0, 3.0, 4.0)),
+            ..slab
+        };
+        assert_near(bounding_radius(&offset).unwrap(), 6.5);
+    }
+
+    #[test]
+    fn bounding_radius_of_a_capsule_and_a_mesh() {
+        let capsule = ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5,
+            height: 2.0,
+            ..Default::default()
+        };
+        assert_eq!(bounding_radius(&capsule), Some(1.5));
+        let mesh = ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("rock.obj".into()),
+            ..Default::default()
+        };
+        assert_eq!(bounding_radius(&mesh), None);
+    }
+}
@@ -0,0 +1,712 @@
+// Dependency-free JSON reading and writing for colliders, for builds without serde.
+
+use std::fmt::{self, Write as _};
+
+use crate::collider_component::*;
+use crate::collider_component_generated::*;
+use crate::common_types_generated::*;
+
+/// Error from `collider_from_json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonError {
+    /// The input is not well-formed JSON; `offset` is the byte where parsing stopped.
+    Syntax { offset: usize },
+    /// The top-level value is not an object.
+    NotAnObject,
+    /// A known field holds a value of the wrong type or out of range.
+    InvalidField { field: String },
+    /// Objects and arrays are nested more than `MAX_JSON_DEPTH` deep;
+    /// `offset` is the byte of the bracket that went over.
+    TooDeep { offset: usize },
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax { offset } => write!(f, "malformed JSON at byte {offset}"),
+            Self::NotAnObject => f.write_str("collider JSON must be an object"),
+            Self::InvalidField { field } => write!(f, "invalid value for `{field}`"),
+            Self::TooDeep { offset } => write!(
+                f,
+                "JSON nested more than {MAX_JSON_DEPTH} levels deep at byte {offset}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+/// Deepest nesting of objects and arrays `collider_from_json` accepts,
+/// counting the top-level object. A collider needs 2; the limit keeps crafted
+/// input from overflowing the stack of the recursive parser.
+pub const MAX_JSON_DEPTH: usize = 64;
+
+/// Writes `data` as a compact JSON object keyed by schema field names.
+///
+/// Fields equal to their schema default are left out, so a sphere comes out as
+/// `{"collider_type":"Sphere","radius":0.5}`. `collider_type` is written by
+/// name, or as its integer value if this build does not know it. Non-finite
+/// floats have no JSON form and are written as `null`, which reads back as the
+/// field's default.
+pub fn collider_to_json(data: &ColliderComponentData) -> String {
+    let mut out = JsonObjectWriter::default();
+    let collider_type = data.collider_type();
+    if collider_type != ColliderType::Box {
+        match collider_type.variant_name() {
+            Some(name) => out.string("collider_type", name),
+            None => out.raw("collider_type", format_args!("{}", collider_type.0)),
+        }
+    }
+    if data.is_trigger() {
+        out.raw("is_trigger", format_args!("true"));
+    }
+    if let Some(size) = data.size() {
+        out.vec3("size", size);
+    }
+    if data.radius() != 0.0 {
+        out.float("radius", data.radius());
+    }
+    if data.height() != 0.0 {
+        out.float("height", data.height());
+    }
+    if let Some(name) = data.material_name() {
+        out.string("material_name", name);
+    }
+    if let Some(path) = data.mesh_path() {
+        out.string("mesh_path", path);
+    }
+    if data.layer() != 0 {
+        out.raw("layer", format_args!("{}", data.layer()));
+    }
+    if data.collision_mask() != u32::MAX {
+        out.raw("collision_mask", format_args!("{}", data.collision_mask()));
+    }
+    if let Some(center) = data.center() {
+        out.vec3("center", center);
+    }
+    if data.is_2d() {
+        out.raw("is_2d", format_args!("true"));
+    }
+    if let Some(size) = data.size_2d() {
+        out.vec2("size_2d", size);
+    }
+    if data.density() != 1.0 {
+        out.float("density", data.density());
+    }
+    if data.mass_override() != 0.0 {
+        out.float("mass_override", data.mass_override());
+    }
+    if let Some(c) = data.debug_color() {
+        out.raw(
+            "debug_color",
+            format_args!(
+                "{{\"r\":{},\"g\":{},\"b\":{},\"a\":{}}}",
+                c.r(),
+                c.g(),
+                c.b(),
+                c.a()
+            ),
+        );
+    }
+    let axis = data.capsule_axis();
+    if axis != CapsuleAxis::Y {
+        match axis.variant_name() {
+            Some(name) => out.string("capsule_axis", name),
+            None => out.raw("capsule_axis", format_args!("{}", axis.0)),
+        }
+    }
+    if data.mesh_is_convex() {
+        out.raw("mesh_is_convex", format_args!("true"));
+    }
+    if let Some(channel) = data.trigger_channel() {
+        out.string("trigger_channel", channel);
+    }
+    if data.contact_margin() != 0.01 {
+        out.float("contact_margin", data.contact_margin());
+    }
+    if data.trigger_dwell_seconds() != 0.0 {
+        out.float("trigger_dwell_seconds", data.trigger_dwell_seconds());
+    }
+    if data.trigger_with_triggers() {
+        out.raw("trigger_with_triggers", format_args!("true"));
+    }
+    if data.is_static() {
+        out.raw("is_static", format_args!("true"));
+    }
+    let units = data.units();
+    if units != ColliderUnits::Meters {
+        match units.variant_name() {
+            Some(name) => out.string("units", name),
+            None => out.raw("units", format_args!("{}", units.0)),
+        }
+    }
+    if let Some(surface) = data.surface_type() {
+        out.string("surface_type", surface);
+    }
+    if data.trigger_debounce_seconds() != 0.0 {
+        out.float("trigger_debounce_seconds", data.trigger_debounce_seconds());
+    }
+    out.finish()
+}
+
+/// Parses a JSON object written by `collider_to_json` (or by hand).
+///
+/// Missing fields take their schema defaults and unknown fields are ignored, so
+/// files keep loading as the schema grows. `collider_type` accepts a variant
+/// name in any case or an integer value, as do `capsule_axis` and `units`.
+pub fn collider_from_json(s: &str) -> Result<ColliderComponent, JsonError> {
+    let mut parser = Parser {
+        bytes: s.as_bytes(),
+        pos: 0,
+        depth: 0,
+    };
+    parser.skip_ws();
+    let value = parser.value()?;
+    parser.skip_ws();
+    if parser.pos != parser.bytes.len() {
+        return Err(JsonError::Syntax { offset: parser.pos });
+    }
+    let JsonValue::Object(fields) = value else {
+        return Err(JsonError::NotAnObject);
+    };
+
+    let mut collider = ColliderComponent::default();
+    for (key, value) in &fields {
+        let invalid = || JsonError::InvalidField { field: key.clone() };
+        if matches!(value, JsonValue::Null) {
+            continue;
+        }
+        match key.as_str() {
+            "collider_type" => {
+                collider.collider_type = match value {
+                    JsonValue::String(name) => ColliderType::from_name(name).ok_or_else(invalid)?,
+                    JsonValue::Number(n) => ColliderType(integer(*n).ok_or_else(invalid)?),
+                    _ => return Err(invalid()),
+                }
+            }
+            "is_trigger" => collider.is_trigger = value.as_bool().ok_or_else(invalid)?,
+            "size" => collider.size = Some(value.as_vec3().ok_or_else(invalid)?),
+            "radius" => collider.radius = value.as_f32().ok_or_else(invalid)?,
+            "height" => collider.height = value.as_f32().ok_or_else(invalid)?,
+            "material_name" => {
+                collider.material_name = Some(value.as_str().ok_or_else(invalid)?.to_owned())
+            }
+            "mesh_path" => {
+                collider.mesh_path = Some(value.as_str().ok_or_else(invalid)?.to_owned())
+            }
+            "layer" => collider.layer = value.as_integer().ok_or_else(invalid)?,
+            "collision_mask" => collider.collision_mask = value.as_integer().ok_or_else(invalid)?,
+            "center" => collider.center = Some(value.as_vec3().ok_or_else(invalid)?),
+            "is_2d" => collider.is_2d = value.as_bool().ok_or_else(invalid)?,
+            "size_2d" => collider.size_2d = Some(value.as_vec2().ok_or_else(invalid)?),
+            "density" => collider.density = value.as_f32().ok_or_else(invalid)?,
+            "mass_override" => collider.mass_override = value.as_f32().ok_or_else(invalid)?,
+            "capsule_axis" => {
+                collider.capsule_axis = match value {
+                    JsonValue::String(name) => CapsuleAxis::ENUM_VALUES
+                        .iter()
+                        .copied()
+                        .find(|a| {
+                            a.variant_name()
+                                .is_some_and(|v| v.eq_ignore_ascii_case(name))
+                        })
+                        .ok_or_else(invalid)?,
+                    JsonValue::Number(n) => CapsuleAxis(integer(*n).ok_or_else(invalid)?),
+                    _ => return Err(invalid()),
+                }
+            }
+            "mesh_is_convex" => collider.mesh_is_convex = value.as_bool().ok_or_else(invalid)?,
+            "debug_color" => collider.debug_color = Some(value.as_color().ok_or_else(invalid)?),
+            "contact_margin" => collider.contact_margin = value.as_f32().ok_or_else(invalid)?,
+            "trigger_dwell_seconds" => {
+                collider.trigger_dwell_seconds = value.as_f32().ok_or_else(invalid)?
+            }
+            "trigger_with_triggers" => {
+                collider.trigger_with_triggers = value.as_bool().ok_or_else(invalid)?
+            }
+            "is_static" => collider.is_static = value.as_bool().ok_or_else(invalid)?,
+            "units" => {
+                collider.units = match value {
+                    JsonValue::String(name) => ColliderUnits::ENUM_VALUES
+                        .iter()
+                        .copied()
+                        .find(|u| {
+                            u.variant_name()
+                                .is_some_and(|v| v.eq_ignore_ascii_case(name))
+                        })
+                        .ok_or_else(invalid)?,
+                    JsonValue::Number(n) => ColliderUnits(integer(*n).ok_or_else(invalid)?),
+                    _ => return Err(invalid()),
+                }
+            }
+            "trigger_channel" => {
+                collider.trigger_channel = Some(value.as_str().ok_or_else(invalid)?.to_owned())
+            }
+            "trigger_debounce_seconds" => {
+                collider.trigger_debounce_seconds = value.as_f32().ok_or_else(invalid)?
+            }
+            "surface_type" => {
+                collider.surface_type = Some(value.as_str().ok_or_else(invalid)?.to_owned())
+            }
+            _ => {}
+        }
+    }
+    Ok(collider)
+}
+
+#[derive(Default)]
+struct JsonObjectWriter {
+    buf: String,
+}
+
+impl JsonObjectWriter {
+    fn key(&mut self, key: &str) {
+        self.buf.push(if self.buf.is_empty() { '{' } else { ',' });
+        push_string(&mut self.buf, key);
+        self.buf.push(':');
+    }
+
+    fn raw(&mut self, key: &str, value: fmt::Arguments<'_>) {
+        self.key(key);
+        let _ = self.buf.write_fmt(value);
+    }
+
+    fn string(&mut self, key: &str, value: &str) {
+        self.key(key);
+        push_string(&mut self.buf, value);
+    }
+
+    fn float(&mut self, key: &str, value: f32) {
+        self.key(key);
+        push_float(&mut self.buf, value);
+    }
+
+    fn vec3(&mut self, key: &str, v: &Vec3) {
+        self.key(key);
+        self.buf.push_str("{\"x\":");
+        push_float(&mut self.buf, v.x());
+        self.buf.push_str(",\"y\":");
+        push_float(&mut self.buf, v.y());
+        self.buf.push_str(",\"z\":");
+        push_float(&mut self.buf, v.z());
+        self.buf.push('}');
+    }
+
+    fn vec2(&mut self, key: &str, v: &Vec2) {
+        self.key(key);
+        self.buf.push_str("{\"x\":");
+        push_float(&mut self.buf, v.x());
+        self.buf.push_str(",\"y\":");
+        push_float(&mut self.buf, v.y());
+        self.buf.push('}');
+    }
+
+    fn finish(mut self) -> String {
+        if self.buf.is_empty() {
+            self.buf.push('{');
+        }
+        self.buf.push('}');
+        self.buf
+    }
+}
+
+fn push_float(buf: &mut String, value: f32) {
+    if value.is_finite() {
+        // `Display` for floats is the shortest round-tripping form and never
+        // uses exponent notation, so it is always a valid JSON number.
+        let _ = write!(buf, "{value}");
+    } else {
+        buf.push_str("null");
+    }
+}
+
+fn push_string(buf: &mut String, s: &str) {
+    buf.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(buf, "\\u{:04x}", c as u32);
+            }
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+}
+
+/// Returns `n` as an integer of type `T` if it is integral and in range.
+fn integer<T: TryFrom<i64>>(n: f64) -> Option<T> {
+    if n.fract() != 0.0 || n.abs() > i64::MAX as f64 {
+        return None;
+    }
+    T::try_from(n as i64).ok()
+}
+
+enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    /// Arrays are checked for syntax but their items are dropped; no collider
+    /// field holds one.
+    Array,
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    fn as_bool(&self) -> Option<bool> {
+        match *self {
+            JsonValue::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    fn as_f32(&self) -> Option<f32> {
+        match *self {
+            JsonValue::Number(n) => Some(n as f32),
+            _ => None,
+        }
+    }
+
+    fn as_integer<T: TryFrom<i64>>(&self) -> Option<T> {
+        match *self {
+            JsonValue::Number(n) => integer(n),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Reads a number member of an object, treating a missing or `null` member as 0.
+    fn component(&self, name: &str) -> Option<f32> {
+        let JsonValue::Object(fields) = self else {
+            return None;
+        };
+        match fields.iter().rev().find(|(key, _)| key == name) {
+            None | Some((_, JsonValue::Null)) => Some(0.0),
+            Some((_, value)) => value.as_f32(),
+        }
+    }
+
+    fn as_vec3(&self) -> Option<Vec3> {
+        Some(Vec3::new(
+            self.component("x")?,
+            self.component("y")?,
+            self.component("z")?,
+        ))
+    }
+
+    fn as_vec2(&self) -> Option<Vec2> {
+        Some(Vec2::new(self.component("x")?, self.component("y")?))
+    }
+
+    /// Reads an `{r, g, b, a}` object of 0-255 integers; missing channels are 0.
+    fn as_color(&self) -> Option<Color> {
+        let channel = |name| integer(f64::from(self.component(name)?));
+        Some(Color::new(
+            channel("r")?,
+            channel("g")?,
+            channel("b")?,
+            channel("a")?,
+        ))
+    }
+}
+
+/// Minimal recursive-descent JSON parser over the input bytes.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    /// Objects and arrays currently open.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self) -> JsonError {
+        JsonError::Syntax { offset: self.pos }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), JsonError> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: JsonValue) -> Result<JsonValue, JsonError> {
+        if self.bytes[self.pos..].starts_with(word.as_bytes()) {
+            self.pos += word.len();
+            Ok(value)
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn value(&mut self) -> Result<JsonValue, JsonError> {
+        match self.peek() {
+            Some(b'{') => self.nested(Self::object),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'"') => self.string().map(JsonValue::String),
+            Some(b't') => self.literal("true", JsonValue::Bool(true)),
+            Some(b'f') => self.literal("false", JsonValue::Bool(false)),
+            Some(b'n') => self.literal("null", JsonValue::Null),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => Err(self.error()),
+        }
+    }
+
+    /// Parses an object or array with `parse`, one level deeper.
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<JsonValue, JsonError>,
+    ) -> Result<JsonValue, JsonError> {
+        if self.depth == MAX_JSON_DEPTH {
+            return Err(JsonError::TooDeep { offset: self.pos });
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn object(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect(b'{')?;
+        let mut fields = Vec::new();
+        self.skip_ws();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(JsonValue::Object(fields));
+        }
+        loop {
+            self.skip_ws();
+            let key = self.string()?;
+            self.skip_ws();
+            self.expect(b':')?;
+            self.skip_ws();
+            fields.push((key, self.value()?));
+            self.skip_ws();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Object(fields));
+                }
+                _ => return Err(self.error()),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<JsonValue, JsonError> {
+        self.expect(b'[')?;
+        self.skip_ws();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(JsonValue::Array);
+        }
+        loop {
+            self.skip_ws();
+            self.value()?;
+            self.skip_ws();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(JsonValue::Array);
+                }
+                _ => return Err(self.error()),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<JsonValue, JsonError> {
+        let start = self.pos;
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .map(JsonValue::Number)
+            .ok_or(JsonError::Syntax { offset: start })
+    }
+
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.expect(b'"')?;
+        let mut out = Vec::new();
+        loop {
+            let byte = self.peek().ok_or_else(|| self.error())?;
+            self.pos += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escape = self.peek().ok_or_else(|| self.error())?;
+                    self.pos += 1;
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err(self.error()),
+                    };
+                    out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                byte if byte < 0x20 => return Err(self.error()),
+                byte => out.push(byte),
+            }
+        }
+        // The input is a `&str` and escapes are pushed as whole characters, so
+        // the collected bytes are always valid UTF-8.
+        String::from_utf8(out).map_err(|_| self.error())
+    }
+
+    /// Decodes the `XXXX` after `\u`, combining a surrogate pair if present.
+    fn unicode_escape(&mut self) -> Result<char, JsonError> {
+        let high = self.hex4()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high).ok_or_else(|| self.error());
+        }
+        if !self.bytes[self.pos..].starts_with(b"\\u") {
+            return Err(self.error());
+        }
+        self.pos += 2;
+        let low = self.hex4()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(self.error());
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+            .ok_or_else(|| self.error())
+    }
+
+    fn hex4(&mut self) -> Result<u32, JsonError> {
+        let digits = self
+            .bytes
+            .get(self.pos..self.pos + 4)
+            .filter(|d| d.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|d| std::str::from_utf8(d).ok())
+            .and_then(|d| u32::from_str_radix(d, 16).ok())
+            .ok_or_else(|| self.error())?;
+        self.pos += 4;
+        Ok(digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+
+    fn to_json(collider: &ColliderComponent) -> String {
+        let buf = make_collider_buffer(collider);
+        collider_to_json(&root_as_collider_component_data(&buf).unwrap())
+    }
+
+    #[test]
+    fn sphere_writes_only_non_default_fields() {
+        let sphere = ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 0.5,
+            ..Default::default()
+        };
+        let json = to_json(&sphere);
+        assert_eq!(json, r#"{"collider_type":"Sphere","radius":0.5}"#);
+        assert_eq!(collider_from_json(&json), Ok(sphere));
+        assert_eq!(to_json(&ColliderComponent::default()), "{}");
+    }
+
+    #[test]
+    fn strings_and_structs_round_trip() {
+        let collider = ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            size: Some(Vec3::new(1.0, 2.5, -3.0)),
+            material_name: Some("a\"b\\c\u{1}é😀".into()),
+            mesh_path: Some("rock.obj".into()),
+            debug_color: Some(Color::new(1, 2, 3, 255)),
+            capsule_axis: CapsuleAxis::X,
+            ..Default::default()
+        };
+        assert_eq!(collider_from_json(&to_json(&collider)), Ok(collider));
+    }
+
+    #[test]
+    fn parser_tolerates_missing_and_unknown_fields() {
+        let parsed = collider_from_json(
+            r#" { "collider_type" : "mesh", "unknown": [1, {"a": null}], "size": {"x": 1e1} } "#,
+        )
+        .unwrap();
+        assert_eq!(parsed.collider_type, ColliderType::Mesh);
+        assert_eq!(parsed.size, Some(Vec3::new(10.0, 0.0, 0.0)));
+        assert_eq!(parsed.radius, ColliderComponent::default().radius);
+    }
+
+    #[test]
+    fn malformed_input_is_rejected() {
+        assert!(matches!(
+            collider_from_json(r#"{"radius": "x"}"#),
+            Err(JsonError::InvalidField { .. })
+        ));
+        assert!(matches!(
+            collider_from_json(r#"{"radius": 1,}"#),
+            Err(JsonError::Syntax { .. })
+        ));
+        assert_eq!(collider_from_json("[]"), Err(JsonError::NotAnObject));
+    }
+
+    #[test]
+    fn deep_nesting_is_rejected_without_overflowing() {
+        let nested = |depth: usize| {
+            format!(
+                "{{\"unknown\":{}{}}}",
+                "[".repeat(depth - 1),
+                "]".repeat(depth - 1)
+            )
+        };
+        assert!(collider_from_json(&nested(MAX_JSON_DEPTH)).is_ok());
+        assert_eq!(
+            collider_from_json(&nested(MAX_JSON_DEPTH + 1)),
+            Err(JsonError::TooDeep {
+                offset: 11 + MAX_JSON_DEPTH - 1
+            })
+        );
+        let hostile = "[".repeat(300_000);
+        assert!(matches!(
+            collider_from_json(&hostile),
+            Err(JsonError::TooDeep { .. })
+        ));
+    }
+
+    #[test]
+    fn surface_type_round_trips() {
+        let gravel = ColliderComponent {
+            surface_type: Some("gravel".into()),
+            ..Default::default()
+        };
+        let json = to_json(&gravel);
+        assert_eq!(json, r#"{"surface_type":"gravel"}"#);
+        assert_eq!(collider_from_json(&json), Ok(gravel));
+    }
+}
@@ -0,0 +1,100 @@
+// Zero-copy loading of collider arrays from memory-mapped level files.
+// Needs the `mmap` feature; README.md lists the manifest entries.
+#![cfg(feature = "mmap")]
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use crate::collider_array::*;
+
+use flatbuffers::InvalidFlatbuffer;
+
+/// A memory-mapped `ColliderComponentArrayData` file.
+///
+/// The mapping is owned here and colliders are read from it in place, so the
+/// views returned by `as_array` borrow from this value and cannot outlive it.
+/// Keep the `MmapColliders` alive for as long as the level is loaded (e.g. in
+/// the level resource) and call `as_array` once per load or system pass, using
+/// the returned array within that scope:
+///
+/// ```ignore
+/// let level = load_colliders_mmap(path)?;
+/// let colliders = level.as_array()?;
+/// for collider in colliders.iter() { /* ... */ }
+/// ```
+///
+/// Each `as_array` call runs the verifier over the whole mapping, so avoid
+/// calling it per collider.
+pub struct MmapColliders {
+    mmap: Mmap,
+}
+
+impl MmapColliders {
+    /// Verifies the mapped bytes and returns a view over the colliders in them.
+    pub fn as_array(&self) -> Result<ColliderComponentArray<'_>, InvalidFlatbuffer> {
+        ColliderComponentArray::from_buffer(&self.mmap)
+    }
+
+    /// The raw mapped bytes.
+    pub fn bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+}
+
+/// Maps the file at `path` read-only without copying it into memory.
+///
+/// The file must not be modified or truncated while it is mapped; level files
+/// are treated as immutable once written.
+pub fn load_colliders_mmap(path: &Path) -> io::Result<MmapColliders> {
+    let file = File::open(path)?;
+    // Safety:
+    // The mapping is read-only and level files are not written to while the
+    // game has them loaded.
+    let mmap = unsafe { Mmap::map(&file)? };
+    Ok(MmapColliders { mmap })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_component::ColliderComponent;
+    use crate::collider_component_array_generated::*;
+    use flatbuffers::FlatBufferBuilder;
+
+    #[test]
+    fn maps_a_level_file_and_reads_its_colliders() {
+        let mut fbb = FlatBufferBuilder::new();
+        let collider = ColliderComponent {
+            radius: 3.0,
+            ..Default::default()
+        }
+        .serialize(&mut fbb);
+        let colliders = fbb.create_vector(&[collider]);
+        let root = ColliderComponentArrayData::create(
+            &mut fbb,
+            &ColliderComponentArrayDataArgs {
+                colliders: Some(colliders),
+            },
+        );
+        finish_collider_component_array_data_buffer(&mut fbb, root);
+
+        let path = std::env::temp_dir().join(format!("colliders-{}.bin", std::process::id()));
+        std::fs::write(&path, fbb.finished_data()).unwrap();
+        let level = load_colliders_mmap(&path).unwrap();
+        assert_eq!(level.bytes(), fbb.finished_data());
+        let array = level.as_array().unwrap();
+        assert_eq!(array.len(), 1);
+        assert_eq!(array.get(0).unwrap().radius(), 3.0);
+        drop(level);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn missing_file_is_an_io_error() {
+        let path = std::env::temp_dir().join("no-such-level-file.bin");
+        assert!(load_colliders_mmap(&path).is_err());
+    }
+}
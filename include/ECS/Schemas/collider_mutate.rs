@@ -0,0 +1,142 @@
+// In-place edits of scalar fields in a finished collider buffer.
+
+use crate::collider_component_generated::*;
+
+use flatbuffers::{InvalidFlatbuffer, VOffsetT};
+
+/// Error from the `set_*_in_place` functions.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MutateError {
+    /// The buffer failed verification, so no field was located.
+    Invalid(InvalidFlatbuffer),
+    /// The field was not written to the buffer (it held its default), so there
+    /// are no bytes to overwrite; rebuild the buffer instead.
+    FieldAbsent { field: &'static str },
+}
+
+impl core::fmt::Display for MutateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Invalid(err) => write!(f, "invalid collider buffer: {err}"),
+            Self::FieldAbsent { field } => {
+                write!(f, "field `{field}` is not present in the buffer")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MutateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid(err) => Some(err),
+            Self::FieldAbsent { .. } => None,
+        }
+    }
+}
+
+impl From<InvalidFlatbuffer> for MutateError {
+    fn from(err: InvalidFlatbuffer) -> Self {
+        MutateError::Invalid(err)
+    }
+}
+
+/// Overwrites `radius` in a finished `ColliderComponentData` buffer.
+///
+/// Like FlatBuffers' own mutation support this only works for fields that are
+/// physically present: builders skip fields equal to their default, so a
+/// collider serialized with `radius == 0.0` has nowhere to write to and this
+/// returns `FieldAbsent`.
+pub fn set_radius_in_place(buf: &mut [u8], radius: f32) -> Result<(), MutateError> {
+    let pos = field_position(buf, ColliderComponentData::VT_RADIUS, "radius")?;
+    buf[pos..pos + 4].copy_from_slice(&radius.to_le_bytes());
+    Ok(())
+}
+
+/// Overwrites `height`; see `set_radius_in_place`.
+pub fn set_height_in_place(buf: &mut [u8], height: f32) -> Result<(), MutateError> {
+    let pos = field_position(buf, ColliderComponentData::VT_HEIGHT, "height")?;
+    buf[pos..pos + 4].copy_from_slice(&height.to_le_bytes());
+    Ok(())
+}
+
+/// Overwrites `is_trigger`; see `set_radius_in_place`.
+pub fn set_is_trigger_in_place(buf: &mut [u8], is_trigger: bool) -> Result<(), MutateError> {
+    let pos = field_position(buf, ColliderComponentData::VT_IS_TRIGGER, "is_trigger")?;
+    buf[pos] = is_trigger as u8;
+    Ok(())
+}
+
+/// Overwrites `collider_type`; see `set_radius_in_place`.
+pub fn set_collider_type_in_place(
+    buf: &mut [u8],
+    collider_type: ColliderType,
+) -> Result<(), MutateError> {
+    let pos = field_position(
+        buf,
+        ColliderComponentData::VT_COLLIDER_TYPE,
+        "collider_type",
+    )?;
+    buf[pos] = collider_type.0 as u8;
+    Ok(())
+}
+
+/// Verifies `buf` and returns the absolute byte position of the field stored
+/// at vtable slot `vt`. Verification guarantees the position and the field's
+/// inline bytes are in bounds.
+fn field_position(buf: &[u8], vt: VOffsetT, field: &'static str) -> Result<usize, MutateError> {
+    let table = root_as_collider_component_data(buf)?._tab;
+    match table.vtable().get(vt) {
+        0 => Err(MutateError::FieldAbsent { field }),
+        offset => Ok(table.loc() + offset as usize),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+
+    fn read(buf: &[u8]) -> ColliderComponentData<'_> {
+        root_as_collider_component_data(buf).unwrap()
+    }
+
+    #[test]
+    fn present_fields_are_overwritten() {
+        let mut buf = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.0,
+            is_trigger: true,
+            ..Default::default()
+        });
+        set_radius_in_place(&mut buf, 3.5).unwrap();
+        set_collider_type_in_place(&mut buf, ColliderType::Capsule).unwrap();
+        set_is_trigger_in_place(&mut buf, false).unwrap();
+        let collider = read(&buf);
+        assert_eq!(collider.radius(), 3.5);
+        assert_eq!(collider.collider_type(), ColliderType::Capsule);
+        assert!(!collider.is_trigger());
+    }
+
+    #[test]
+    fn absent_field_is_reported() {
+        let mut buf = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.0,
+            ..Default::default()
+        });
+        assert_eq!(
+            set_height_in_place(&mut buf, 1.0),
+            Err(MutateError::FieldAbsent { field: "height" })
+        );
+        assert_eq!(read(&buf).height(), 0.0);
+    }
+
+    #[test]
+    fn invalid_buffer_is_rejected() {
+        assert!(matches!(
+            set_radius_in_place(&mut [0u8; 3], 1.0),
+            Err(MutateError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+// Conversion of collider tables to parry3d shapes for collision queries.
+// Needs the `parry` feature; README.md lists the manifest entries.
+#![cfg(feature = "parry")]
+
+use parry3d::math::Point;
+use parry3d::shape::SharedShape;
+
+use crate::collider_component_generated::*;
+
+/// Builds the parry shape for `data`, in the collider's local frame.
+///
+/// Box becomes a cuboid with half of `size` as half-extents, Sphere a ball,
+/// Capsule a capsule along `capsule_axis` whose segment is `height` long,
+/// and Cylinder and Cone the Y-up parry shapes of the same name. `center` is
+/// not applied; place the shape at it when building the query pipeline.
+///
+/// Returns `None` for Mesh (the mesh has to be loaded and converted
+/// separately), for a Box without `size`, for 2D colliders (use parry2d) and
+/// for unknown types.
+pub fn to_parry_shape(data: &ColliderComponentData) -> Option<SharedShape> {
+    if data.is_2d() {
+        return None;
+    }
+    let radius = data.radius();
+    let half_height = data.height() / 2.0;
+    let shape = match data.collider_type() {
+        ColliderType::Box => {
+            let size = data.size()?;
+            SharedShape::cuboid(size.x() / 2.0, size.y() / 2.0, size.z() / 2.0)
+        }
+        ColliderType::Sphere => SharedShape::ball(radius),
+        ColliderType::Capsule => {
+            let mut end = [0.0; 3];
+            match data.capsule_axis() {
+                CapsuleAxis::X => end[0] = half_height,
+                CapsuleAxis::Z => end[2] = half_height,
+                _ => end[1] = half_height,
+            }
+            let a = Point::new(-end[0], -end[1], -end[2]);
+            let b = Point::new(end[0], end[1], end[2]);
+            SharedShape::capsule(a, b, radius)
+        }
+        ColliderType::Cylinder => SharedShape::cylinder(half_height, radius),
+        ColliderType::Cone => SharedShape::cone(half_height, radius),
+        _ => return None,
+    };
+    Some(shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+    use crate::common_types_generated::{Vec2, Vec3};
+    use parry3d::shape::ShapeType;
+
+    fn shape_of(collider: &ColliderComponent) -> Option<SharedShape> {
+        let buf = make_collider_buffer(collider);
+        to_parry_shape(&root_as_collider_component_data(&buf).unwrap())
+    }
+
+    #[test]
+    fn box_becomes_a_cuboid_of_half_extents() {
+        let cube = shape_of(&ColliderComponent {
+            size: Some(Vec3::new(2.0, 4.0, 6.0)),
+            ..Default::default()
+        })
+        .unwrap();
+        let half_extents = cube.as_cuboid().unwrap().half_extents;
+        assert_eq!(
+            (half_extents.x, half_extents.y, half_extents.z),
+            (1.0, 2.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn round_shapes_map_to_their_parry_types() {
+        let ball = shape_of(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.0,
+            ..Default::default()
+        });
+        assert_eq!(ball.unwrap().shape_type(), ShapeType::Ball);
+        let cylinder = shape_of(&ColliderComponent {
+            collider_type: ColliderType::Cylinder,
+            radius: 1.0,
+            height: 1.0,
+            ..Default::default()
+        });
+        assert_eq!(cylinder.unwrap().shape_type(), ShapeType::Cylinder);
+    }
+
+    #[test]
+    fn capsule_lies_along_its_axis() {
+        let capsule = shape_of(&ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5,
+            height: 2.0,
+            capsule_axis: CapsuleAxis::Z,
+            ..Default::default()
+        })
+        .unwrap();
+        let capsule = capsule.as_capsule().unwrap();
+        assert_eq!(capsule.radius, 0.5);
+        assert_eq!((capsule.segment.b.y, capsule.segment.b.z), (0.0, 1.0));
+        assert_eq!(capsule.segment.a.z, -1.0);
+    }
+
+    #[test]
+    fn meshes_2d_and_sizeless_boxes_have_no_shape() {
+        let mesh = ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("rock.obj".into()),
+            ..Default::default()
+        };
+        assert!(shape_of(&mesh).is_none());
+        assert!(shape_of(&ColliderComponent::default()).is_none());
+        let flat = ColliderComponent {
+            is_2d: true,
+            size_2d: Some(Vec2::new(1.0, 1.0)),
+            ..Default::default()
+        };
+        assert!(shape_of(&flat).is_none());
+    }
+}
@@ -0,0 +1,264 @@
+// Field-level overrides for rewriting a collider, e.g. for editor undo/redo.
+
+use crate::collider_component::*;
+use crate::collider_component_generated::*;
+use crate::collider_fields::*;
+use crate::common_types_generated::*;
+
+use flatbuffers::{Allocator, FlatBufferBuilder, WIPOffset};
+
+/// Fields to change in a collider; `None` keeps the existing value.
+///
+/// Fields that are optional in the schema take `Option<Option<_>>`, so
+/// `Some(None)` clears them. Start from `ColliderPatch::default()` and set only
+/// the fields being changed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColliderPatch {
+    pub collider_type: Option<ColliderType>,
+    pub is_trigger: Option<bool>,
+    pub size: Option<Option<Vec3>>,
+    pub radius: Option<f32>,
+    pub height: Option<f32>,
+    pub material_name: Option<Option<String>>,
+    pub mesh_path: Option<Option<String>>,
+    pub layer: Option<u32>,
+    pub collision_mask: Option<u32>,
+    pub center: Option<Option<Vec3>>,
+    pub is_2d: Option<bool>,
+    pub size_2d: Option<Option<Vec2>>,
+    pub density: Option<f32>,
+    pub mass_override: Option<f32>,
+    pub debug_color: Option<Option<Color>>,
+    pub capsule_axis: Option<CapsuleAxis>,
+    pub mesh_is_convex: Option<bool>,
+    pub trigger_channel: Option<Option<String>>,
+    pub contact_margin: Option<f32>,
+    pub trigger_dwell_seconds: Option<f32>,
+    pub trigger_with_triggers: Option<bool>,
+    pub is_static: Option<bool>,
+    pub units: Option<ColliderUnits>,
+    pub surface_type: Option<Option<String>>,
+    pub trigger_debounce_seconds: Option<f32>,
+}
+
+impl ColliderPatch {
+    /// A patch setting every field physically present in `overlay`, per
+    /// `collider_present_fields`. `schema_version` is not a patchable field.
+    pub fn from_present_fields(overlay: &ColliderComponentData) -> Self {
+        let present = collider_present_fields(overlay);
+        let has = |field| present.contains(field);
+        type M = ColliderFieldMask;
+        ColliderPatch {
+            collider_type: has(M::COLLIDER_TYPE).then(|| overlay.collider_type()),
+            is_trigger: has(M::IS_TRIGGER).then(|| overlay.is_trigger()),
+            size: has(M::SIZE).then(|| overlay.size().copied()),
+            radius: has(M::RADIUS).then(|| overlay.radius()),
+            height: has(M::HEIGHT).then(|| overlay.height()),
+            material_name: has(M::MATERIAL_NAME)
+                .then(|| overlay.material_name().map(str::to_owned)),
+            mesh_path: has(M::MESH_PATH).then(|| overlay.mesh_path().map(str::to_owned)),
+            layer: has(M::LAYER).then(|| overlay.layer()),
+            collision_mask: has(M::COLLISION_MASK).then(|| overlay.collision_mask()),
+            center: has(M::CENTER).then(|| overlay.center().copied()),
+            is_2d: has(M::IS_2D).then(|| overlay.is_2d()),
+            size_2d: has(M::SIZE_2D).then(|| overlay.size_2d().copied()),
+            density: has(M::DENSITY).then(|| overlay.density()),
+            mass_override: has(M::MASS_OVERRIDE).then(|| overlay.mass_override()),
+            debug_color: has(M::DEBUG_COLOR).then(|| overlay.debug_color().copied()),
+            capsule_axis: has(M::CAPSULE_AXIS).then(|| overlay.capsule_axis()),
+            mesh_is_convex: has(M::MESH_IS_CONVEX).then(|| overlay.mesh_is_convex()),
+            trigger_channel: has(M::TRIGGER_CHANNEL)
+                .then(|| overlay.trigger_channel().map(str::to_owned)),
+            contact_margin: has(M::CONTACT_MARGIN).then(|| overlay.contact_margin()),
+            trigger_dwell_seconds: has(M::TRIGGER_DWELL_SECONDS)
+                .then(|| overlay.trigger_dwell_seconds()),
+            trigger_with_triggers: has(M::TRIGGER_WITH_TRIGGERS)
+                .then(|| overlay.trigger_with_triggers()),
+            is_static: has(M::IS_STATIC).then(|| overlay.is_static()),
+            units: has(M::UNITS).then(|| overlay.units()),
+            surface_type: has(M::SURFACE_TYPE).then(|| overlay.surface_type().map(str::to_owned)),
+            trigger_debounce_seconds: has(M::TRIGGER_DEBOUNCE_SECONDS)
+                .then(|| overlay.trigger_debounce_seconds()),
+        }
+    }
+
+    /// Overwrites the fields of `collider` that this patch sets.
+    pub fn apply(self, collider: &mut ColliderComponent) {
+        fn set<T>(field: &mut T, value: Option<T>) {
+            if let Some(value) = value {
+                *field = value;
+            }
+        }
+        set(&mut collider.collider_type, self.collider_type);
+        set(&mut collider.is_trigger, self.is_trigger);
+        set(&mut collider.size, self.size);
+        set(&mut collider.radius, self.radius);
+        set(&mut collider.height, self.height);
+        set(&mut collider.material_name, self.material_name);
+        set(&mut collider.mesh_path, self.mesh_path);
+        set(&mut collider.layer, self.layer);
+        set(&mut collider.collision_mask, self.collision_mask);
+        set(&mut collider.center, self.center);
+        set(&mut collider.is_2d, self.is_2d);
+        set(&mut collider.size_2d, self.size_2d);
+        set(&mut collider.density, self.density);
+        set(&mut collider.mass_override, self.mass_override);
+        set(&mut collider.debug_color, self.debug_color);
+        set(&mut collider.capsule_axis, self.capsule_axis);
+        set(&mut collider.mesh_is_convex, self.mesh_is_convex);
+        set(&mut collider.trigger_channel, self.trigger_channel);
+        set(&mut collider.contact_margin, self.contact_margin);
+        set(
+            &mut collider.trigger_dwell_seconds,
+            self.trigger_dwell_seconds,
+        );
+        set(
+            &mut collider.trigger_with_triggers,
+            self.trigger_with_triggers,
+        );
+        set(&mut collider.is_static, self.is_static);
+        set(&mut collider.units, self.units);
+        set(&mut collider.surface_type, self.surface_type);
+        set(
+            &mut collider.trigger_debounce_seconds,
+            self.trigger_debounce_seconds,
+        );
+    }
+}
+
+/// Writes a copy of `data` with the fields set in `patch` replaced.
+///
+/// The copy is written at `CURRENT_COLLIDER_SCHEMA_VERSION`, like any table
+/// written from a `ColliderComponent`.
+pub fn patch_collider<'bldr, A: Allocator + 'bldr>(
+    data: &ColliderComponentData,
+    patch: ColliderPatch,
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    let mut collider = ColliderComponent::from(*data);
+    patch.apply(&mut collider);
+    collider.serialize(fbb)
+}
+
+/// Writes `base` with every field present in `overlay` taken from `overlay`,
+/// for layering a prefab override on its base collider.
+///
+/// Presence is read from the vtable, so an overlay can only set a field back
+/// to its schema default if it was built with `force_defaults(true)`;
+/// otherwise the builder leaves the field out and the base value wins.
+pub fn merge_colliders<'bldr, A: Allocator + 'bldr>(
+    base: &ColliderComponentData,
+    overlay: &ColliderComponentData,
+    fbb: &mut FlatBufferBuilder<'bldr, A>,
+) -> WIPOffset<ColliderComponentData<'bldr>> {
+    patch_collider(base, ColliderPatch::from_present_fields(overlay), fbb)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::common_types_generated::Vec3;
+    use flatbuffers::FlatBufferBuilder;
+
+    fn wooden_capsule() -> ColliderComponent {
+        ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5,
+            height: 2.0,
+            material_name: Some("wood".into()),
+            center: Some(Vec3::new(0.0, 1.0, 0.0)),
+            layer: 4,
+            ..Default::default()
+        }
+    }
+
+    fn patched(collider: &ColliderComponent, patch: ColliderPatch) -> ColliderComponent {
+        let buf = make_collider_buffer(collider);
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = patch_collider(
+            &root_as_collider_component_data(&buf).unwrap(),
+            patch,
+            &mut fbb,
+        );
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        ColliderComponent::from(root_as_collider_component_data(fbb.finished_data()).unwrap())
+    }
+
+    #[test]
+    fn patch_changes_only_the_given_fields() {
+        let patch = ColliderPatch {
+            is_trigger: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            patched(&wooden_capsule(), patch),
+            ColliderComponent {
+                is_trigger: true,
+                ..wooden_capsule()
+            }
+        );
+        assert_eq!(
+            patched(&wooden_capsule(), ColliderPatch::default()),
+            wooden_capsule()
+        );
+    }
+
+    #[test]
+    fn patch_can_clear_optional_fields() {
+        let patch = ColliderPatch {
+            material_name: Some(None),
+            center: Some(None),
+            ..Default::default()
+        };
+        let cleared = patched(&wooden_capsule(), patch);
+        assert_eq!(cleared.material_name, None);
+        assert_eq!(cleared.center, None);
+        assert_eq!(cleared.layer, 4);
+    }
+
+    fn merged(base: &ColliderComponent, overlay: &[u8]) -> Vec<u8> {
+        let base = make_collider_buffer(base);
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = merge_colliders(
+            &root_as_collider_component_data(&base).unwrap(),
+            &root_as_collider_component_data(overlay).unwrap(),
+            &mut fbb,
+        );
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        fbb.finished_data().to_vec()
+    }
+
+    #[test]
+    fn merge_takes_only_the_fields_present_in_the_overlay() {
+        let mut fbb = FlatBufferBuilder::new();
+        let mut overlay = ColliderComponentDataBuilder::new(&mut fbb);
+        overlay.add_is_trigger(true);
+        let offset = overlay.finish();
+        finish_collider_component_data_buffer(&mut fbb, offset);
+
+        let buf = merged(&wooden_capsule(), fbb.finished_data());
+        assert_eq!(
+            ColliderComponent::from(root_as_collider_component_data(&buf).unwrap()),
+            ColliderComponent {
+                is_trigger: true,
+                ..wooden_capsule()
+            }
+        );
+    }
+
+    #[test]
+    fn merge_can_reset_a_field_to_its_default_with_forced_defaults() {
+        let mut fbb = FlatBufferBuilder::new();
+        fbb.force_defaults(true);
+        let mut overlay = ColliderComponentDataBuilder::new(&mut fbb);
+        overlay.add_layer(0);
+        let offset = overlay.finish();
+        finish_collider_component_data_buffer(&mut fbb, offset);
+
+        let buf = merged(&wooden_capsule(), fbb.finished_data());
+        let data = root_as_collider_component_data(&buf).unwrap();
+        assert_eq!(data.layer(), 0);
+        assert_eq!(data.material_name(), Some("wood"));
+    }
+}
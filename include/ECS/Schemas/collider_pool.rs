@@ -0,0 +1,160 @@
+// Building and reading `ColliderPoolSceneData`, where entities share colliders
+// through a deduplicated pool.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::collider_compare::*;
+use crate::collider_component::*;
+use crate::collider_component_generated::*;
+use crate::collider_pool_scene_generated::*;
+
+use flatbuffers::{Allocator, FlatBufferBuilder, WIPOffset};
+
+/// Collects entity colliders for a `ColliderPoolSceneData`, storing each
+/// distinct collider once.
+///
+/// Colliders are keyed by `collider_content_hash`; on a hash match the
+/// candidates are compared with `collider_cmp`, so a hash collision never
+/// merges two different colliders. Indices are handed out in insertion order
+/// and never change, so they can be written into other tables before the pool
+/// is finished.
+#[derive(Default)]
+pub struct ColliderPoolBuilder<'a> {
+    colliders: Vec<ColliderComponentData<'a>>,
+    by_hash: HashMap<u64, Vec<u32>>,
+    entities: Vec<EntityColliderRef>,
+}
+
+impl<'a> ColliderPoolBuilder<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `collider` to the pool unless an identical one is already there,
+    /// and returns its pool index.
+    pub fn add_collider(&mut self, collider: ColliderComponentData<'a>) -> u32 {
+        let candidates = self
+            .by_hash
+            .entry(collider_content_hash(&collider))
+            .or_default();
+        let existing = candidates.iter().copied().find(|&index| {
+            collider_cmp(&self.colliders[index as usize], &collider) == Ordering::Equal
+        });
+        existing.unwrap_or_else(|| {
+            let index = self.colliders.len() as u32;
+            self.colliders.push(collider);
+            candidates.push(index);
+            index
+        })
+    }
+
+    /// Pools `collider` and records that `entity_id` uses it. Returns the pool
+    /// index.
+    pub fn add_entity(&mut self, entity_id: u64, collider: ColliderComponentData<'a>) -> u32 {
+        let index = self.add_collider(collider);
+        self.entities.push(EntityColliderRef::new(entity_id, index));
+        index
+    }
+
+    /// Number of distinct colliders in the pool.
+    #[inline]
+    pub fn pool_len(&self) -> usize {
+        self.colliders.len()
+    }
+
+    /// Number of entity references added with `add_entity`.
+    #[inline]
+    pub fn entity_count(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Writes the pool and entity references as a `ColliderPoolSceneData`.
+    ///
+    /// Each pooled collider is written from a `ColliderComponent`, so it is
+    /// stamped with `CURRENT_COLLIDER_SCHEMA_VERSION`.
+    pub fn finish<'bldr, A: Allocator + 'bldr>(
+        &self,
+        fbb: &mut FlatBufferBuilder<'bldr, A>,
+    ) -> WIPOffset<ColliderPoolSceneData<'bldr>> {
+        let colliders: Vec<_> = self
+            .colliders
+            .iter()
+            .map(|collider| ColliderComponent::from(*collider).serialize(fbb))
+            .collect();
+        let colliders = fbb.create_vector(&colliders);
+        let entities = fbb.create_vector(&self.entities);
+        ColliderPoolSceneData::create(
+            fbb,
+            &ColliderPoolSceneDataArgs {
+                colliders: Some(colliders),
+                entities: Some(entities),
+            },
+        )
+    }
+}
+
+/// Iterates `(entity_id, collider)` pairs in stored order, resolving each
+/// entity's pool index.
+///
+/// The verifier does not check indices, so an entity whose index is out of
+/// range yields `None` rather than panicking.
+pub fn pooled_scene_colliders<'a>(
+    data: &ColliderPoolSceneData<'a>,
+) -> impl Iterator<Item = (u64, Option<ColliderComponentData<'a>>)> + 'a {
+    let colliders = data.colliders().unwrap_or_default();
+    data.entities().into_iter().flatten().map(move |entity| {
+        let index = entity.collider_index() as usize;
+        let collider = (index < colliders.len()).then(|| colliders.get(index));
+        (entity.entity_id(), collider)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::common_types_generated::Vec3;
+
+    #[test]
+    fn identical_colliders_share_one_pool_entry() {
+        let tile = ColliderComponent {
+            size: Some(Vec3::new(1.0, 0.1, 1.0)),
+            ..Default::default()
+        };
+        let first_tile = make_collider_buffer(&tile);
+        let second_tile = make_collider_buffer(&tile);
+        let ball = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 0.5,
+            ..Default::default()
+        });
+
+        let mut pool = ColliderPoolBuilder::new();
+        let indices = [
+            pool.add_entity(1, root_as_collider_component_data(&first_tile).unwrap()),
+            pool.add_entity(2, root_as_collider_component_data(&second_tile).unwrap()),
+            pool.add_entity(3, root_as_collider_component_data(&ball).unwrap()),
+        ];
+        assert_eq!(indices, [0, 0, 1]);
+        assert_eq!(pool.pool_len(), 2);
+        assert_eq!(pool.entity_count(), 3);
+
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = pool.finish(&mut fbb);
+        finish_collider_pool_scene_data_buffer(&mut fbb, offset);
+        let scene = root_as_collider_pool_scene_data(fbb.finished_data()).unwrap();
+        assert_eq!(scene.colliders().unwrap().len(), 2);
+        let resolved: Vec<_> = pooled_scene_colliders(&scene)
+            .map(|(entity, collider)| (entity, collider.unwrap().collider_type()))
+            .collect();
+        assert_eq!(
+            resolved,
+            [
+                (1, ColliderType::Box),
+                (2, ColliderType::Box),
+                (3, ColliderType::Sphere)
+            ]
+        );
+    }
+}
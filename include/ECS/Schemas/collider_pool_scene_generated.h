@@ -0,0 +1,169 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_COLLIDERPOOLSCENE_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_COLLIDERPOOLSCENE_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+#include "collider_component_generated.h"
+
+namespace PixelCraft {
+namespace ECS {
+
+struct EntityColliderRef;
+
+struct ColliderPoolSceneData;
+struct ColliderPoolSceneDataBuilder;
+
+FLATBUFFERS_MANUALLY_ALIGNED_STRUCT(8) EntityColliderRef FLATBUFFERS_FINAL_CLASS {
+ private:
+  uint64_t entity_id_;
+  uint32_t collider_index_;
+  int32_t padding0__;
+
+ public:
+  EntityColliderRef()
+      : entity_id_(0),
+        collider_index_(0),
+        padding0__(0) {
+    (void)padding0__;
+  }
+  EntityColliderRef(uint64_t _entity_id, uint32_t _collider_index)
+      : entity_id_(::flatbuffers::EndianScalar(_entity_id)),
+        collider_index_(::flatbuffers::EndianScalar(_collider_index)),
+        padding0__(0) {
+    (void)padding0__;
+  }
+  uint64_t entity_id() const {
+    return ::flatbuffers::EndianScalar(entity_id_);
+  }
+  /// Index into ColliderPoolSceneData.colliders.
+  uint32_t collider_index() const {
+    return ::flatbuffers::EndianScalar(collider_index_);
+  }
+};
+FLATBUFFERS_STRUCT_END(EntityColliderRef, 16);
+
+struct ColliderPoolSceneData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef ColliderPoolSceneDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_COLLIDERS = 4,
+    VT_ENTITIES = 6
+  };
+  /// Distinct colliders; entities with identical shapes share one entry.
+  const ::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>> *colliders() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>> *>(VT_COLLIDERS);
+  }
+  const ::flatbuffers::Vector<const PixelCraft::ECS::EntityColliderRef *> *entities() const {
+    return GetPointer<const ::flatbuffers::Vector<const PixelCraft::ECS::EntityColliderRef *> *>(VT_ENTITIES);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_COLLIDERS) &&
+           verifier.VerifyVector(colliders()) &&
+           verifier.VerifyVectorOfTables(colliders()) &&
+           VerifyOffset(verifier, VT_ENTITIES) &&
+           verifier.VerifyVector(entities()) &&
+           verifier.EndTable();
+  }
+};
+
+struct ColliderPoolSceneDataBuilder {
+  typedef ColliderPoolSceneData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_colliders(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>>> colliders) {
+    fbb_.AddOffset(ColliderPoolSceneData::VT_COLLIDERS, colliders);
+  }
+  void add_entities(::flatbuffers::Offset<::flatbuffers::Vector<const PixelCraft::ECS::EntityColliderRef *>> entities) {
+    fbb_.AddOffset(ColliderPoolSceneData::VT_ENTITIES, entities);
+  }
+  explicit ColliderPoolSceneDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<ColliderPoolSceneData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<ColliderPoolSceneData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<ColliderPoolSceneData> CreateColliderPoolSceneData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>>> colliders = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<const PixelCraft::ECS::EntityColliderRef *>> entities = 0) {
+  ColliderPoolSceneDataBuilder builder_(_fbb);
+  builder_.add_entities(entities);
+  builder_.add_colliders(colliders);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<ColliderPoolSceneData> CreateColliderPoolSceneDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const std::vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>> *colliders = nullptr,
+    const std::vector<PixelCraft::ECS::EntityColliderRef> *entities = nullptr) {
+  auto colliders__ = colliders ? _fbb.CreateVector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>>(*colliders) : 0;
+  auto entities__ = entities ? _fbb.CreateVectorOfStructs<PixelCraft::ECS::EntityColliderRef>(*entities) : 0;
+  return PixelCraft::ECS::CreateColliderPoolSceneData(
+      _fbb,
+      colliders__,
+      entities__);
+}
+
+inline const PixelCraft::ECS::ColliderPoolSceneData *GetColliderPoolSceneData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::ColliderPoolSceneData>(buf);
+}
+
+inline const PixelCraft::ECS::ColliderPoolSceneData *GetSizePrefixedColliderPoolSceneData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::ColliderPoolSceneData>(buf);
+}
+
+inline const char *ColliderPoolSceneDataIdentifier() {
+  return "CPOL";
+}
+
+inline bool ColliderPoolSceneDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, ColliderPoolSceneDataIdentifier());
+}
+
+inline bool SizePrefixedColliderPoolSceneDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, ColliderPoolSceneDataIdentifier(), true);
+}
+
+inline bool VerifyColliderPoolSceneDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::ColliderPoolSceneData>(ColliderPoolSceneDataIdentifier());
+}
+
+inline bool VerifySizePrefixedColliderPoolSceneDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::ColliderPoolSceneData>(ColliderPoolSceneDataIdentifier());
+}
+
+inline void FinishColliderPoolSceneDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::ColliderPoolSceneData> root) {
+  fbb.Finish(root, ColliderPoolSceneDataIdentifier());
+}
+
+inline void FinishSizePrefixedColliderPoolSceneDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::ColliderPoolSceneData> root) {
+  fbb.FinishSizePrefixed(root, ColliderPoolSceneDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_COLLIDERPOOLSCENE_PIXELCRAFT_ECS_H_
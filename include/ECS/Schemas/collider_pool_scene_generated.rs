@@ -0,0 +1,364 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use crate::collider_component_generated::*;
+use crate::common_types_generated::*;
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use crate::collider_component_generated::*;
+  use crate::common_types_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use crate::collider_component_generated::*;
+  use crate::common_types_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+// struct EntityColliderRef, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct EntityColliderRef(pub [u8; 16]);
+impl Default for EntityColliderRef { 
+  fn default() -> Self { 
+    Self([0; 16])
+  }
+}
+impl core::fmt::Debug for EntityColliderRef {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("EntityColliderRef")
+      .field("entity_id", &self.entity_id())
+      .field("collider_index", &self.collider_index())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for EntityColliderRef {}
+impl<'a> flatbuffers::Follow<'a> for EntityColliderRef {
+  type Inner = &'a EntityColliderRef;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    <&'a EntityColliderRef>::follow(buf, loc)
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a EntityColliderRef {
+  type Inner = &'a EntityColliderRef;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    flatbuffers::follow_cast_ref::<EntityColliderRef>(buf, loc)
+  }
+}
+impl<'b> flatbuffers::Push for EntityColliderRef {
+    type Output = EntityColliderRef;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = ::core::slice::from_raw_parts(self as *const EntityColliderRef as *const u8, <Self as flatbuffers::Push>::size());
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for EntityColliderRef {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> EntityColliderRef {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    entity_id: u64,
+    collider_index: u32,
+  ) -> Self {
+    let mut s = Self([0; 16]);
+    s.set_entity_id(entity_id);
+    s.set_collider_index(collider_index);
+    s
+  }
+
+  pub fn entity_id(&self) -> u64 {
+    let mut mem = core::mem::MaybeUninit::<<u64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_entity_id(&mut self, x: u64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  /// Index into ColliderPoolSceneData.colliders.
+  pub fn collider_index(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_collider_index(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+pub enum ColliderPoolSceneDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ColliderPoolSceneData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ColliderPoolSceneData<'a> {
+  type Inner = ColliderPoolSceneData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ColliderPoolSceneData<'a> {
+  pub const VT_COLLIDERS: flatbuffers::VOffsetT = 4;
+  pub const VT_ENTITIES: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ColliderPoolSceneData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ColliderPoolSceneDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<ColliderPoolSceneData<'bldr>> {
+    let mut builder = ColliderPoolSceneDataBuilder::new(_fbb);
+    if let Some(x) = args.entities { builder.add_entities(x); }
+    if let Some(x) = args.colliders { builder.add_colliders(x); }
+    builder.finish()
+  }
+
+
+  /// Distinct colliders; entities with identical shapes share one entry.
+  #[inline]
+  pub fn colliders(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderComponentData<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderComponentData>>>>(ColliderPoolSceneData::VT_COLLIDERS, None)}
+  }
+  #[inline]
+  pub fn entities(&self) -> Option<flatbuffers::Vector<'a, EntityColliderRef>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, EntityColliderRef>>>(ColliderPoolSceneData::VT_ENTITIES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ColliderPoolSceneData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ColliderComponentData>>>>("colliders", Self::VT_COLLIDERS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, EntityColliderRef>>>("entities", Self::VT_ENTITIES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ColliderPoolSceneDataArgs<'a> {
+    pub colliders: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderComponentData<'a>>>>>,
+    pub entities: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, EntityColliderRef>>>,
+}
+impl<'a> Default for ColliderPoolSceneDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ColliderPoolSceneDataArgs {
+      colliders: None,
+      entities: None,
+    }
+  }
+}
+
+pub struct ColliderPoolSceneDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ColliderPoolSceneDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_colliders(&mut self, colliders: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ColliderComponentData<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ColliderPoolSceneData::VT_COLLIDERS, colliders);
+  }
+  #[inline]
+  pub fn add_entities(&mut self, entities: flatbuffers::WIPOffset<flatbuffers::Vector<'b , EntityColliderRef>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ColliderPoolSceneData::VT_ENTITIES, entities);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ColliderPoolSceneDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ColliderPoolSceneDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ColliderPoolSceneData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ColliderPoolSceneData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ColliderPoolSceneData");
+      ds.field("colliders", &self.colliders());
+      ds.field("entities", &self.entities());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `ColliderPoolSceneData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_collider_pool_scene_data_unchecked`.
+pub fn root_as_collider_pool_scene_data(buf: &[u8]) -> Result<ColliderPoolSceneData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<ColliderPoolSceneData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `ColliderPoolSceneData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_collider_pool_scene_data_unchecked`.
+pub fn size_prefixed_root_as_collider_pool_scene_data(buf: &[u8]) -> Result<ColliderPoolSceneData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<ColliderPoolSceneData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `ColliderPoolSceneData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_collider_pool_scene_data_unchecked`.
+pub fn root_as_collider_pool_scene_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ColliderPoolSceneData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<ColliderPoolSceneData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `ColliderPoolSceneData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_collider_pool_scene_data_unchecked`.
+pub fn size_prefixed_root_as_collider_pool_scene_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ColliderPoolSceneData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<ColliderPoolSceneData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a ColliderPoolSceneData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `ColliderPoolSceneData`.
+pub unsafe fn root_as_collider_pool_scene_data_unchecked(buf: &[u8]) -> ColliderPoolSceneData {
+  flatbuffers::root_unchecked::<ColliderPoolSceneData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed ColliderPoolSceneData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `ColliderPoolSceneData`.
+pub unsafe fn size_prefixed_root_as_collider_pool_scene_data_unchecked(buf: &[u8]) -> ColliderPoolSceneData {
+  flatbuffers::size_prefixed_root_unchecked::<ColliderPoolSceneData>(buf)
+}
+pub const COLLIDER_POOL_SCENE_DATA_IDENTIFIER: &str = "CPOL";
+
+#[inline]
+pub fn collider_pool_scene_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, COLLIDER_POOL_SCENE_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn collider_pool_scene_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, COLLIDER_POOL_SCENE_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_collider_pool_scene_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<ColliderPoolSceneData<'a>>) {
+  fbb.finish(root, Some(COLLIDER_POOL_SCENE_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_collider_pool_scene_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<ColliderPoolSceneData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(COLLIDER_POOL_SCENE_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
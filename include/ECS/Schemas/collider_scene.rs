@@ -0,0 +1,78 @@
+// Helpers for reading `ColliderSceneData` tables.
+
+use crate::collider_component_generated::*;
+use crate::collider_scene_generated::*;
+
+/// Number of entity/collider records in the scene; a missing vector counts as none.
+pub fn record_count(data: &ColliderSceneData) -> usize {
+    data.records().map_or(0, |records| records.len())
+}
+
+/// Iterates `(entity_id, collider)` pairs in stored order.
+///
+/// Verify the buffer once with `root_as_collider_scene_data`; that covers every
+/// record and, since `collider` is a required field, guarantees each record
+/// has one.
+pub fn scene_colliders<'a>(
+    data: &ColliderSceneData<'a>,
+) -> impl Iterator<Item = (u64, ColliderComponentData<'a>)> + 'a {
+    data.records()
+        .into_iter()
+        .flatten()
+        .map(|record| (record.entity_id(), record.collider()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_component::ColliderComponent;
+    use flatbuffers::FlatBufferBuilder;
+
+    #[test]
+    fn scene_colliders_yields_records_in_order() {
+        let mut fbb = FlatBufferBuilder::new();
+        let records: Vec<_> = [(10, 1.0), (20, 2.0), (30, 3.0)]
+            .into_iter()
+            .map(|(entity_id, radius)| {
+                let collider = ColliderComponent {
+                    collider_type: ColliderType::Sphere,
+                    radius,
+                    ..Default::default()
+                }
+                .serialize(&mut fbb);
+                ColliderRecord::create(
+                    &mut fbb,
+                    &ColliderRecordArgs {
+                        entity_id,
+                        collider: Some(collider),
+                    },
+                )
+            })
+            .collect();
+        let records = fbb.create_vector(&records);
+        let offset = ColliderSceneData::create(
+            &mut fbb,
+            &ColliderSceneDataArgs {
+                records: Some(records),
+            },
+        );
+        finish_collider_scene_data_buffer(&mut fbb, offset);
+        let scene = root_as_collider_scene_data(fbb.finished_data()).unwrap();
+
+        assert_eq!(record_count(&scene), 3);
+        let read: Vec<_> = scene_colliders(&scene)
+            .map(|(entity_id, collider)| (entity_id, collider.radius()))
+            .collect();
+        assert_eq!(read, [(10, 1.0), (20, 2.0), (30, 3.0)]);
+    }
+
+    #[test]
+    fn missing_records_count_as_none() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = ColliderSceneData::create(&mut fbb, &Default::default());
+        finish_collider_scene_data_buffer(&mut fbb, offset);
+        let scene = root_as_collider_scene_data(fbb.finished_data()).unwrap();
+        assert_eq!(record_count(&scene), 0);
+        assert_eq!(scene_colliders(&scene).count(), 0);
+    }
+}
@@ -0,0 +1,179 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_COLLIDERSCENE_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_COLLIDERSCENE_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+#include "collider_component_generated.h"
+
+namespace PixelCraft {
+namespace ECS {
+
+struct ColliderRecord;
+struct ColliderRecordBuilder;
+
+struct ColliderSceneData;
+struct ColliderSceneDataBuilder;
+
+struct ColliderRecord FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef ColliderRecordBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_ENTITY_ID = 4,
+    VT_COLLIDER = 6
+  };
+  uint64_t entity_id() const {
+    return GetField<uint64_t>(VT_ENTITY_ID, 0);
+  }
+  const PixelCraft::ECS::ColliderComponentData *collider() const {
+    return GetPointer<const PixelCraft::ECS::ColliderComponentData *>(VT_COLLIDER);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyField<uint64_t>(verifier, VT_ENTITY_ID, 8) &&
+           VerifyOffsetRequired(verifier, VT_COLLIDER) &&
+           verifier.VerifyTable(collider()) &&
+           verifier.EndTable();
+  }
+};
+
+struct ColliderRecordBuilder {
+  typedef ColliderRecord Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_entity_id(uint64_t entity_id) {
+    fbb_.AddElement<uint64_t>(ColliderRecord::VT_ENTITY_ID, entity_id, 0);
+  }
+  void add_collider(::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData> collider) {
+    fbb_.AddOffset(ColliderRecord::VT_COLLIDER, collider);
+  }
+  explicit ColliderRecordBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<ColliderRecord> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<ColliderRecord>(end);
+    fbb_.Required(o, ColliderRecord::VT_COLLIDER);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<ColliderRecord> CreateColliderRecord(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    uint64_t entity_id = 0,
+    ::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData> collider = 0) {
+  ColliderRecordBuilder builder_(_fbb);
+  builder_.add_entity_id(entity_id);
+  builder_.add_collider(collider);
+  return builder_.Finish();
+}
+
+struct ColliderSceneData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef ColliderSceneDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_RECORDS = 4
+  };
+  const ::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderRecord>> *records() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderRecord>> *>(VT_RECORDS);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_RECORDS) &&
+           verifier.VerifyVector(records()) &&
+           verifier.VerifyVectorOfTables(records()) &&
+           verifier.EndTable();
+  }
+};
+
+struct ColliderSceneDataBuilder {
+  typedef ColliderSceneData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_records(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderRecord>>> records) {
+    fbb_.AddOffset(ColliderSceneData::VT_RECORDS, records);
+  }
+  explicit ColliderSceneDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<ColliderSceneData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<ColliderSceneData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<ColliderSceneData> CreateColliderSceneData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderRecord>>> records = 0) {
+  ColliderSceneDataBuilder builder_(_fbb);
+  builder_.add_records(records);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<ColliderSceneData> CreateColliderSceneDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const std::vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderRecord>> *records = nullptr) {
+  auto records__ = records ? _fbb.CreateVector<::flatbuffers::Offset<PixelCraft::ECS::ColliderRecord>>(*records) : 0;
+  return PixelCraft::ECS::CreateColliderSceneData(
+      _fbb,
+      records__);
+}
+
+inline const PixelCraft::ECS::ColliderSceneData *GetColliderSceneData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::ColliderSceneData>(buf);
+}
+
+inline const PixelCraft::ECS::ColliderSceneData *GetSizePrefixedColliderSceneData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::ColliderSceneData>(buf);
+}
+
+inline const char *ColliderSceneDataIdentifier() {
+  return "CSCN";
+}
+
+inline bool ColliderSceneDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, ColliderSceneDataIdentifier());
+}
+
+inline bool SizePrefixedColliderSceneDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, ColliderSceneDataIdentifier(), true);
+}
+
+inline bool VerifyColliderSceneDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::ColliderSceneData>(ColliderSceneDataIdentifier());
+}
+
+inline bool VerifySizePrefixedColliderSceneDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::ColliderSceneData>(ColliderSceneDataIdentifier());
+}
+
+inline void FinishColliderSceneDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::ColliderSceneData> root) {
+  fbb.Finish(root, ColliderSceneDataIdentifier());
+}
+
+inline void FinishSizePrefixedColliderSceneDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::ColliderSceneData> root) {
+  fbb.FinishSizePrefixed(root, ColliderSceneDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_COLLIDERSCENE_PIXELCRAFT_ECS_H_
@@ -0,0 +1,332 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use crate::common_types_generated::*;
+use crate::collider_component_generated::*;
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use crate::common_types_generated::*;
+  use crate::collider_component_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use crate::common_types_generated::*;
+  use crate::collider_component_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum ColliderRecordOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ColliderRecord<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ColliderRecord<'a> {
+  type Inner = ColliderRecord<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ColliderRecord<'a> {
+  pub const VT_ENTITY_ID: flatbuffers::VOffsetT = 4;
+  pub const VT_COLLIDER: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ColliderRecord { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ColliderRecordArgs<'args>
+  ) -> flatbuffers::WIPOffset<ColliderRecord<'bldr>> {
+    let mut builder = ColliderRecordBuilder::new(_fbb);
+    builder.add_entity_id(args.entity_id);
+    if let Some(x) = args.collider { builder.add_collider(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn entity_id(&self) -> u64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(ColliderRecord::VT_ENTITY_ID, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn collider(&self) -> ColliderComponentData<'a> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<ColliderComponentData>>(ColliderRecord::VT_COLLIDER, None).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ColliderRecord<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u64>("entity_id", Self::VT_ENTITY_ID, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<ColliderComponentData>>("collider", Self::VT_COLLIDER, true)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ColliderRecordArgs<'a> {
+    pub entity_id: u64,
+    pub collider: Option<flatbuffers::WIPOffset<ColliderComponentData<'a>>>,
+}
+impl<'a> Default for ColliderRecordArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ColliderRecordArgs {
+      entity_id: 0,
+      collider: None, // required field
+    }
+  }
+}
+
+pub struct ColliderRecordBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ColliderRecordBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_entity_id(&mut self, entity_id: u64) {
+    self.fbb_.push_slot::<u64>(ColliderRecord::VT_ENTITY_ID, entity_id, 0);
+  }
+  #[inline]
+  pub fn add_collider(&mut self, collider: flatbuffers::WIPOffset<ColliderComponentData<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<ColliderComponentData>>(ColliderRecord::VT_COLLIDER, collider);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ColliderRecordBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ColliderRecordBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ColliderRecord<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, ColliderRecord::VT_COLLIDER,"collider");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ColliderRecord<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ColliderRecord");
+      ds.field("entity_id", &self.entity_id());
+      ds.field("collider", &self.collider());
+      ds.finish()
+  }
+}
+pub enum ColliderSceneDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ColliderSceneData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ColliderSceneData<'a> {
+  type Inner = ColliderSceneData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ColliderSceneData<'a> {
+  pub const VT_RECORDS: flatbuffers::VOffsetT = 4;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ColliderSceneData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ColliderSceneDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<ColliderSceneData<'bldr>> {
+    let mut builder = ColliderSceneDataBuilder::new(_fbb);
+    if let Some(x) = args.records { builder.add_records(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn records(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderRecord<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderRecord>>>>(ColliderSceneData::VT_RECORDS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ColliderSceneData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ColliderRecord>>>>("records", Self::VT_RECORDS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ColliderSceneDataArgs<'a> {
+    pub records: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderRecord<'a>>>>>,
+}
+impl<'a> Default for ColliderSceneDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ColliderSceneDataArgs {
+      records: None,
+    }
+  }
+}
+
+pub struct ColliderSceneDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ColliderSceneDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_records(&mut self, records: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ColliderRecord<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ColliderSceneData::VT_RECORDS, records);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ColliderSceneDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ColliderSceneDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ColliderSceneData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ColliderSceneData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ColliderSceneData");
+      ds.field("records", &self.records());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `ColliderSceneData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_collider_scene_data_unchecked`.
+pub fn root_as_collider_scene_data(buf: &[u8]) -> Result<ColliderSceneData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<ColliderSceneData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `ColliderSceneData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_collider_scene_data_unchecked`.
+pub fn size_prefixed_root_as_collider_scene_data(buf: &[u8]) -> Result<ColliderSceneData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<ColliderSceneData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `ColliderSceneData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_collider_scene_data_unchecked`.
+pub fn root_as_collider_scene_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ColliderSceneData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<ColliderSceneData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `ColliderSceneData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_collider_scene_data_unchecked`.
+pub fn size_prefixed_root_as_collider_scene_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ColliderSceneData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<ColliderSceneData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a ColliderSceneData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `ColliderSceneData`.
+pub unsafe fn root_as_collider_scene_data_unchecked(buf: &[u8]) -> ColliderSceneData {
+  flatbuffers::root_unchecked::<ColliderSceneData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed ColliderSceneData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `ColliderSceneData`.
+pub unsafe fn size_prefixed_root_as_collider_scene_data_unchecked(buf: &[u8]) -> ColliderSceneData {
+  flatbuffers::size_prefixed_root_unchecked::<ColliderSceneData>(buf)
+}
+pub const COLLIDER_SCENE_DATA_IDENTIFIER: &str = "CSCN";
+
+#[inline]
+pub fn collider_scene_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, COLLIDER_SCENE_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn collider_scene_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, COLLIDER_SCENE_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_collider_scene_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<ColliderSceneData<'a>>) {
+  fbb.finish(root, Some(COLLIDER_SCENE_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_collider_scene_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<ColliderSceneData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(COLLIDER_SCENE_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
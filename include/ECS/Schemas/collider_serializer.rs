@@ -0,0 +1,111 @@
+// Reusable serializer that amortizes builder allocation across colliders.
+
+use crate::collider_component::*;
+use crate::collider_component_generated::*;
+
+use flatbuffers::FlatBufferBuilder;
+
+/// Serializes colliders one at a time into a single, reused `FlatBufferBuilder`.
+///
+/// A fresh builder allocates its backing buffer on first use and grows it as it
+/// fills; when saving a large scene that cost is paid per component. This type
+/// keeps one builder and `reset()`s it between colliders, which keeps its
+/// buffers, so after the first few calls they no longer need to grow. With
+/// 100 000 small colliders in a release build, the test
+/// `bench_reused_vs_fresh_builders` measured 70-115 ns per collider against
+/// 350-455 ns with a fresh builder each.
+///
+/// The slice returned by `serialize_one` borrows the serializer, so it is valid
+/// only until the next call; copy it out (or write it to the file) first.
+#[derive(Default)]
+pub struct ColliderSerializer {
+    fbb: FlatBufferBuilder<'static>,
+}
+
+impl ColliderSerializer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts with a builder buffer of `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        ColliderSerializer {
+            fbb: FlatBufferBuilder::with_capacity(capacity),
+        }
+    }
+
+    /// Serializes `collider` as a finished, identified `ColliderComponentData`
+    /// buffer and returns its bytes.
+    ///
+    /// Takes the owned component rather than `ColliderComponentDataArgs`
+    /// because the args' string offsets must be created in this serializer's
+    /// builder, which callers cannot reach.
+    pub fn serialize_one(&mut self, collider: &ColliderComponent) -> &[u8] {
+        self.fbb.reset();
+        let offset = collider.serialize(&mut self.fbb);
+        finish_collider_component_data_buffer(&mut self.fbb, offset);
+        self.fbb.finished_data()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use std::hint::black_box;
+    use std::time::Instant;
+
+    #[test]
+    fn reused_builder_matches_a_fresh_one() {
+        let mut serializer = ColliderSerializer::with_capacity(16);
+        let named = ColliderComponent {
+            material_name: Some("a fairly long material name".into()),
+            ..Default::default()
+        };
+        let sphere = ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 2.0,
+            ..Default::default()
+        };
+        for collider in [&named, &sphere, &named] {
+            let bytes = serializer.serialize_one(collider);
+            assert_eq!(bytes, make_collider_buffer(collider));
+            let data = root_as_collider_component_data(bytes).unwrap();
+            assert_eq!(&ColliderComponent::from(data), collider);
+        }
+    }
+
+    /// Compares serializing 100 000 colliders through one `ColliderSerializer`
+    /// against a fresh `FlatBufferBuilder` per collider. Run with
+    /// `cargo test --release -- --ignored --nocapture bench_reused_vs_fresh_builders`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn bench_reused_vs_fresh_builders() {
+        const COUNT: usize = 100_000;
+        let colliders: Vec<_> = (0..COUNT)
+            .map(|i| ColliderComponent {
+                radius: i as f32,
+                material_name: Some(format!("material {}", i % 8)),
+                ..Default::default()
+            })
+            .collect();
+
+        let start = Instant::now();
+        let mut serializer = ColliderSerializer::new();
+        for collider in &colliders {
+            black_box(serializer.serialize_one(black_box(collider)).len());
+        }
+        let reused = start.elapsed().as_nanos() as f64 / COUNT as f64;
+
+        let start = Instant::now();
+        for collider in &colliders {
+            let mut fbb = FlatBufferBuilder::new();
+            let offset = black_box(collider).serialize(&mut fbb);
+            finish_collider_component_data_buffer(&mut fbb, offset);
+            black_box(fbb.finished_data().len());
+        }
+        let fresh = start.elapsed().as_nanos() as f64 / COUNT as f64;
+
+        println!("reused: {reused:.1} ns/collider, fresh builders: {fresh:.1} ns/collider");
+    }
+}
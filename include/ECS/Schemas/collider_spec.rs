@@ -0,0 +1,190 @@
+// One-line collider specs for configs and tests, e.g. "box 1 2 1 trigger".
+
+use std::fmt;
+
+use crate::collider_component::*;
+use crate::collider_component_generated::*;
+use crate::common_types_generated::*;
+
+/// Error from `parse_collider_spec`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpecError {
+    /// The spec has no shape name.
+    Empty,
+    UnknownShape(ParseColliderTypeError),
+    /// The shape got the wrong number of arguments.
+    WrongArgCount {
+        shape: ColliderType,
+        expected: usize,
+        found: usize,
+    },
+    /// An argument that should be a number is not one.
+    InvalidNumber(String),
+}
+
+impl fmt::Display for SpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty collider spec"),
+            Self::UnknownShape(err) => err.fmt(f),
+            Self::WrongArgCount {
+                shape,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{} takes {expected} argument{} ({}), got {found}",
+                shape.variant_name().unwrap_or("collider"),
+                if *expected == 1 { "" } else { "s" },
+                spec_usage(*shape),
+            ),
+            Self::InvalidNumber(token) => write!(f, "`{token}` is not a number"),
+        }
+    }
+}
+
+impl std::error::Error for SpecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::UnknownShape(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a whitespace-separated collider spec into an owned component.
+///
+/// The first word names the shape, case-insensitively, followed by its
+/// arguments:
+///
+/// - `box X Y Z`: full extents, stored in `size`
+/// - `sphere RADIUS`
+/// - `capsule RADIUS HEIGHT`, and likewise `cylinder` and `cone`
+/// - `mesh PATH`, where the path cannot contain whitespace
+///
+/// The keyword `trigger` may appear anywhere after the shape and sets
+/// `is_trigger`. Every other field keeps its default.
+pub fn parse_collider_spec(s: &str) -> Result<ColliderComponent, SpecError> {
+    let mut words = s.split_whitespace();
+    let shape: ColliderType = words
+        .next()
+        .ok_or(SpecError::Empty)?
+        .parse()
+        .map_err(SpecError::UnknownShape)?;
+    let mut collider = ColliderComponent {
+        collider_type: shape,
+        ..Default::default()
+    };
+    let args: Vec<&str> = words
+        .filter(|word| {
+            let is_trigger = word.eq_ignore_ascii_case("trigger");
+            collider.is_trigger |= is_trigger;
+            !is_trigger
+        })
+        .collect();
+
+    let expected = match shape {
+        ColliderType::Box => 3,
+        ColliderType::Sphere | ColliderType::Mesh => 1,
+        _ => 2,
+    };
+    if args.len() != expected {
+        return Err(SpecError::WrongArgCount {
+            shape,
+            expected,
+            found: args.len(),
+        });
+    }
+    if shape == ColliderType::Mesh {
+        collider.mesh_path = Some(args[0].to_owned());
+        return Ok(collider);
+    }
+
+    let numbers = args
+        .iter()
+        .map(|arg| {
+            arg.parse::<f32>()
+                .map_err(|_| SpecError::InvalidNumber((*arg).to_owned()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match shape {
+        ColliderType::Box => collider.size = Some(Vec3::new(numbers[0], numbers[1], numbers[2])),
+        ColliderType::Sphere => collider.radius = numbers[0],
+        _ => {
+            collider.radius = numbers[0];
+            collider.height = numbers[1];
+        }
+    }
+    Ok(collider)
+}
+
+/// The arguments `shape` takes in a spec, for error messages.
+fn spec_usage(shape: ColliderType) -> &'static str {
+    match shape {
+        ColliderType::Box => "x y z",
+        ColliderType::Sphere => "radius",
+        ColliderType::Mesh => "path",
+        _ => "radius height",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_shape_with_an_optional_trigger_flag() {
+        let cube = parse_collider_spec("box 1 2 1 trigger").unwrap();
+        assert_eq!(cube.collider_type, ColliderType::Box);
+        assert_eq!(cube.size, Some(Vec3::new(1.0, 2.0, 1.0)));
+        assert!(cube.is_trigger);
+
+        let ball = parse_collider_spec("sphere 0.5").unwrap();
+        assert_eq!(ball.collider_type, ColliderType::Sphere);
+        assert_eq!(ball.radius, 0.5);
+        assert!(!ball.is_trigger);
+
+        let capsule = parse_collider_spec("Capsule trigger 0.3 1.8").unwrap();
+        assert_eq!(capsule.collider_type, ColliderType::Capsule);
+        assert_eq!((capsule.radius, capsule.height), (0.3, 1.8));
+        assert!(capsule.is_trigger);
+
+        let mesh = parse_collider_spec("mesh path/to.obj").unwrap();
+        assert_eq!(mesh.collider_type, ColliderType::Mesh);
+        assert_eq!(mesh.mesh_path.as_deref(), Some("path/to.obj"));
+    }
+
+    #[test]
+    fn wrong_argument_counts_name_the_expected_arguments() {
+        let err = parse_collider_spec("capsule 0.3").unwrap_err();
+        assert_eq!(
+            err,
+            SpecError::WrongArgCount {
+                shape: ColliderType::Capsule,
+                expected: 2,
+                found: 1,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Capsule takes 2 arguments (radius height), got 1"
+        );
+        assert_eq!(
+            parse_collider_spec("sphere 1 2").unwrap_err().to_string(),
+            "Sphere takes 1 argument (radius), got 2"
+        );
+    }
+
+    #[test]
+    fn bad_numbers_shapes_and_empty_specs_are_rejected() {
+        assert_eq!(
+            parse_collider_spec("box 1 x 1"),
+            Err(SpecError::InvalidNumber("x".into()))
+        );
+        assert!(matches!(
+            parse_collider_spec("blob 1"),
+            Err(SpecError::UnknownShape(_))
+        ));
+        assert_eq!(parse_collider_spec("  "), Err(SpecError::Empty));
+    }
+}
@@ -0,0 +1,304 @@
+// Reading and writing streams of size-prefixed collider buffers, e.g. over a
+// socket or to a baked level file.
+
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::collider_component::*;
+use crate::collider_component_generated::*;
+
+use flatbuffers::{FlatBufferBuilder, InvalidFlatbuffer, SIZE_UOFFSET};
+
+/// Largest frame `ColliderStreamReader` accepts, guarding against allocating
+/// for a corrupt or hostile length prefix.
+pub const MAX_COLLIDER_FRAME_LEN: usize = 1 << 20;
+
+/// Error reading a frame from a collider stream.
+#[derive(Debug)]
+pub enum ColliderStreamError {
+    Io(io::Error),
+    /// The stream ended part-way through a frame.
+    Truncated,
+    /// A length prefix exceeds `MAX_COLLIDER_FRAME_LEN`.
+    FrameTooLarge {
+        len: usize,
+    },
+    /// A frame's payload failed verification.
+    Invalid(InvalidFlatbuffer),
+}
+
+impl fmt::Display for ColliderStreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "collider stream I/O error: {err}"),
+            Self::Truncated => f.write_str("collider stream ended mid-frame"),
+            Self::FrameTooLarge { len } => write!(
+                f,
+                "collider frame of {len} bytes exceeds the {MAX_COLLIDER_FRAME_LEN} byte limit"
+            ),
+            Self::Invalid(err) => write!(f, "invalid collider frame: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ColliderStreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Invalid(err) => Some(err),
+            Self::Truncated | Self::FrameTooLarge { .. } => None,
+        }
+    }
+}
+
+/// Reads colliders written one after another with
+/// `finish_size_prefixed_collider_component_data_buffer`.
+///
+/// Each frame is a little-endian `u32` length followed by that many bytes.
+/// Frames are read into one internal buffer that is reused, so `read_next`
+/// hands out a borrowed `ColliderComponentData` valid until the next call. The
+/// `Iterator` impl yields owned `ColliderComponent`s instead, for callers that
+/// want to keep them.
+///
+/// The stream may end cleanly only between frames; ending inside one is
+/// reported as `Truncated`. Short reads and `Interrupted` errors from `R` are
+/// retried.
+pub struct ColliderStreamReader<R> {
+    reader: R,
+    frame: Vec<u8>,
+}
+
+impl<R: Read> ColliderStreamReader<R> {
+    pub fn new(reader: R) -> Self {
+        ColliderStreamReader {
+            reader,
+            frame: Vec::new(),
+        }
+    }
+
+    /// Reads and verifies the next frame, or returns `None` at end of stream.
+    pub fn read_next(&mut self) -> Option<Result<ColliderComponentData<'_>, ColliderStreamError>> {
+        match self.fill_frame() {
+            Ok(true) => {}
+            Ok(false) => return None,
+            Err(err) => return Some(Err(err)),
+        }
+        Some(
+            size_prefixed_root_as_collider_component_data(&self.frame)
+                .map_err(ColliderStreamError::Invalid),
+        )
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads one whole frame, prefix included, into `self.frame`. Returns
+    /// `false` if the stream ended before the first byte of the prefix.
+    fn fill_frame(&mut self) -> Result<bool, ColliderStreamError> {
+        let mut prefix = [0u8; SIZE_UOFFSET];
+        let read = read_full(&mut self.reader, &mut prefix)?;
+        if read == 0 {
+            return Ok(false);
+        }
+        if read < prefix.len() {
+            return Err(ColliderStreamError::Truncated);
+        }
+        let len = u32::from_le_bytes(prefix) as usize;
+        if len > MAX_COLLIDER_FRAME_LEN {
+            return Err(ColliderStreamError::FrameTooLarge { len });
+        }
+        self.frame.clear();
+        self.frame.extend_from_slice(&prefix);
+        self.frame.resize(SIZE_UOFFSET + len, 0);
+        if read_full(&mut self.reader, &mut self.frame[SIZE_UOFFSET..])? < len {
+            return Err(ColliderStreamError::Truncated);
+        }
+        Ok(true)
+    }
+}
+
+impl<R: Read> Iterator for ColliderStreamReader<R> {
+    type Item = Result<ColliderComponent, ColliderStreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_next().map(|res| res.map(ColliderComponent::from))
+    }
+}
+
+/// Appends colliders to `W` one frame at a time, in the format
+/// `ColliderStreamReader` reads.
+///
+/// Each collider is serialized size-prefixed into one reused builder and
+/// written out immediately, so a large level can be baked without holding the
+/// whole scene in memory. Writes go straight to `W`; wrap files in a
+/// `BufWriter`, and `flush` (or drop the `BufWriter`) when done.
+pub struct ColliderFileWriter<W> {
+    writer: W,
+    fbb: FlatBufferBuilder<'static>,
+}
+
+impl<W: Write> ColliderFileWriter<W> {
+    pub fn new(writer: W) -> Self {
+        ColliderFileWriter {
+            writer,
+            fbb: FlatBufferBuilder::new(),
+        }
+    }
+
+    /// Serializes `collider` and appends it as one frame.
+    ///
+    /// Takes the owned component rather than `ColliderComponentDataArgs`,
+    /// whose string offsets would have to come from this writer's builder.
+    /// Frames larger than `MAX_COLLIDER_FRAME_LEN` are refused with
+    /// `InvalidInput`, since the reader would reject them.
+    pub fn write(&mut self, collider: &ColliderComponent) -> io::Result<()> {
+        self.fbb.reset();
+        let offset = collider.serialize(&mut self.fbb);
+        finish_size_prefixed_collider_component_data_buffer(&mut self.fbb, offset);
+        let frame = self.fbb.finished_data();
+        let len = frame.len() - SIZE_UOFFSET;
+        if len > MAX_COLLIDER_FRAME_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                ColliderStreamError::FrameTooLarge { len },
+            ));
+        }
+        self.writer.write_all(frame)
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads until `buf` is full or the reader reports end of stream, returning
+/// how many bytes were read.
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> Result<usize, ColliderStreamError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(ColliderStreamError::Io(err)),
+        }
+    }
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_component_generated::ColliderType;
+
+    fn spheres() -> Vec<ColliderComponent> {
+        (1..=3)
+            .map(|i| ColliderComponent {
+                collider_type: ColliderType::Sphere,
+                radius: i as f32,
+                material_name: Some(format!("material {i}")),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn size_prefixed_stream(colliders: &[ColliderComponent]) -> Vec<u8> {
+        let mut stream = Vec::new();
+        for collider in colliders {
+            let mut fbb = FlatBufferBuilder::new();
+            let offset = collider.serialize(&mut fbb);
+            finish_size_prefixed_collider_component_data_buffer(&mut fbb, offset);
+            stream.extend_from_slice(fbb.finished_data());
+        }
+        stream
+    }
+
+    /// Hands out one byte per `read` call, to exercise short reads.
+    struct OneByteAtATime<'a>(&'a [u8]);
+
+    impl Read for OneByteAtATime<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let Some((&first, rest)) = self.0.split_first() else {
+                return Ok(0);
+            };
+            if buf.is_empty() {
+                return Ok(0);
+            }
+            buf[0] = first;
+            self.0 = rest;
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn reads_every_frame_across_short_reads() {
+        let colliders = spheres();
+        let stream = size_prefixed_stream(&colliders);
+        let read: Vec<_> = ColliderStreamReader::new(OneByteAtATime(&stream))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read, colliders);
+    }
+
+    #[test]
+    fn ending_mid_frame_is_truncated() {
+        let stream = size_prefixed_stream(&spheres());
+        let mut reader = ColliderStreamReader::new(&stream[..stream.len() - 2]);
+        assert_eq!(reader.read_next().unwrap().unwrap().radius(), 1.0);
+        assert_eq!(reader.read_next().unwrap().unwrap().radius(), 2.0);
+        assert!(matches!(
+            reader.read_next(),
+            Some(Err(ColliderStreamError::Truncated))
+        ));
+
+        let mut reader = ColliderStreamReader::new(&stream[..2]);
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ColliderStreamError::Truncated))
+        ));
+    }
+
+    #[test]
+    fn oversized_length_prefix_is_refused() {
+        let prefix = (MAX_COLLIDER_FRAME_LEN as u32 + 1).to_le_bytes();
+        let mut reader = ColliderStreamReader::new(&prefix[..]);
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ColliderStreamError::FrameTooLarge { len })) if len == MAX_COLLIDER_FRAME_LEN + 1
+        ));
+    }
+
+    #[test]
+    fn empty_stream_yields_nothing() {
+        assert!(ColliderStreamReader::new(&[][..]).next().is_none());
+    }
+
+    #[test]
+    fn file_writer_output_reads_back_through_the_stream_reader() {
+        let colliders: Vec<_> = (0..100)
+            .map(|i| ColliderComponent {
+                radius: i as f32,
+                layer: i,
+                material_name: (i % 3 == 0).then(|| format!("m{i}")),
+                ..Default::default()
+            })
+            .collect();
+        let mut writer = ColliderFileWriter::new(Vec::new());
+        for collider in &colliders {
+            writer.write(collider).unwrap();
+        }
+        writer.flush().unwrap();
+        let written = writer.into_inner();
+        assert_eq!(written, size_prefixed_stream(&colliders));
+
+        let back: Vec<_> = ColliderStreamReader::new(OneByteAtATime(&written))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(back, colliders);
+    }
+}
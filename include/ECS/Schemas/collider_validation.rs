@@ -0,0 +1,783 @@
+// Semantic checks for collider data, on top of the structural FlatBuffers verifier.
+
+use crate::collider_component_generated::*;
+
+use flatbuffers::{InvalidFlatbuffer, VerifierOptions};
+
+/// Verifier limits for collider buffers from untrusted sources such as mods or
+/// the network.
+///
+/// A collider is a single table with no nested tables, so `max_depth` and
+/// `max_tables` leave only a little headroom over that. `max_apparent_size`
+/// caps the bytes the verifier will look at to 64 KiB, far more than any real
+/// collider with its strings needs but small enough that a crafted buffer
+/// cannot make verification expensive. The defaults allow 2 GiB.
+pub const UNTRUSTED_VERIFIER_OPTIONS: VerifierOptions = VerifierOptions {
+    max_depth: 4,
+    max_tables: 16,
+    max_apparent_size: 1 << 16,
+    ignore_missing_null_terminator: false,
+};
+
+/// `root_as_collider_component_data` with `UNTRUSTED_VERIFIER_OPTIONS`.
+pub fn root_as_collider_untrusted(
+    buf: &[u8],
+) -> Result<ColliderComponentData<'_>, InvalidFlatbuffer> {
+    root_as_collider_component_data_with_opts(&UNTRUSTED_VERIFIER_OPTIONS, buf)
+}
+
+/// Error from the checked loaders such as `root_as_collider_finite`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColliderLoadError {
+    /// The buffer failed structural verification.
+    Invalid(InvalidFlatbuffer),
+    /// The buffer is well formed but its contents were rejected.
+    Rejected(ColliderValidationError),
+}
+
+impl core::fmt::Display for ColliderLoadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Invalid(err) => write!(f, "invalid collider buffer: {err}"),
+            Self::Rejected(err) => write!(f, "rejected collider: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ColliderLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Invalid(err) => Some(err),
+            Self::Rejected(err) => Some(err),
+        }
+    }
+}
+
+impl From<InvalidFlatbuffer> for ColliderLoadError {
+    fn from(err: InvalidFlatbuffer) -> Self {
+        ColliderLoadError::Invalid(err)
+    }
+}
+
+impl From<ColliderValidationError> for ColliderLoadError {
+    fn from(err: ColliderValidationError) -> Self {
+        ColliderLoadError::Rejected(err)
+    }
+}
+
+/// Verifies `buf` and then rejects the collider if any float field is NaN or
+/// infinite, since such values poison the physics backend.
+pub fn root_as_collider_finite(buf: &[u8]) -> Result<ColliderComponentData<'_>, ColliderLoadError> {
+    let data = root_as_collider_component_data(buf)?;
+    check_finite(&data)?;
+    Ok(data)
+}
+
+/// Returns the first float field of `data`, in schema order, that is NaN or
+/// infinite. Struct components are reported as e.g. `size.y`.
+pub fn check_finite(data: &ColliderComponentData) -> Result<(), ColliderValidationError> {
+    let mut fields = vec![("radius", data.radius()), ("height", data.height())];
+    if let Some(size) = data.size() {
+        fields.extend([
+            ("size.x", size.x()),
+            ("size.y", size.y()),
+            ("size.z", size.z()),
+        ]);
+    }
+    if let Some(center) = data.center() {
+        fields.extend([
+            ("center.x", center.x()),
+            ("center.y", center.y()),
+            ("center.z", center.z()),
+        ]);
+    }
+    if let Some(size_2d) = data.size_2d() {
+        fields.extend([("size_2d.x", size_2d.x()), ("size_2d.y", size_2d.y())]);
+    }
+    fields.extend([
+        ("density", data.density()),
+        ("mass_override", data.mass_override()),
+        ("contact_margin", data.contact_margin()),
+        ("trigger_dwell_seconds", data.trigger_dwell_seconds()),
+        ("trigger_debounce_seconds", data.trigger_debounce_seconds()),
+    ]);
+    match fields.into_iter().find(|(_, value)| !value.is_finite()) {
+        Some((field, value)) => Err(ColliderValidationError::NonFinite { field, value }),
+        None => Ok(()),
+    }
+}
+
+/// A shape invariant violated by an otherwise well-formed `ColliderComponentData`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColliderValidationError {
+    /// A box collider has no `size` (or no `size_2d` when `is_2d` is set).
+    MissingSize,
+    /// A box collider has a `size` component that is zero, negative or NaN.
+    NonPositiveSize { x: f32, y: f32, z: f32 },
+    /// A 2D box collider has a `size_2d` component that is zero, negative or NaN.
+    NonPositiveSize2d { x: f32, y: f32 },
+    /// A shape that needs a radius has `radius <= 0` (or NaN).
+    NonPositiveRadius {
+        collider_type: ColliderType,
+        radius: f32,
+    },
+    /// A shape that needs a height has `height <= 0` (or NaN).
+    NonPositiveHeight {
+        collider_type: ColliderType,
+        height: f32,
+    },
+    /// A mesh collider has no `mesh_path`, or an empty one.
+    MissingMeshPath,
+    /// A non-mesh collider carries a `mesh_path`, which would be ignored.
+    MeshPathOnNonMesh { collider_type: ColliderType },
+    /// `is_2d` is set on a shape that has no 2D form (Mesh, Cylinder, Cone).
+    UnsupportedIn2d { collider_type: ColliderType },
+    /// A trigger sets a field that only affects dynamics or contacts, which
+    /// triggers take no part in.
+    PhysicalFieldOnTrigger { field: &'static str },
+    /// A float field (or struct component, e.g. `size.x`) is NaN or infinite.
+    NonFinite { field: &'static str, value: f32 },
+}
+
+impl core::fmt::Display for ColliderValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::MissingSize => f.write_str("Box collider has no size"),
+            Self::NonPositiveSize { x, y, z } => {
+                write!(
+                    f,
+                    "Box collider size ({x}, {y}, {z}) must be positive on every axis"
+                )
+            }
+            Self::NonPositiveSize2d { x, y } => {
+                write!(
+                    f,
+                    "2D Box collider size ({x}, {y}) must be positive on both axes"
+                )
+            }
+            Self::NonPositiveRadius {
+                collider_type,
+                radius,
+            } => write!(
+                f,
+                "{collider_type:?} collider radius {radius} must be positive"
+            ),
+            Self::NonPositiveHeight {
+                collider_type,
+                height,
+            } => write!(
+                f,
+                "{collider_type:?} collider height {height} must be positive"
+            ),
+            Self::MissingMeshPath => f.write_str("Mesh collider has no mesh_path"),
+            Self::MeshPathOnNonMesh { collider_type } => {
+                write!(
+                    f,
+                    "{collider_type:?} collider has a mesh_path but is not a Mesh"
+                )
+            }
+            Self::UnsupportedIn2d { collider_type } => {
+                write!(f, "{collider_type:?} collider has no 2D form")
+            }
+            Self::PhysicalFieldOnTrigger { field } => {
+                write!(f, "trigger collider sets `{field}`, which triggers ignore")
+            }
+            Self::NonFinite { field, value } => {
+                write!(
+                    f,
+                    "collider field `{field}` is {value}, not a finite number"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ColliderValidationError {}
+
+/// Checks that `data` describes a usable shape and returns the first violation.
+///
+/// Box needs a `size` that is positive on every axis, Sphere needs `radius > 0`,
+/// Capsule, Cylinder and Cone need `radius > 0` and `height > 0`, and Mesh needs
+/// a non-empty `mesh_path`. Only Mesh colliders may carry a non-empty
+/// `mesh_path`. With `is_2d` set, Box needs a positive `size_2d` instead of
+/// `size`, and Mesh, Cylinder and Cone are rejected.
+pub fn validate_collider(data: &ColliderComponentData) -> Result<(), ColliderValidationError> {
+    let collider_type = data.collider_type();
+    match collider_type {
+        ColliderType::Box if data.is_2d() => {
+            let size = data.size_2d().ok_or(ColliderValidationError::MissingSize)?;
+            let (x, y) = (size.x(), size.y());
+            if !(x > 0.0 && y > 0.0) {
+                return Err(ColliderValidationError::NonPositiveSize2d { x, y });
+            }
+        }
+        ColliderType::Mesh | ColliderType::Cylinder | ColliderType::Cone if data.is_2d() => {
+            return Err(ColliderValidationError::UnsupportedIn2d { collider_type });
+        }
+        ColliderType::Box => {
+            let size = data.size().ok_or(ColliderValidationError::MissingSize)?;
+            let (x, y, z) = (size.x(), size.y(), size.z());
+            if !(x > 0.0 && y > 0.0 && z > 0.0) {
+                return Err(ColliderValidationError::NonPositiveSize { x, y, z });
+            }
+        }
+        ColliderType::Sphere => check_radius(collider_type, data.radius())?,
+        ColliderType::Capsule | ColliderType::Cylinder | ColliderType::Cone => {
+            check_radius(collider_type, data.radius())?;
+            check_height(collider_type, data.height())?;
+        }
+        ColliderType::Mesh if data.mesh_path().is_none_or(str::is_empty) => {
+            return Err(ColliderValidationError::MissingMeshPath);
+        }
+        _ => {}
+    }
+    // The engine's C++ serializer writes an empty `mesh_path` for every
+    // collider, so only a non-empty one counts as set.
+    if collider_type != ColliderType::Mesh && data.mesh_path().is_some_and(|p| !p.is_empty()) {
+        return Err(ColliderValidationError::MeshPathOnNonMesh { collider_type });
+    }
+    Ok(())
+}
+
+/// Checks that a trigger leaves `density`, `mass_override` and
+/// `contact_margin` at their defaults, returning the first one it sets.
+/// Non-trigger colliders always pass.
+///
+/// Kept separate from `validate_collider` so that content authored before the
+/// rule still loads; authoring tools and `try_build_collider` apply it.
+pub fn validate_trigger_fields(
+    data: &ColliderComponentData,
+) -> Result<(), ColliderValidationError> {
+    if !data.is_trigger() {
+        return Ok(());
+    }
+    let field = if data.density() != 1.0 {
+        "density"
+    } else if data.mass_override() > 0.0 {
+        "mass_override"
+    } else if data.contact_margin() != 0.01 {
+        "contact_margin"
+    } else {
+        return Ok(());
+    };
+    Err(ColliderValidationError::PhysicalFieldOnTrigger { field })
+}
+
+fn check_radius(collider_type: ColliderType, radius: f32) -> Result<(), ColliderValidationError> {
+    if radius > 0.0 {
+        Ok(())
+    } else {
+        Err(ColliderValidationError::NonPositiveRadius {
+            collider_type,
+            radius,
+        })
+    }
+}
+
+fn check_height(collider_type: ColliderType, height: f32) -> Result<(), ColliderValidationError> {
+    if height > 0.0 {
+        Ok(())
+    } else {
+        Err(ColliderValidationError::NonPositiveHeight {
+            collider_type,
+            height,
+        })
+    }
+}
+
+/// Dimensions below this are reported by `collider_warnings` as degenerate.
+pub const DEGENERATE_EXTENT: f32 = 1e-3;
+
+/// A valid but likely unintended collider setup, reported to content authors.
+///
+/// Unlike `ColliderValidationError` these do not stop a collider from being
+/// used; the physics backend just tends to behave badly with them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColliderWarning {
+    /// A box is thinner than `DEGENERATE_EXTENT` on at least one axis, so it
+    /// has next to no volume and fast bodies pass through it.
+    ThinBox { x: f32, y: f32, z: f32 },
+    /// A round shape's radius is below `DEGENERATE_EXTENT`.
+    TinyRadius {
+        collider_type: ColliderType,
+        radius: f32,
+    },
+    /// A capsule's `height` is less than its diameter, so it is closer to a
+    /// sphere than to a rod; a Sphere collider is cheaper.
+    ShortCapsule { radius: f32, height: f32 },
+    /// `collision_mask` is 0, so the collider collides with nothing.
+    EmptyCollisionMask,
+}
+
+impl core::fmt::Display for ColliderWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::ThinBox { x, y, z } => {
+                write!(f, "Box collider size ({x}, {y}, {z}) is nearly flat")
+            }
+            Self::TinyRadius {
+                collider_type,
+                radius,
+            } => write!(f, "{collider_type:?} collider radius {radius} is tiny"),
+            Self::ShortCapsule { radius, height } => write!(
+                f,
+                "Capsule collider height {height} is less than its diameter {}; \
+                 consider a Sphere",
+                2.0 * radius
+            ),
+            Self::EmptyCollisionMask => {
+                f.write_str("collider has an empty collision_mask and collides with nothing")
+            }
+        }
+    }
+}
+
+/// Lint-style findings for `data`, in a fixed order; empty for a clean
+/// collider.
+///
+/// Only looks at values that pass `validate_collider`, so a zero or negative
+/// dimension is left to that check rather than reported twice.
+pub fn collider_warnings(data: &ColliderComponentData) -> Vec<ColliderWarning> {
+    let mut warnings = Vec::new();
+    let collider_type = data.collider_type();
+    let radius = data.radius();
+    match collider_type {
+        ColliderType::Box if !data.is_2d() => {
+            if let Some(size) = data.size() {
+                let (x, y, z) = (size.x(), size.y(), size.z());
+                let positive = x > 0.0 && y > 0.0 && z > 0.0;
+                if positive && x.min(y).min(z) < DEGENERATE_EXTENT {
+                    warnings.push(ColliderWarning::ThinBox { x, y, z });
+                }
+            }
+        }
+        ColliderType::Sphere
+        | ColliderType::Capsule
+        | ColliderType::Cylinder
+        | ColliderType::Cone
+            if radius > 0.0 && radius < DEGENERATE_EXTENT =>
+        {
+            warnings.push(ColliderWarning::TinyRadius {
+                collider_type,
+                radius,
+            });
+        }
+        _ => {}
+    }
+    if collider_type == ColliderType::Capsule {
+        let height = data.height();
+        if radius > 0.0 && height > 0.0 && height < 2.0 * radius {
+            warnings.push(ColliderWarning::ShortCapsule { radius, height });
+        }
+    }
+    if data.collision_mask() == 0 {
+        warnings.push(ColliderWarning::EmptyCollisionMask);
+    }
+    warnings
+}
+
+/// Whether the collider's `mesh_path` is safe to resolve against an asset root
+/// when the buffer comes from untrusted content.
+///
+/// Rejects absolute paths (including `\\server` and `C:` forms) and any `..`
+/// segment. Both `/` and `\` count as separators whatever the host platform, as
+/// level files are shared between platforms. A collider without a
+/// `mesh_path` has nothing to resolve and counts as safe.
+pub fn mesh_path_is_safe(data: &ColliderComponentData) -> bool {
+    let Some(path) = data.mesh_path() else {
+        return true;
+    };
+    let absolute = path.starts_with(['/', '\\'])
+        || path.as_bytes().get(1) == Some(&b':')
+        || std::path::Path::new(path).has_root();
+    !absolute && !path.split(['/', '\\']).any(|segment| segment == "..")
+}
+
+/// A `collider_type` value that names no variant known to this build.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownColliderType(pub i8);
+
+impl core::fmt::Display for UnknownColliderType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown collider type value {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownColliderType {}
+
+/// Returns the collider's type, or an error carrying the raw value if it is
+/// outside the known variants (e.g. written by a newer tool).
+pub fn collider_type_checked(
+    data: &ColliderComponentData,
+) -> Result<ColliderType, UnknownColliderType> {
+    let collider_type = data.collider_type();
+    match collider_type.variant_name() {
+        Some(_) => Ok(collider_type),
+        None => Err(UnknownColliderType(collider_type.0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common_types_generated::Vec3;
+
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+    use crate::common_types_generated::Vec2;
+    use flatbuffers::FlatBufferBuilder;
+
+    /// Builds a collider like the engine's C++ serializer: `size`, `radius`
+    /// and `height` always written, and empty strings for unused names.
+    fn validate_cpp_shaped(
+        collider_type: ColliderType,
+        size: Vec3,
+        radius: f32,
+        height: f32,
+        mesh_path: &str,
+    ) -> Result<(), ColliderValidationError> {
+        let mut fbb = FlatBufferBuilder::new();
+        let material_name = fbb.create_string("");
+        let mesh_path = fbb.create_string(mesh_path);
+        let offset = ColliderComponentData::create(
+            &mut fbb,
+            &ColliderComponentDataArgs {
+                collider_type,
+                size: Some(&size),
+                radius,
+                height,
+                material_name: Some(material_name),
+                mesh_path: Some(mesh_path),
+                ..Default::default()
+            },
+        );
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        validate_collider(&root_as_collider_component_data(fbb.finished_data()).unwrap())
+    }
+
+    fn unit() -> Vec3 {
+        Vec3::new(1.0, 1.0, 1.0)
+    }
+
+    #[test]
+    fn box_requires_positive_size() {
+        assert_eq!(
+            validate_cpp_shaped(ColliderType::Box, unit(), 0.0, 0.0, ""),
+            Ok(())
+        );
+        assert_eq!(
+            validate_cpp_shaped(ColliderType::Box, Vec3::new(1.0, 0.0, 1.0), 0.0, 0.0, ""),
+            Err(ColliderValidationError::NonPositiveSize {
+                x: 1.0,
+                y: 0.0,
+                z: 1.0
+            })
+        );
+    }
+
+    #[test]
+    fn box_without_size_is_missing_size() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = ColliderComponentData::create(&mut fbb, &Default::default());
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        let data = root_as_collider_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(
+            validate_collider(&data),
+            Err(ColliderValidationError::MissingSize)
+        );
+    }
+
+    #[test]
+    fn sphere_requires_positive_radius() {
+        assert_eq!(
+            validate_cpp_shaped(ColliderType::Sphere, unit(), 0.5, 0.0, ""),
+            Ok(())
+        );
+        assert_eq!(
+            validate_cpp_shaped(ColliderType::Sphere, unit(), 0.0, 0.0, ""),
+            Err(ColliderValidationError::NonPositiveRadius {
+                collider_type: ColliderType::Sphere,
+                radius: 0.0
+            })
+        );
+    }
+
+    #[test]
+    fn capsule_requires_positive_radius_and_height() {
+        assert_eq!(
+            validate_cpp_shaped(ColliderType::Capsule, unit(), 0.5, 2.0, ""),
+            Ok(())
+        );
+        assert_eq!(
+            validate_cpp_shaped(ColliderType::Capsule, unit(), 0.5, -1.0, ""),
+            Err(ColliderValidationError::NonPositiveHeight {
+                collider_type: ColliderType::Capsule,
+                height: -1.0
+            })
+        );
+    }
+
+    #[test]
+    fn mesh_requires_non_empty_path() {
+        assert_eq!(
+            validate_cpp_shaped(ColliderType::Mesh, unit(), 0.0, 0.0, "rock.obj"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_cpp_shaped(ColliderType::Mesh, unit(), 0.0, 0.0, ""),
+            Err(ColliderValidationError::MissingMeshPath)
+        );
+    }
+
+    #[test]
+    fn non_empty_mesh_path_on_non_mesh_is_rejected() {
+        assert_eq!(
+            validate_cpp_shaped(ColliderType::Sphere, unit(), 0.5, 0.0, "rock.obj"),
+            Err(ColliderValidationError::MeshPathOnNonMesh {
+                collider_type: ColliderType::Sphere
+            })
+        );
+    }
+
+    fn validate_component(collider: &ColliderComponent) -> Result<(), ColliderValidationError> {
+        let buf = make_collider_buffer(collider);
+        validate_collider(&root_as_collider_component_data(&buf).unwrap())
+    }
+
+    #[test]
+    fn only_flat_shapes_are_valid_in_2d() {
+        let flat_box = ColliderComponent {
+            is_2d: true,
+            size_2d: Some(Vec2::new(2.0, 3.0)),
+            ..Default::default()
+        };
+        assert_eq!(validate_component(&flat_box), Ok(()));
+        let cone = ColliderComponent {
+            collider_type: ColliderType::Cone,
+            is_2d: true,
+            radius: 1.0,
+            height: 1.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            validate_component(&cone),
+            Err(ColliderValidationError::UnsupportedIn2d {
+                collider_type: ColliderType::Cone
+            })
+        );
+    }
+
+    #[test]
+    fn collider_type_checked_reports_unknown_values() {
+        let cone = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Cone,
+            ..Default::default()
+        });
+        let cone = root_as_collider_component_data(&cone).unwrap();
+        assert_eq!(collider_type_checked(&cone), Ok(ColliderType::Cone));
+
+        let unknown = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType(42),
+            ..Default::default()
+        });
+        let unknown = root_as_collider_component_data(&unknown).unwrap();
+        let err = collider_type_checked(&unknown).unwrap_err();
+        assert_eq!(err, UnknownColliderType(42));
+        assert_eq!(err.to_string(), "unknown collider type value 42");
+    }
+
+    fn mesh_at(path: &str) -> Vec<u8> {
+        make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some(path.into()),
+            ..Default::default()
+        })
+    }
+
+    fn path_is_safe(path: &str) -> bool {
+        mesh_path_is_safe(&root_as_collider_component_data(&mesh_at(path)).unwrap())
+    }
+
+    #[test]
+    fn relative_mesh_paths_are_safe() {
+        assert!(path_is_safe("meshes/rock.obj"));
+        assert!(path_is_safe("a..b/c.obj"));
+    }
+
+    #[test]
+    fn absolute_and_escaping_mesh_paths_are_not() {
+        assert!(!path_is_safe("/etc/passwd"));
+        assert!(!path_is_safe("C:\\x.obj"));
+        assert!(!path_is_safe("meshes/../../secret"));
+        assert!(!path_is_safe("meshes\\..\\secret"));
+    }
+
+    fn warnings(collider: &ColliderComponent) -> Vec<ColliderWarning> {
+        let buf = make_collider_buffer(collider);
+        collider_warnings(&root_as_collider_component_data(&buf).unwrap())
+    }
+
+    #[test]
+    fn well_proportioned_colliders_have_no_warnings() {
+        let capsule = ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 0.5,
+            height: 2.0,
+            ..Default::default()
+        };
+        assert!(warnings(&capsule).is_empty());
+        let cube = ColliderComponent {
+            size: Some(Vec3::new(1.0, 1.0, 1.0)),
+            ..Default::default()
+        };
+        assert!(warnings(&cube).is_empty());
+    }
+
+    #[test]
+    fn suspicious_dimensions_are_warned_about() {
+        let stubby = ColliderComponent {
+            collider_type: ColliderType::Capsule,
+            radius: 1.0,
+            height: 1.5,
+            ..Default::default()
+        };
+        assert_eq!(
+            warnings(&stubby),
+            [ColliderWarning::ShortCapsule {
+                radius: 1.0,
+                height: 1.5
+            }]
+        );
+        let flat = ColliderComponent {
+            size: Some(Vec3::new(1.0, 0.0001, 1.0)),
+            collision_mask: 0,
+            ..Default::default()
+        };
+        assert_eq!(
+            warnings(&flat),
+            [
+                ColliderWarning::ThinBox {
+                    x: 1.0,
+                    y: 0.0001,
+                    z: 1.0
+                },
+                ColliderWarning::EmptyCollisionMask,
+            ]
+        );
+        let speck = ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1e-4,
+            ..Default::default()
+        };
+        assert_eq!(
+            warnings(&speck),
+            [ColliderWarning::TinyRadius {
+                collider_type: ColliderType::Sphere,
+                radius: 1e-4
+            }]
+        );
+    }
+
+    #[test]
+    fn short_capsule_warning_suggests_a_sphere() {
+        let warning = ColliderWarning::ShortCapsule {
+            radius: 1.0,
+            height: 1.0,
+        };
+        assert_eq!(
+            warning.to_string(),
+            "Capsule collider height 1 is less than its diameter 2; consider a Sphere"
+        );
+    }
+
+    #[test]
+    fn triggers_must_not_carry_physical_fields() {
+        let trigger = ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.0,
+            is_trigger: true,
+            ..Default::default()
+        };
+        let check = |collider: &ColliderComponent| {
+            let buf = make_collider_buffer(collider);
+            validate_trigger_fields(&root_as_collider_component_data(&buf).unwrap())
+        };
+        assert_eq!(check(&trigger), Ok(()));
+        let heavy = ColliderComponent {
+            mass_override: 5.0,
+            ..trigger.clone()
+        };
+        assert_eq!(
+            check(&heavy),
+            Err(ColliderValidationError::PhysicalFieldOnTrigger {
+                field: "mass_override"
+            })
+        );
+        let solid = ColliderComponent {
+            is_trigger: false,
+            ..heavy
+        };
+        assert_eq!(check(&solid), Ok(()));
+    }
+
+    #[test]
+    fn untrusted_preset_caps_the_apparent_size() {
+        let small = make_collider_buffer(&ColliderComponent {
+            material_name: Some("stone".into()),
+            ..Default::default()
+        });
+        assert!(root_as_collider_untrusted(&small).is_ok());
+
+        let huge = make_collider_buffer(&ColliderComponent {
+            material_name: Some("x".repeat(100_000)),
+            ..Default::default()
+        });
+        assert!(matches!(
+            root_as_collider_untrusted(&huge),
+            Err(InvalidFlatbuffer::ApparentSizeTooLarge)
+        ));
+        assert!(root_as_collider_component_data(&huge).is_ok());
+    }
+
+    #[test]
+    fn finite_loader_rejects_nan_and_infinity() {
+        let ok = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.0,
+            size: Some(Vec3::new(1.0, 2.0, 3.0)),
+            ..Default::default()
+        });
+        assert!(root_as_collider_finite(&ok).is_ok());
+
+        let nan = make_collider_buffer(&ColliderComponent {
+            radius: f32::NAN,
+            ..Default::default()
+        });
+        assert!(matches!(
+            root_as_collider_finite(&nan),
+            Err(ColliderLoadError::Rejected(
+                ColliderValidationError::NonFinite {
+                    field: "radius",
+                    ..
+                }
+            ))
+        ));
+
+        let inf = make_collider_buffer(&ColliderComponent {
+            size: Some(Vec3::new(1.0, f32::INFINITY, 1.0)),
+            ..Default::default()
+        });
+        assert_eq!(
+            root_as_collider_finite(&inf).unwrap_err(),
+            ColliderLoadError::Rejected(ColliderValidationError::NonFinite {
+                field: "size.y",
+                value: f32::INFINITY,
+            })
+        );
+
+        assert!(matches!(
+            root_as_collider_finite(&[1, 2]),
+            Err(ColliderLoadError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+// Per-shape views of a collider, holding only the fields each shape uses.
+
+use crate::collider_component_generated::*;
+use crate::common_types_generated::*;
+
+/// A collider's shape-relevant fields, by `collider_type`.
+///
+/// Matching on this instead of reading fields off `ColliderComponentData`
+/// means a sphere has no `size` to misuse and a new shape shows up as a
+/// non-exhaustive `match`. Shape-independent fields (layers, material, 2D,
+/// mass) are still read from the table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColliderView<'a> {
+    Box {
+        size: Option<Vec3>,
+        is_trigger: bool,
+        center: Option<Vec3>,
+    },
+    Sphere {
+        radius: f32,
+        is_trigger: bool,
+    },
+    Capsule {
+        radius: f32,
+        height: f32,
+        axis: CapsuleAxis,
+        is_trigger: bool,
+    },
+    Mesh {
+        path: Option<&'a str>,
+        convex: bool,
+        is_trigger: bool,
+    },
+    Cylinder {
+        radius: f32,
+        height: f32,
+        is_trigger: bool,
+    },
+    Cone {
+        radius: f32,
+        height: f32,
+        is_trigger: bool,
+    },
+}
+
+/// Returns the view for `data`'s `collider_type`, or `None` for a type this
+/// build does not know.
+pub fn collider_view<'a>(data: &ColliderComponentData<'a>) -> Option<ColliderView<'a>> {
+    let is_trigger = data.is_trigger();
+    let view = match data.collider_type() {
+        ColliderType::Box => ColliderView::Box {
+            size: data.size().copied(),
+            is_trigger,
+            center: data.center().copied(),
+        },
+        ColliderType::Sphere => ColliderView::Sphere {
+            radius: data.radius(),
+            is_trigger,
+        },
+        ColliderType::Capsule => ColliderView::Capsule {
+            radius: data.radius(),
+            height: data.height(),
+            axis: data.capsule_axis(),
+            is_trigger,
+        },
+        ColliderType::Mesh => ColliderView::Mesh {
+            path: data.mesh_path(),
+            convex: data.mesh_is_convex(),
+            is_trigger,
+        },
+        ColliderType::Cylinder => ColliderView::Cylinder {
+            radius: data.radius(),
+            height: data.height(),
+            is_trigger,
+        },
+        ColliderType::Cone => ColliderView::Cone {
+            radius: data.radius(),
+            height: data.height(),
+            is_trigger,
+        },
+        _ => return None,
+    };
+    Some(view)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+
+    fn view_of(collider: &ColliderComponent, check: impl FnOnce(Option<ColliderView>)) {
+        let buf = make_collider_buffer(collider);
+        check(collider_view(
+            &root_as_collider_component_data(&buf).unwrap(),
+        ));
+    }
+
+    #[test]
+    fn each_shape_gets_its_own_view() {
+        view_of(
+            &ColliderComponent {
+                size: Some(Vec3::new(1.0, 1.0, 1.0)),
+                radius: 9.0,
+                ..Default::default()
+            },
+            |view| {
+                assert_eq!(
+                    view,
+                    Some(ColliderView::Box {
+                        size: Some(Vec3::new(1.0, 1.0, 1.0)),
+                        is_trigger: false,
+                        center: None,
+                    })
+                )
+            },
+        );
+        view_of(
+            &ColliderComponent {
+                collider_type: ColliderType::Sphere,
+                radius: 1.0,
+                is_trigger: true,
+                ..Default::default()
+            },
+            |view| {
+                assert_eq!(
+                    view,
+                    Some(ColliderView::Sphere {
+                        radius: 1.0,
+                        is_trigger: true,
+                    })
+                )
+            },
+        );
+        view_of(
+            &ColliderComponent {
+                collider_type: ColliderType::Capsule,
+                radius: 1.0,
+                height: 2.0,
+                ..Default::default()
+            },
+            |view| {
+                assert_eq!(
+                    view,
+                    Some(ColliderView::Capsule {
+                        radius: 1.0,
+                        height: 2.0,
+                        axis: CapsuleAxis::Y,
+                        is_trigger: false,
+                    })
+                )
+            },
+        );
+        view_of(
+            &ColliderComponent {
+                collider_type: ColliderType::Mesh,
+                mesh_path: Some("rock.obj".into()),
+                ..Default::default()
+            },
+            |view| {
+                assert_eq!(
+                    view,
+                    Some(ColliderView::Mesh {
+                        path: Some("rock.obj"),
+                        convex: false,
+                        is_trigger: false,
+                    })
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn unknown_type_has_no_view() {
+        view_of(
+            &ColliderComponent {
+                collider_type: ColliderType(9),
+                ..Default::default()
+            },
+            |view| assert_eq!(view, None),
+        );
+    }
+}
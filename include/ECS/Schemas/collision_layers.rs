@@ -0,0 +1,119 @@
+// Designer-facing names for the bits of the collider `layer` field.
+
+use std::collections::HashMap;
+
+use crate::collider_component_generated::*;
+
+/// Maps collision layer names such as "Player" or "Enemy" to their layer bits.
+///
+/// The game fills this in at startup; colliders only store the numeric
+/// `layer`/`collision_mask` bits. Bits are single-bit masks (`1 << n`), the
+/// same values that are or-ed together in those fields.
+#[derive(Clone, Debug, Default)]
+pub struct CollisionLayerTable {
+    bits: HashMap<String, u32>,
+    names: [Option<String>; 32],
+}
+
+impl CollisionLayerTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Names the layer `bit`, replacing any previous name for that bit and any
+    /// previous bit for that name.
+    ///
+    /// # Panics
+    ///
+    /// If `bit` is not a single-bit mask.
+    pub fn define(&mut self, name: &str, bit: u32) {
+        assert!(
+            bit.is_power_of_two(),
+            "layer bit {bit:#x} is not a single bit"
+        );
+        if let Some(previous) = self.bits.insert(name.to_owned(), bit) {
+            self.names[previous.trailing_zeros() as usize] = None;
+        }
+        let slot = &mut self.names[bit.trailing_zeros() as usize];
+        if let Some(old) = slot.replace(name.to_owned()) {
+            if old != name {
+                self.bits.remove(&old);
+            }
+        }
+    }
+
+    pub fn layer_name_to_bit(&self, name: &str) -> Option<u32> {
+        self.bits.get(name).copied()
+    }
+
+    /// Returns the name of a single-bit mask, or `None` if it is unnamed or not a single bit.
+    pub fn layer_bit_to_name(&self, bit: u32) -> Option<&str> {
+        if !bit.is_power_of_two() {
+            return None;
+        }
+        self.names[bit.trailing_zeros() as usize].as_deref()
+    }
+}
+
+/// Names of the layers set in the collider's `layer` bits, lowest bit first.
+/// Bits without a name in `table` are skipped.
+pub fn collider_layer_names<'t>(
+    data: &ColliderComponentData,
+    table: &'t CollisionLayerTable,
+) -> Vec<&'t str> {
+    let layer = data.layer();
+    (0..32)
+        .map(|i| 1u32 << i)
+        .filter(|bit| layer & bit != 0)
+        .filter_map(|bit| table.layer_bit_to_name(bit))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+
+    #[test]
+    fn names_map_both_ways() {
+        let mut table = CollisionLayerTable::new();
+        table.define("Player", 1);
+        table.define("Enemy", 4);
+        assert_eq!(table.layer_name_to_bit("Enemy"), Some(4));
+        assert_eq!(table.layer_bit_to_name(1), Some("Player"));
+        assert_eq!(table.layer_bit_to_name(2), None);
+        assert_eq!(table.layer_bit_to_name(5), None);
+    }
+
+    #[test]
+    fn redefining_replaces_the_old_mapping() {
+        let mut table = CollisionLayerTable::new();
+        table.define("Player", 1);
+        table.define("Player", 2);
+        assert_eq!(table.layer_bit_to_name(1), None);
+        assert_eq!(table.layer_name_to_bit("Player"), Some(2));
+        table.define("Boss", 2);
+        assert_eq!(table.layer_name_to_bit("Player"), None);
+        assert_eq!(table.layer_bit_to_name(2), Some("Boss"));
+    }
+
+    #[test]
+    fn collider_layer_names_skips_unnamed_bits() {
+        let mut table = CollisionLayerTable::new();
+        table.define("Player", 1);
+        table.define("Enemy", 4);
+        let buf = make_collider_buffer(&ColliderComponent {
+            layer: 0b111,
+            ..Default::default()
+        });
+        let collider = root_as_collider_component_data(&buf).unwrap();
+        assert_eq!(collider_layer_names(&collider, &table), ["Player", "Enemy"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a single bit")]
+    fn define_rejects_multi_bit_masks() {
+        CollisionLayerTable::new().define("Both", 0b11);
+    }
+}
@@ -0,0 +1,256 @@
+// Hand-written extensions for the structs in `common_types_generated`.
+// The glam, serde and bytemuck impls sit behind features of the same names;
+// README.md lists the manifest entries.
+
+use crate::common_types_generated::*;
+
+impl Quat {
+    /// Whether the quaternion's length is within `eps` of 1.
+    pub fn is_normalized(&self, eps: f32) -> bool {
+        (self.length_squared().sqrt() - 1.0).abs() <= eps
+    }
+
+    /// This rotation scaled to unit length. A zero quaternion has no direction
+    /// and normalizes to the identity.
+    pub fn normalized(&self) -> Quat {
+        let len = self.length_squared().sqrt();
+        if len == 0.0 || !len.is_finite() {
+            return Quat::new(0.0, 0.0, 0.0, 1.0);
+        }
+        Quat::new(
+            self.x() / len,
+            self.y() / len,
+            self.z() / len,
+            self.w() / len,
+        )
+    }
+
+    fn length_squared(&self) -> f32 {
+        self.x() * self.x() + self.y() * self.y() + self.z() * self.z() + self.w() * self.w()
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+    use crate::common_types_generated::*;
+
+    impl From<glam::Vec3> for Vec3 {
+        #[inline]
+        fn from(v: glam::Vec3) -> Self {
+            Vec3::new(v.x, v.y, v.z)
+        }
+    }
+
+    /// Takes a reference so it applies directly to the `&'a Vec3` returned by
+    /// table accessors such as `ColliderComponentData::size`.
+    impl From<&Vec3> for glam::Vec3 {
+        #[inline]
+        fn from(v: &Vec3) -> Self {
+            glam::Vec3::new(v.x(), v.y(), v.z())
+        }
+    }
+
+    impl From<glam::Vec2> for Vec2 {
+        #[inline]
+        fn from(v: glam::Vec2) -> Self {
+            Vec2::new(v.x, v.y)
+        }
+    }
+
+    impl From<&Vec2> for glam::Vec2 {
+        #[inline]
+        fn from(v: &Vec2) -> Self {
+            glam::Vec2::new(v.x(), v.y())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use crate::common_types_generated::*;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Field-wise stand-in for `Vec3`, whose generated form is a raw byte array.
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Vec3")]
+    struct Vec3Repr {
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+
+    impl Serialize for Vec3 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Vec3Repr {
+                x: self.x(),
+                y: self.y(),
+                z: self.z(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Vec3 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let v = Vec3Repr::deserialize(deserializer)?;
+            Ok(Vec3::new(v.x, v.y, v.z))
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Vec2")]
+    struct Vec2Repr {
+        x: f32,
+        y: f32,
+    }
+
+    impl Serialize for Vec2 {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Vec2Repr {
+                x: self.x(),
+                y: self.y(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Vec2 {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let v = Vec2Repr::deserialize(deserializer)?;
+            Ok(Vec2::new(v.x, v.y))
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Color")]
+    struct ColorRepr {
+        r: u8,
+        g: u8,
+        b: u8,
+        a: u8,
+    }
+
+    impl Serialize for Color {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            ColorRepr {
+                r: self.r(),
+                g: self.g(),
+                b: self.b(),
+                a: self.a(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Color {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let c = ColorRepr::deserialize(deserializer)?;
+            Ok(Color::new(c.r, c.g, c.b, c.a))
+        }
+    }
+}
+
+/// `bytemuck` support, for uploading slices of common types to the GPU and
+/// bulk copies without per-element conversion.
+///
+/// The generated structs are `#[repr(transparent)]` wrappers around a byte
+/// array holding the fields in FlatBuffers' little-endian layout. Every bit
+/// pattern is a valid byte array and there is no padding, so `Zeroable` and
+/// `Pod` hold. The byte contents are the same on every platform; on
+/// little-endian targets (all our GPU targets) they are also the native
+/// `f32` layout that shaders expect. Alignment is 1, so casts from unaligned
+/// byte slices succeed.
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls {
+    use crate::common_types_generated::*;
+
+    use core::mem::{align_of, size_of};
+
+    const _: () = {
+        assert!(size_of::<Vec2>() == 8 && align_of::<Vec2>() == 1);
+        assert!(size_of::<Vec3>() == 12 && align_of::<Vec3>() == 1);
+        assert!(size_of::<Vec4>() == 16 && align_of::<Vec4>() == 1);
+        assert!(size_of::<Quat>() == 16 && align_of::<Quat>() == 1);
+        assert!(size_of::<Color>() == 4 && align_of::<Color>() == 1);
+    };
+
+    // Safety: see the module docs; each type is a transparent byte array.
+    unsafe impl bytemuck::Zeroable for Vec2 {}
+    unsafe impl bytemuck::Pod for Vec2 {}
+    unsafe impl bytemuck::Zeroable for Vec3 {}
+    unsafe impl bytemuck::Pod for Vec3 {}
+    unsafe impl bytemuck::Zeroable for Vec4 {}
+    unsafe impl bytemuck::Pod for Vec4 {}
+    unsafe impl bytemuck::Zeroable for Quat {}
+    unsafe impl bytemuck::Pod for Quat {}
+    unsafe impl bytemuck::Zeroable for Color {}
+    unsafe impl bytemuck::Pod for Color {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn collider_size_round_trips_through_glam() {
+        use crate::collider_builders::make_collider_buffer;
+        use crate::collider_component::ColliderComponent;
+        use crate::collider_component_generated::root_as_collider_component_data;
+
+        let size = glam::Vec3::new(1.0, 2.0, 3.0);
+        let buf = make_collider_buffer(&ColliderComponent {
+            size: Some(size.into()),
+            ..Default::default()
+        });
+        let data = root_as_collider_component_data(&buf).unwrap();
+        let back: glam::Vec3 = data.size().unwrap().into();
+        assert_eq!(back, size);
+        let flat: glam::Vec2 = (&Vec2::new(4.0, 5.0)).into();
+        assert_eq!(Vec2::from(flat), Vec2::new(4.0, 5.0));
+    }
+
+    fn identity() -> Quat {
+        Quat::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    #[test]
+    fn normalized_scales_to_unit_length() {
+        let doubled = Quat::new(0.0, 0.0, 0.0, 2.0);
+        assert!(!doubled.is_normalized(1e-5));
+        assert_eq!(doubled.normalized(), identity());
+        let drifted = Quat::new(1.0, 2.0, 3.0, 4.0).normalized();
+        assert!(drifted.is_normalized(1e-5));
+        assert!((drifted.w() - 4.0 / 30f32.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn identity_is_already_normalized() {
+        assert!(identity().is_normalized(0.0));
+        assert_eq!(identity().normalized(), identity());
+    }
+
+    #[test]
+    fn degenerate_quaternions_normalize_to_identity() {
+        assert_eq!(Quat::new(0.0, 0.0, 0.0, 0.0).normalized(), identity());
+        assert_eq!(Quat::new(f32::NAN, 0.0, 0.0, 1.0).normalized(), identity());
+        assert_eq!(
+            Quat::new(f32::INFINITY, 0.0, 0.0, 1.0).normalized(),
+            identity()
+        );
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn vec3_slices_cast_to_packed_little_endian_floats() {
+        let points = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(-1.0, 0.5, 9.0)];
+        let bytes: &[u8] = bytemuck::cast_slice(&points);
+        assert_eq!(bytes.len(), 24);
+        assert_eq!(&bytes[0..4], &1.0f32.to_le_bytes());
+        let back: &[Vec3] = bytemuck::cast_slice(&bytes[12..]);
+        assert_eq!(back, &points[1..]);
+        let zeroed: Color = bytemuck::Zeroable::zeroed();
+        assert_eq!(zeroed, Color::new(0, 0, 0, 0));
+    }
+}
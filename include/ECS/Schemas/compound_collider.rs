@@ -0,0 +1,88 @@
+// Helpers for reading `CompoundColliderData` tables.
+
+use crate::collider_component_generated::*;
+use crate::common_types_generated::*;
+use crate::compound_collider_generated::*;
+
+/// Number of child shapes in the compound; a missing `children` vector counts as none.
+pub fn compound_child_count(data: &CompoundColliderData) -> usize {
+    data.children().map_or(0, |children| children.len())
+}
+
+/// Iterates the children together with their local positions.
+///
+/// Children past the end of `child_positions` (or all of them, if it is absent)
+/// are placed at the origin.
+pub fn compound_children<'a>(
+    data: &CompoundColliderData<'a>,
+) -> impl Iterator<Item = (ColliderComponentData<'a>, Vec3)> + 'a {
+    let positions = data.child_positions();
+    data.children()
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(move |(i, child)| {
+            let position = positions
+                .filter(|p| i < p.len())
+                .map_or_else(Vec3::default, |p| *p.get(i));
+            (child, position)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_component::ColliderComponent;
+    use flatbuffers::FlatBufferBuilder;
+
+    #[test]
+    fn children_past_the_positions_sit_at_the_origin() {
+        let mut fbb = FlatBufferBuilder::new();
+        let cube = ColliderComponent {
+            size: Some(Vec3::new(1.0, 1.0, 1.0)),
+            ..Default::default()
+        }
+        .serialize(&mut fbb);
+        let sphere = ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 0.5,
+            ..Default::default()
+        };
+        let first_sphere = sphere.serialize(&mut fbb);
+        let second_sphere = sphere.serialize(&mut fbb);
+        let children = fbb.create_vector(&[cube, first_sphere, second_sphere]);
+        let positions = fbb.create_vector(&[Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0)]);
+        let offset = CompoundColliderData::create(
+            &mut fbb,
+            &CompoundColliderDataArgs {
+                children: Some(children),
+                child_positions: Some(positions),
+            },
+        );
+        finish_compound_collider_data_buffer(&mut fbb, offset);
+        let compound = root_as_compound_collider_data(fbb.finished_data()).unwrap();
+
+        assert_eq!(compound_child_count(&compound), 3);
+        let children: Vec<_> = compound_children(&compound)
+            .map(|(child, position)| (child.collider_type(), position))
+            .collect();
+        assert_eq!(
+            children,
+            [
+                (ColliderType::Box, Vec3::new(0.0, 0.0, 0.0)),
+                (ColliderType::Sphere, Vec3::new(1.0, 0.0, 0.0)),
+                (ColliderType::Sphere, Vec3::new(0.0, 0.0, 0.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_children_count_as_none() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = CompoundColliderData::create(&mut fbb, &Default::default());
+        finish_compound_collider_data_buffer(&mut fbb, offset);
+        let compound = root_as_compound_collider_data(fbb.finished_data()).unwrap();
+        assert_eq!(compound_child_count(&compound), 0);
+        assert_eq!(compound_children(&compound).count(), 0);
+    }
+}
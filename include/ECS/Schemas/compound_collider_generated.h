@@ -0,0 +1,139 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_COMPOUNDCOLLIDER_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_COMPOUNDCOLLIDER_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+#include "collider_component_generated.h"
+
+namespace PixelCraft {
+namespace ECS {
+
+struct CompoundColliderData;
+struct CompoundColliderDataBuilder;
+
+struct CompoundColliderData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef CompoundColliderDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_CHILDREN = 4,
+    VT_CHILD_POSITIONS = 6
+  };
+  const ::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>> *children() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>> *>(VT_CHILDREN);
+  }
+  /// Local position of each child relative to the entity, parallel to
+  /// `children`. A child without an entry sits at the entity origin.
+  const ::flatbuffers::Vector<const PixelCraft::ECS::Vec3 *> *child_positions() const {
+    return GetPointer<const ::flatbuffers::Vector<const PixelCraft::ECS::Vec3 *> *>(VT_CHILD_POSITIONS);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_CHILDREN) &&
+           verifier.VerifyVector(children()) &&
+           verifier.VerifyVectorOfTables(children()) &&
+           VerifyOffset(verifier, VT_CHILD_POSITIONS) &&
+           verifier.VerifyVector(child_positions()) &&
+           verifier.EndTable();
+  }
+};
+
+struct CompoundColliderDataBuilder {
+  typedef CompoundColliderData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_children(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>>> children) {
+    fbb_.AddOffset(CompoundColliderData::VT_CHILDREN, children);
+  }
+  void add_child_positions(::flatbuffers::Offset<::flatbuffers::Vector<const PixelCraft::ECS::Vec3 *>> child_positions) {
+    fbb_.AddOffset(CompoundColliderData::VT_CHILD_POSITIONS, child_positions);
+  }
+  explicit CompoundColliderDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<CompoundColliderData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<CompoundColliderData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<CompoundColliderData> CreateCompoundColliderData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>>> children = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<const PixelCraft::ECS::Vec3 *>> child_positions = 0) {
+  CompoundColliderDataBuilder builder_(_fbb);
+  builder_.add_child_positions(child_positions);
+  builder_.add_children(children);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<CompoundColliderData> CreateCompoundColliderDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const std::vector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>> *children = nullptr,
+    const std::vector<PixelCraft::ECS::Vec3> *child_positions = nullptr) {
+  auto children__ = children ? _fbb.CreateVector<::flatbuffers::Offset<PixelCraft::ECS::ColliderComponentData>>(*children) : 0;
+  auto child_positions__ = child_positions ? _fbb.CreateVectorOfStructs<PixelCraft::ECS::Vec3>(*child_positions) : 0;
+  return PixelCraft::ECS::CreateCompoundColliderData(
+      _fbb,
+      children__,
+      child_positions__);
+}
+
+inline const PixelCraft::ECS::CompoundColliderData *GetCompoundColliderData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::CompoundColliderData>(buf);
+}
+
+inline const PixelCraft::ECS::CompoundColliderData *GetSizePrefixedCompoundColliderData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::CompoundColliderData>(buf);
+}
+
+inline const char *CompoundColliderDataIdentifier() {
+  return "CCMP";
+}
+
+inline bool CompoundColliderDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, CompoundColliderDataIdentifier());
+}
+
+inline bool SizePrefixedCompoundColliderDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, CompoundColliderDataIdentifier(), true);
+}
+
+inline bool VerifyCompoundColliderDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::CompoundColliderData>(CompoundColliderDataIdentifier());
+}
+
+inline bool VerifySizePrefixedCompoundColliderDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::CompoundColliderData>(CompoundColliderDataIdentifier());
+}
+
+inline void FinishCompoundColliderDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::CompoundColliderData> root) {
+  fbb.Finish(root, CompoundColliderDataIdentifier());
+}
+
+inline void FinishSizePrefixedCompoundColliderDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::CompoundColliderData> root) {
+  fbb.FinishSizePrefixed(root, CompoundColliderDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_COMPOUNDCOLLIDER_PIXELCRAFT_ECS_H_
@@ -0,0 +1,236 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use crate::common_types_generated::*;
+use crate::collider_component_generated::*;
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use crate::common_types_generated::*;
+  use crate::collider_component_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use crate::common_types_generated::*;
+  use crate::collider_component_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum CompoundColliderDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct CompoundColliderData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for CompoundColliderData<'a> {
+  type Inner = CompoundColliderData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> CompoundColliderData<'a> {
+  pub const VT_CHILDREN: flatbuffers::VOffsetT = 4;
+  pub const VT_CHILD_POSITIONS: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    CompoundColliderData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args CompoundColliderDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<CompoundColliderData<'bldr>> {
+    let mut builder = CompoundColliderDataBuilder::new(_fbb);
+    if let Some(x) = args.child_positions { builder.add_child_positions(x); }
+    if let Some(x) = args.children { builder.add_children(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn children(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderComponentData<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderComponentData>>>>(CompoundColliderData::VT_CHILDREN, None)}
+  }
+  /// Local position of each child relative to the entity, parallel to
+  /// `children`. A child without an entry sits at the entity origin.
+  #[inline]
+  pub fn child_positions(&self) -> Option<flatbuffers::Vector<'a, Vec3>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Vec3>>>(CompoundColliderData::VT_CHILD_POSITIONS, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for CompoundColliderData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ColliderComponentData>>>>("children", Self::VT_CHILDREN, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Vec3>>>("child_positions", Self::VT_CHILD_POSITIONS, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct CompoundColliderDataArgs<'a> {
+    pub children: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ColliderComponentData<'a>>>>>,
+    pub child_positions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Vec3>>>,
+}
+impl<'a> Default for CompoundColliderDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    CompoundColliderDataArgs {
+      children: None,
+      child_positions: None,
+    }
+  }
+}
+
+pub struct CompoundColliderDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CompoundColliderDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_children(&mut self, children: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ColliderComponentData<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompoundColliderData::VT_CHILDREN, children);
+  }
+  #[inline]
+  pub fn add_child_positions(&mut self, child_positions: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Vec3>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(CompoundColliderData::VT_CHILD_POSITIONS, child_positions);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CompoundColliderDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    CompoundColliderDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<CompoundColliderData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for CompoundColliderData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("CompoundColliderData");
+      ds.field("children", &self.children());
+      ds.field("child_positions", &self.child_positions());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `CompoundColliderData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_compound_collider_data_unchecked`.
+pub fn root_as_compound_collider_data(buf: &[u8]) -> Result<CompoundColliderData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<CompoundColliderData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `CompoundColliderData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_compound_collider_data_unchecked`.
+pub fn size_prefixed_root_as_compound_collider_data(buf: &[u8]) -> Result<CompoundColliderData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<CompoundColliderData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `CompoundColliderData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_compound_collider_data_unchecked`.
+pub fn root_as_compound_collider_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CompoundColliderData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<CompoundColliderData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `CompoundColliderData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_compound_collider_data_unchecked`.
+pub fn size_prefixed_root_as_compound_collider_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<CompoundColliderData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<CompoundColliderData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a CompoundColliderData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `CompoundColliderData`.
+pub unsafe fn root_as_compound_collider_data_unchecked(buf: &[u8]) -> CompoundColliderData {
+  flatbuffers::root_unchecked::<CompoundColliderData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed CompoundColliderData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `CompoundColliderData`.
+pub unsafe fn size_prefixed_root_as_compound_collider_data_unchecked(buf: &[u8]) -> CompoundColliderData {
+  flatbuffers::size_prefixed_root_unchecked::<CompoundColliderData>(buf)
+}
+pub const COMPOUND_COLLIDER_DATA_IDENTIFIER: &str = "CCMP";
+
+#[inline]
+pub fn compound_collider_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, COMPOUND_COLLIDER_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn compound_collider_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, COMPOUND_COLLIDER_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_compound_collider_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<CompoundColliderData<'a>>) {
+  fbb.finish(root, Some(COMPOUND_COLLIDER_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_compound_collider_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<CompoundColliderData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(COMPOUND_COLLIDER_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
@@ -0,0 +1,681 @@
+// Common interface over component schema root tables, for generic ECS storage.
+
+use std::fmt;
+
+use crate::animation_component_generated::*;
+use crate::audio_source_component_generated::*;
+use crate::camera_component_generated::*;
+use crate::collider_component_array_generated::*;
+use crate::collider_component_generated::*;
+use crate::collider_pool_scene_generated::*;
+use crate::collider_scene_generated::*;
+use crate::compound_collider_generated::*;
+use crate::light_component_generated::*;
+use crate::mesh_renderer_component_generated::*;
+use crate::particle_emitter_component_generated::*;
+use crate::particle_system_component_generated::*;
+use crate::physics_material_generated::*;
+use crate::rigid_body_component_generated::*;
+use crate::script_component_generated::*;
+use crate::sprite_component_generated::*;
+use crate::text_component_generated::*;
+use crate::tilemap_component_generated::*;
+use crate::transform_component_generated::*;
+
+use flatbuffers::InvalidFlatbuffer;
+
+/// A component table that is stored as its own identified FlatBuffer.
+///
+/// Lets generic storage code load any component by type parameter instead of
+/// calling each schema's `root_as_*` function by name.
+pub trait EcsComponent<'a>: Sized {
+    /// The schema's four-character `file_identifier`.
+    const IDENTIFIER: &'static str;
+
+    /// Verifies `buf` and returns the root table. Like the generated
+    /// `root_as_*` functions this does not check the identifier.
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer>;
+
+    /// Whether `buf` carries `IDENTIFIER`.
+    fn buffer_has_identifier(buf: &[u8]) -> bool {
+        flatbuffers::buffer_has_identifier(buf, Self::IDENTIFIER, false)
+    }
+}
+
+impl<'a> EcsComponent<'a> for ColliderComponentData<'a> {
+    const IDENTIFIER: &'static str = COLLIDER_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_collider_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for AnimationComponentData<'a> {
+    const IDENTIFIER: &'static str = ANIMATION_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_animation_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for AudioSourceComponentData<'a> {
+    const IDENTIFIER: &'static str = AUDIO_SOURCE_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_audio_source_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for CameraComponentData<'a> {
+    const IDENTIFIER: &'static str = CAMERA_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_camera_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for LightComponentData<'a> {
+    const IDENTIFIER: &'static str = LIGHT_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_light_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for ParticleEmitterComponentData<'a> {
+    const IDENTIFIER: &'static str = PARTICLE_EMITTER_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_particle_emitter_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for TextComponentData<'a> {
+    const IDENTIFIER: &'static str = TEXT_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_text_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for ScriptComponentData<'a> {
+    const IDENTIFIER: &'static str = SCRIPT_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_script_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for TilemapComponentData<'a> {
+    const IDENTIFIER: &'static str = TILEMAP_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_tilemap_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for ColliderComponentArrayData<'a> {
+    const IDENTIFIER: &'static str = COLLIDER_COMPONENT_ARRAY_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_collider_component_array_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for ColliderPoolSceneData<'a> {
+    const IDENTIFIER: &'static str = COLLIDER_POOL_SCENE_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_collider_pool_scene_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for ColliderSceneData<'a> {
+    const IDENTIFIER: &'static str = COLLIDER_SCENE_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_collider_scene_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for CompoundColliderData<'a> {
+    const IDENTIFIER: &'static str = COMPOUND_COLLIDER_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_compound_collider_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for MeshRendererComponentData<'a> {
+    const IDENTIFIER: &'static str = MESH_RENDERER_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_mesh_renderer_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for ParticleSystemComponentData<'a> {
+    const IDENTIFIER: &'static str = PARTICLE_SYSTEM_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_particle_system_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for PhysicsMaterialData<'a> {
+    const IDENTIFIER: &'static str = PHYSICS_MATERIAL_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_physics_material_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for RigidBodyComponentData<'a> {
+    const IDENTIFIER: &'static str = RIGID_BODY_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_rigid_body_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for SpriteComponentData<'a> {
+    const IDENTIFIER: &'static str = SPRITE_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_sprite_component_data(buf)
+    }
+}
+
+impl<'a> EcsComponent<'a> for TransformComponentData<'a> {
+    const IDENTIFIER: &'static str = TRANSFORM_COMPONENT_DATA_IDENTIFIER;
+
+    fn verify(buf: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+        root_as_transform_component_data(buf)
+    }
+}
+
+/// Error from `load_component`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadComponentError {
+    /// The buffer is for a different component (or has no identifier).
+    WrongIdentifier {
+        expected: &'static str,
+    },
+    Invalid(InvalidFlatbuffer),
+}
+
+impl fmt::Display for LoadComponentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongIdentifier { expected } => {
+                write!(f, "buffer does not have the `{expected}` identifier")
+            }
+            Self::Invalid(err) => write!(f, "invalid component buffer: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadComponentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::WrongIdentifier { .. } => None,
+            Self::Invalid(err) => Some(err),
+        }
+    }
+}
+
+/// Checks that `buf` is a `T` buffer by its identifier, then verifies it.
+pub fn load_component<'a, T: EcsComponent<'a>>(buf: &'a [u8]) -> Result<T, LoadComponentError> {
+    if !T::buffer_has_identifier(buf) {
+        return Err(LoadComponentError::WrongIdentifier {
+            expected: T::IDENTIFIER,
+        });
+    }
+    T::verify(buf).map_err(LoadComponentError::Invalid)
+}
+
+/// The kind of data in an identified FlatBuffer, by its `file_identifier`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ComponentKind {
+    Animation,
+    AudioSource,
+    Camera,
+    Collider,
+    ColliderArray,
+    ColliderPoolScene,
+    ColliderScene,
+    CompoundCollider,
+    Light,
+    MeshRenderer,
+    ParticleEmitter,
+    ParticleSystem,
+    PhysicsMaterial,
+    RigidBody,
+    Script,
+    Sprite,
+    Text,
+    Tilemap,
+    Transform,
+}
+
+impl ComponentKind {
+    pub const ALL: [ComponentKind; 19] = [
+        Self::Animation,
+        Self::AudioSource,
+        Self::Camera,
+        Self::Collider,
+        Self::ColliderArray,
+        Self::ColliderPoolScene,
+        Self::ColliderScene,
+        Self::CompoundCollider,
+        Self::Light,
+        Self::MeshRenderer,
+        Self::ParticleEmitter,
+        Self::ParticleSystem,
+        Self::PhysicsMaterial,
+        Self::RigidBody,
+        Self::Script,
+        Self::Sprite,
+        Self::Text,
+        Self::Tilemap,
+        Self::Transform,
+    ];
+
+    /// The schema's four-character `file_identifier`.
+    pub fn identifier(self) -> &'static str {
+        match self {
+            Self::Animation => ANIMATION_COMPONENT_DATA_IDENTIFIER,
+            Self::AudioSource => AUDIO_SOURCE_COMPONENT_DATA_IDENTIFIER,
+            Self::Camera => CAMERA_COMPONENT_DATA_IDENTIFIER,
+            Self::Collider => COLLIDER_COMPONENT_DATA_IDENTIFIER,
+            Self::ColliderArray => COLLIDER_COMPONENT_ARRAY_DATA_IDENTIFIER,
+            Self::ColliderPoolScene => COLLIDER_POOL_SCENE_DATA_IDENTIFIER,
+            Self::ColliderScene => COLLIDER_SCENE_DATA_IDENTIFIER,
+            Self::CompoundCollider => COMPOUND_COLLIDER_DATA_IDENTIFIER,
+            Self::Light => LIGHT_COMPONENT_DATA_IDENTIFIER,
+            Self::MeshRenderer => MESH_RENDERER_COMPONENT_DATA_IDENTIFIER,
+            Self::ParticleEmitter => PARTICLE_EMITTER_COMPONENT_DATA_IDENTIFIER,
+            Self::ParticleSystem => PARTICLE_SYSTEM_COMPONENT_DATA_IDENTIFIER,
+            Self::PhysicsMaterial => PHYSICS_MATERIAL_DATA_IDENTIFIER,
+            Self::RigidBody => RIGID_BODY_COMPONENT_DATA_IDENTIFIER,
+            Self::Script => SCRIPT_COMPONENT_DATA_IDENTIFIER,
+            Self::Sprite => SPRITE_COMPONENT_DATA_IDENTIFIER,
+            Self::Text => TEXT_COMPONENT_DATA_IDENTIFIER,
+            Self::Tilemap => TILEMAP_COMPONENT_DATA_IDENTIFIER,
+            Self::Transform => TRANSFORM_COMPONENT_DATA_IDENTIFIER,
+        }
+    }
+}
+
+/// Identifies a raw buffer by the file identifier at bytes 4..8, for
+/// dispatching blobs from a mixed asset bundle to the right reader.
+///
+/// Returns `None` for buffers too short to hold an identifier and for unknown
+/// identifiers. Only the identifier is checked; verify the buffer with the
+/// matching reader before using it. Size-prefixed buffers are not recognized.
+pub fn identify_component(buf: &[u8]) -> Option<ComponentKind> {
+    let identifier = buf.get(4..8)?;
+    ComponentKind::ALL
+        .into_iter()
+        .find(|kind| kind.identifier().as_bytes() == identifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common_types_generated::{Color, Vec3, Vec4};
+
+    use flatbuffers::FlatBufferBuilder;
+
+    /// Writes an empty `$table` with its identifier, then checks that
+    /// `identify_component` names `$kind` and `load_component` accepts it.
+    macro_rules! check_loads {
+        ($($kind:ident => $table:ident, $args:ident;)*) => {
+            let mut seen = Vec::new();
+            $(
+                let mut fbb = FlatBufferBuilder::new();
+                let offset = $table::create(&mut fbb, &$args::default());
+                fbb.finish(offset, Some(<$table as EcsComponent>::IDENTIFIER));
+                let buf = fbb.finished_data();
+                assert_eq!(identify_component(buf), Some(ComponentKind::$kind));
+                assert_eq!(ComponentKind::$kind.identifier(), <$table as EcsComponent>::IDENTIFIER);
+                assert!(load_component::<$table>(buf).is_ok());
+                seen.push(ComponentKind::$kind);
+            )*
+            assert_eq!(seen, ComponentKind::ALL);
+        };
+    }
+
+    #[test]
+    fn every_kind_can_be_loaded() {
+        check_loads! {
+            Animation => AnimationComponentData, AnimationComponentDataArgs;
+            AudioSource => AudioSourceComponentData, AudioSourceComponentDataArgs;
+            Camera => CameraComponentData, CameraComponentDataArgs;
+            Collider => ColliderComponentData, ColliderComponentDataArgs;
+            ColliderArray => ColliderComponentArrayData, ColliderComponentArrayDataArgs;
+            ColliderPoolScene => ColliderPoolSceneData, ColliderPoolSceneDataArgs;
+            ColliderScene => ColliderSceneData, ColliderSceneDataArgs;
+            CompoundCollider => CompoundColliderData, CompoundColliderDataArgs;
+            Light => LightComponentData, LightComponentDataArgs;
+            MeshRenderer => MeshRendererComponentData, MeshRendererComponentDataArgs;
+            ParticleEmitter => ParticleEmitterComponentData, ParticleEmitterComponentDataArgs;
+            ParticleSystem => ParticleSystemComponentData, ParticleSystemComponentDataArgs;
+            PhysicsMaterial => PhysicsMaterialData, PhysicsMaterialDataArgs;
+            RigidBody => RigidBodyComponentData, RigidBodyComponentDataArgs;
+            Script => ScriptComponentData, ScriptComponentDataArgs;
+            Sprite => SpriteComponentData, SpriteComponentDataArgs;
+            Text => TextComponentData, TextComponentDataArgs;
+            Tilemap => TilemapComponentData, TilemapComponentDataArgs;
+            Transform => TransformComponentData, TransformComponentDataArgs;
+        }
+    }
+
+    #[test]
+    fn wrong_identifier_is_rejected() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = TransformComponentData::create(&mut fbb, &Default::default());
+        fbb.finish(offset, Some(TRANSFORM_COMPONENT_DATA_IDENTIFIER));
+        assert_eq!(
+            load_component::<RigidBodyComponentData>(fbb.finished_data()).unwrap_err(),
+            LoadComponentError::WrongIdentifier {
+                expected: RIGID_BODY_COMPONENT_DATA_IDENTIFIER
+            }
+        );
+    }
+
+    #[test]
+    fn physics_material_round_trips() {
+        let mut fbb = FlatBufferBuilder::new();
+        let name = fbb.create_string("ice");
+        let offset = PhysicsMaterialData::create(
+            &mut fbb,
+            &PhysicsMaterialDataArgs {
+                name: Some(name),
+                static_friction: 0.1,
+                dynamic_friction: 0.05,
+                restitution: 0.2,
+                combine_mode: CombineMode::Min,
+            },
+        );
+        finish_physics_material_data_buffer(&mut fbb, offset);
+        let material = root_as_physics_material_data(fbb.finished_data()).unwrap();
+        assert_eq!(material.name(), Some("ice"));
+        assert_eq!(material.static_friction(), 0.1);
+        assert_eq!(material.dynamic_friction(), 0.05);
+        assert_eq!(material.restitution(), 0.2);
+        assert_eq!(material.combine_mode(), CombineMode::Min);
+    }
+
+    #[test]
+    fn physics_material_combine_mode_defaults_to_average() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = PhysicsMaterialData::create(&mut fbb, &Default::default());
+        finish_physics_material_data_buffer(&mut fbb, offset);
+        let material = root_as_physics_material_data(fbb.finished_data()).unwrap();
+        assert_eq!(material.combine_mode(), CombineMode::Average);
+        assert_eq!(material.name(), None);
+    }
+
+    #[test]
+    fn rigid_body_defaults_to_unit_gravity_scale() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = RigidBodyComponentData::create(&mut fbb, &Default::default());
+        finish_rigid_body_component_data_buffer(&mut fbb, offset);
+        let body = root_as_rigid_body_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(body.body_type(), BodyType::Static);
+        assert_eq!(body.gravity_scale(), 1.0);
+        assert!(!body.lock_rotation());
+    }
+
+    #[test]
+    fn rigid_body_round_trips() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = RigidBodyComponentData::create(
+            &mut fbb,
+            &RigidBodyComponentDataArgs {
+                body_type: BodyType::Dynamic,
+                mass: 70.0,
+                use_gravity: true,
+                gravity_scale: 0.5,
+                lock_rotation: true,
+                ..Default::default()
+            },
+        );
+        finish_rigid_body_component_data_buffer(&mut fbb, offset);
+        let body = root_as_rigid_body_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(body.body_type(), BodyType::Dynamic);
+        assert_eq!(body.mass(), 70.0);
+        assert!(body.use_gravity());
+        assert_eq!(body.gravity_scale(), 0.5);
+        assert!(body.lock_rotation());
+    }
+
+    #[test]
+    fn animation_loads_and_round_trips() {
+        let mut fbb = FlatBufferBuilder::new();
+        let clip_name = fbb.create_string("run");
+        let offset = AnimationComponentData::create(
+            &mut fbb,
+            &AnimationComponentDataArgs {
+                clip_name: Some(clip_name),
+                frame_count: 8,
+                loop_mode: LoopMode::PingPong,
+                current_frame: 3,
+                ..Default::default()
+            },
+        );
+        finish_animation_component_data_buffer(&mut fbb, offset);
+        let animation = load_component::<AnimationComponentData>(fbb.finished_data()).unwrap();
+        assert_eq!(animation.clip_name(), Some("run"));
+        assert_eq!(animation.frame_count(), 8);
+        assert_eq!(animation.loop_mode(), LoopMode::PingPong);
+        assert_eq!(animation.current_frame(), 3);
+    }
+
+    #[test]
+    fn animation_defaults_to_looping_at_twelve_fps() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = AnimationComponentData::create(&mut fbb, &Default::default());
+        finish_animation_component_data_buffer(&mut fbb, offset);
+        let animation = root_as_animation_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(animation.fps(), 12.0);
+        assert_eq!(animation.loop_mode(), LoopMode::Loop);
+    }
+
+    #[test]
+    fn audio_source_loads_and_round_trips() {
+        let mut fbb = FlatBufferBuilder::new();
+        let clip = fbb.create_string("sfx/hit.wav");
+        let offset = AudioSourceComponentData::create(
+            &mut fbb,
+            &AudioSourceComponentDataArgs {
+                audio_clip_path: Some(clip),
+                volume: 0.5,
+                pitch: 1.5,
+                loop_: true,
+                play_on_spawn: true,
+                ..Default::default()
+            },
+        );
+        finish_audio_source_component_data_buffer(&mut fbb, offset);
+        let audio = load_component::<AudioSourceComponentData>(fbb.finished_data()).unwrap();
+        assert_eq!(audio.audio_clip_path(), Some("sfx/hit.wav"));
+        assert_eq!(audio.volume(), 0.5);
+        assert_eq!(audio.pitch(), 1.5);
+        assert!(audio.loop_());
+        assert!(audio.play_on_spawn());
+    }
+
+    #[test]
+    fn audio_source_schema_defaults_stay_zero() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = AudioSourceComponentData::create(&mut fbb, &Default::default());
+        finish_audio_source_component_data_buffer(&mut fbb, offset);
+        let audio = root_as_audio_source_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(audio.volume(), 0.0);
+        assert_eq!(audio.pitch(), 0.0);
+        assert_eq!(audio.max_distance(), 0.0);
+        assert!(!audio.loop_());
+    }
+
+    #[test]
+    fn light_loads_and_round_trips() {
+        let mut fbb = FlatBufferBuilder::new();
+        let color = Vec3::new(1.0, 0.5, 0.0);
+        let offset = LightComponentData::create(
+            &mut fbb,
+            &LightComponentDataArgs {
+                light_type: LightType::Spot,
+                color: Some(&color),
+                intensity: 2.5,
+                range: 10.0,
+                outer_angle: 30.0,
+                cast_shadows: true,
+                ..Default::default()
+            },
+        );
+        finish_light_component_data_buffer(&mut fbb, offset);
+        let light = load_component::<LightComponentData>(fbb.finished_data()).unwrap();
+        assert_eq!(light.light_type(), LightType::Spot);
+        assert_eq!(light.color(), Some(&color));
+        assert_eq!(light.intensity(), 2.5);
+        assert_eq!(light.range(), 10.0);
+        assert_eq!(light.outer_angle(), 30.0);
+        assert!(light.cast_shadows());
+    }
+
+    #[test]
+    fn light_defaults_to_an_unlit_directional_light() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = LightComponentData::create(&mut fbb, &Default::default());
+        finish_light_component_data_buffer(&mut fbb, offset);
+        let light = root_as_light_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(light.light_type(), LightType::Directional);
+        assert_eq!(light.intensity(), 0.0);
+    }
+
+    #[test]
+    fn identify_component_reads_only_the_identifier() {
+        for kind in ComponentKind::ALL {
+            let bytes = format!("\0\0\0\0{}", kind.identifier());
+            assert_eq!(identify_component(bytes.as_bytes()), Some(kind));
+        }
+    }
+
+    #[test]
+    fn unknown_or_short_buffers_are_not_identified() {
+        assert_eq!(identify_component(b"garbage bytes"), None);
+        assert_eq!(identify_component(b"1234CLD"), None);
+        assert_eq!(identify_component(&[]), None);
+    }
+
+    #[test]
+    fn camera_loads_and_round_trips() {
+        let mut fbb = FlatBufferBuilder::new();
+        let viewport = Vec4::new(0.0, 0.0, 0.5, 1.0);
+        let offset = CameraComponentData::create(
+            &mut fbb,
+            &CameraComponentDataArgs {
+                camera_type: CameraType::Orthographic,
+                ortho_size: 8.0,
+                is_main: true,
+                viewport: Some(&viewport),
+                ..Default::default()
+            },
+        );
+        finish_camera_component_data_buffer(&mut fbb, offset);
+        let camera = load_component::<CameraComponentData>(fbb.finished_data()).unwrap();
+        assert_eq!(camera.camera_type(), CameraType::Orthographic);
+        assert_eq!(camera.ortho_size(), 8.0);
+        assert!(camera.is_main());
+        assert_eq!(camera.viewport(), Some(&viewport));
+    }
+
+    #[test]
+    fn camera_defaults() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = CameraComponentData::create(&mut fbb, &Default::default());
+        finish_camera_component_data_buffer(&mut fbb, offset);
+        let camera = root_as_camera_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(camera.camera_type(), CameraType::Perspective);
+        assert_eq!(camera.fov(), 0.0);
+        assert_eq!(camera.ortho_size(), 0.0);
+        assert_eq!(camera.near_plane(), 0.0);
+        assert_eq!(camera.far_plane(), 0.0);
+        assert!(!camera.is_main());
+        assert_eq!(camera.viewport(), None);
+    }
+
+    #[test]
+    fn particle_emitter_loads_and_round_trips() {
+        let mut fbb = FlatBufferBuilder::new();
+        let texture = fbb.create_string("fx/spark.png");
+        let start_color = Color::new(255, 200, 0, 255);
+        let end_color = Color::new(255, 0, 0, 0);
+        let offset = ParticleEmitterComponentData::create(
+            &mut fbb,
+            &ParticleEmitterComponentDataArgs {
+                texture_path: Some(texture),
+                emission_rate: 30.0,
+                lifetime: 1.5,
+                start_color: Some(&start_color),
+                end_color: Some(&end_color),
+                start_size: 0.5,
+                end_size: 0.1,
+                initial_velocity: Some(&Vec3::new(0.0, 2.0, 0.0)),
+                gravity_scale: 0.3,
+                max_particles: 256,
+            },
+        );
+        finish_particle_emitter_component_data_buffer(&mut fbb, offset);
+        let buf = fbb.finished_data();
+        assert_eq!(
+            identify_component(buf),
+            Some(ComponentKind::ParticleEmitter)
+        );
+        let emitter = load_component::<ParticleEmitterComponentData>(buf).unwrap();
+        assert_eq!(emitter.texture_path(), Some("fx/spark.png"));
+        assert_eq!(emitter.start_color(), Some(&start_color));
+        assert_eq!(emitter.end_color(), Some(&end_color));
+        assert_eq!(emitter.end_size(), 0.1);
+        assert_eq!(emitter.max_particles(), 256);
+    }
+
+    #[test]
+    fn text_loads_and_round_trips() {
+        let mut fbb = FlatBufferBuilder::new();
+        let content = fbb.create_string("Press E");
+        let font = fbb.create_string("fonts/pixel.ttf");
+        let color = Color::new(255, 255, 0, 255);
+        let offset = TextComponentData::create(
+            &mut fbb,
+            &TextComponentDataArgs {
+                content: Some(content),
+                font_path: Some(font),
+                color: Some(&color),
+                alignment: TextAlignment::Center,
+                wrap_width: 120.0,
+                ..Default::default()
+            },
+        );
+        finish_text_component_data_buffer(&mut fbb, offset);
+        let text = load_component::<TextComponentData>(fbb.finished_data()).unwrap();
+        assert_eq!(text.content(), Some("Press E"));
+        assert_eq!(text.font_path(), Some("fonts/pixel.ttf"));
+        assert_eq!(text.color(), Some(&color));
+        assert_eq!(text.alignment(), TextAlignment::Center);
+        assert_eq!(text.wrap_width(), 120.0);
+    }
+
+    #[test]
+    fn text_defaults_to_left_aligned_unwrapped() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = TextComponentData::create(&mut fbb, &Default::default());
+        finish_text_component_data_buffer(&mut fbb, offset);
+        let text = root_as_text_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(text.alignment(), TextAlignment::Left);
+        assert_eq!(text.font_size(), 16.0);
+        assert_eq!(text.wrap_width(), 0.0);
+    }
+}
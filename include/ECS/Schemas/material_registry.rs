@@ -0,0 +1,164 @@
+// Interning of collider material names into compact runtime handles.
+
+use std::collections::HashMap;
+
+use crate::collider_component::*;
+use crate::collider_component_generated::*;
+
+/// Stable handle for an interned material name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaterialId(pub u32);
+
+/// Deduplicating store of material names.
+///
+/// Each distinct name is hashed once when interned; afterwards systems can
+/// carry the `MaterialId` around and compare or index by it. Ids are handed out
+/// sequentially and stay valid for the lifetime of the registry.
+#[derive(Clone, Debug, Default)]
+pub struct MaterialRegistry {
+    names: Vec<String>,
+    ids: HashMap<String, MaterialId>,
+}
+
+impl MaterialRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id for `name`, assigning a new one the first time it is seen.
+    pub fn intern(&mut self, name: &str) -> MaterialId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = MaterialId(u32::try_from(self.names.len()).expect("material id space exhausted"));
+        self.names.push(name.to_owned());
+        self.ids.insert(name.to_owned(), id);
+        id
+    }
+
+    /// Looks up an already interned name without adding it.
+    pub fn get(&self, name: &str) -> Option<MaterialId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn resolve(&self, id: MaterialId) -> Option<&str> {
+        self.names.get(id.0 as usize).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// Per-shape default materials, for colliders without a `material_name`.
+///
+/// Types without an override use `default_material_for_type`.
+#[derive(Clone, Debug, Default)]
+pub struct DefaultMaterials {
+    overrides: HashMap<ColliderType, String>,
+}
+
+impl DefaultMaterials {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses `name` as the default material for `collider_type`.
+    pub fn set(&mut self, collider_type: ColliderType, name: &str) {
+        self.overrides.insert(collider_type, name.to_owned());
+    }
+
+    pub fn get(&self, collider_type: ColliderType) -> &str {
+        self.overrides
+            .get(&collider_type)
+            .map_or_else(|| default_material_for_type(collider_type), String::as_str)
+    }
+
+    /// The collider's `material_name`, or this table's default for its type.
+    pub fn collider_material<'a>(&'a self, data: &ColliderComponentData<'a>) -> &'a str {
+        collider_material_or(data, self.get(data.collider_type()))
+    }
+}
+
+/// Interns the collider's `material_name`, or returns `None` if it has none.
+pub fn collider_material_id(
+    data: &ColliderComponentData,
+    registry: &mut MaterialRegistry,
+) -> Option<MaterialId> {
+    data.material_name().map(|name| registry.intern(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+
+    #[test]
+    fn interning_deduplicates_names() {
+        let mut registry = MaterialRegistry::new();
+        let ice = registry.intern("ice");
+        assert_eq!(registry.intern("ice"), ice);
+        let mud = registry.intern("mud");
+        assert_ne!(ice, mud);
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.resolve(mud), Some("mud"));
+        assert_eq!(registry.get("ice"), Some(ice));
+        assert_eq!(registry.get("sand"), None);
+    }
+
+    #[test]
+    fn collider_material_id_interns_the_name() {
+        let mut registry = MaterialRegistry::new();
+        let buf = make_collider_buffer(&ColliderComponent {
+            material_name: Some("ice".to_owned()),
+            ..Default::default()
+        });
+        let data = root_as_collider_component_data(&buf).unwrap();
+        let id = collider_material_id(&data, &mut registry);
+        assert_eq!(id, registry.get("ice"));
+
+        let buf = make_collider_buffer(&ColliderComponent::default());
+        let data = root_as_collider_component_data(&buf).unwrap();
+        assert_eq!(collider_material_id(&data, &mut registry), None);
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn default_materials_fall_back_per_collider_type() {
+        assert_eq!(default_material_for_type(ColliderType::Box), "default");
+        assert_eq!(default_material_for_type(ColliderType::Mesh), "terrain");
+
+        let mesh = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("terrain.obj".to_owned()),
+            ..Default::default()
+        });
+        let mesh = root_as_collider_component_data(&mesh).unwrap();
+        assert_eq!(collider_material_or_default(&mesh), "terrain");
+
+        let mut defaults = DefaultMaterials::new();
+        defaults.set(ColliderType::Sphere, "bouncy");
+        let ball = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.0,
+            ..Default::default()
+        });
+        let ball = root_as_collider_component_data(&ball).unwrap();
+        assert_eq!(defaults.collider_material(&ball), "bouncy");
+        assert_eq!(defaults.collider_material(&mesh), "terrain");
+
+        let ice = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.0,
+            material_name: Some("ice".to_owned()),
+            ..Default::default()
+        });
+        let ice = root_as_collider_component_data(&ice).unwrap();
+        assert_eq!(defaults.collider_material(&ice), "ice");
+        assert_eq!(collider_material_or_default(&ice), "ice");
+    }
+}
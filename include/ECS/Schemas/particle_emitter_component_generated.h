@@ -0,0 +1,234 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_PARTICLEEMITTERCOMPONENT_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_PARTICLEEMITTERCOMPONENT_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+#include "common_types_generated.h"
+
+namespace PixelCraft {
+namespace ECS {
+
+struct ParticleEmitterComponentData;
+struct ParticleEmitterComponentDataBuilder;
+
+struct ParticleEmitterComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef ParticleEmitterComponentDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_TEXTURE_PATH = 4,
+    VT_EMISSION_RATE = 6,
+    VT_LIFETIME = 8,
+    VT_START_COLOR = 10,
+    VT_END_COLOR = 12,
+    VT_START_SIZE = 14,
+    VT_END_SIZE = 16,
+    VT_INITIAL_VELOCITY = 18,
+    VT_GRAVITY_SCALE = 20,
+    VT_MAX_PARTICLES = 22
+  };
+  const ::flatbuffers::String *texture_path() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_TEXTURE_PATH);
+  }
+  /// Particles spawned per second.
+  float emission_rate() const {
+    return GetField<float>(VT_EMISSION_RATE, 0.0f);
+  }
+  /// Seconds each particle lives.
+  float lifetime() const {
+    return GetField<float>(VT_LIFETIME, 0.0f);
+  }
+  /// Color at spawn, blended towards end_color over the particle's lifetime.
+  const PixelCraft::ECS::Color *start_color() const {
+    return GetStruct<const PixelCraft::ECS::Color *>(VT_START_COLOR);
+  }
+  const PixelCraft::ECS::Color *end_color() const {
+    return GetStruct<const PixelCraft::ECS::Color *>(VT_END_COLOR);
+  }
+  float start_size() const {
+    return GetField<float>(VT_START_SIZE, 0.0f);
+  }
+  float end_size() const {
+    return GetField<float>(VT_END_SIZE, 0.0f);
+  }
+  const PixelCraft::ECS::Vec3 *initial_velocity() const {
+    return GetStruct<const PixelCraft::ECS::Vec3 *>(VT_INITIAL_VELOCITY);
+  }
+  /// Multiplier on world gravity; 0 = unaffected.
+  float gravity_scale() const {
+    return GetField<float>(VT_GRAVITY_SCALE, 0.0f);
+  }
+  uint32_t max_particles() const {
+    return GetField<uint32_t>(VT_MAX_PARTICLES, 0);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_TEXTURE_PATH) &&
+           verifier.VerifyString(texture_path()) &&
+           VerifyField<float>(verifier, VT_EMISSION_RATE, 4) &&
+           VerifyField<float>(verifier, VT_LIFETIME, 4) &&
+           VerifyField<PixelCraft::ECS::Color>(verifier, VT_START_COLOR, 1) &&
+           VerifyField<PixelCraft::ECS::Color>(verifier, VT_END_COLOR, 1) &&
+           VerifyField<float>(verifier, VT_START_SIZE, 4) &&
+           VerifyField<float>(verifier, VT_END_SIZE, 4) &&
+           VerifyField<PixelCraft::ECS::Vec3>(verifier, VT_INITIAL_VELOCITY, 4) &&
+           VerifyField<float>(verifier, VT_GRAVITY_SCALE, 4) &&
+           VerifyField<uint32_t>(verifier, VT_MAX_PARTICLES, 4) &&
+           verifier.EndTable();
+  }
+};
+
+struct ParticleEmitterComponentDataBuilder {
+  typedef ParticleEmitterComponentData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_texture_path(::flatbuffers::Offset<::flatbuffers::String> texture_path) {
+    fbb_.AddOffset(ParticleEmitterComponentData::VT_TEXTURE_PATH, texture_path);
+  }
+  void add_emission_rate(float emission_rate) {
+    fbb_.AddElement<float>(ParticleEmitterComponentData::VT_EMISSION_RATE, emission_rate, 0.0f);
+  }
+  void add_lifetime(float lifetime) {
+    fbb_.AddElement<float>(ParticleEmitterComponentData::VT_LIFETIME, lifetime, 0.0f);
+  }
+  void add_start_color(const PixelCraft::ECS::Color *start_color) {
+    fbb_.AddStruct(ParticleEmitterComponentData::VT_START_COLOR, start_color);
+  }
+  void add_end_color(const PixelCraft::ECS::Color *end_color) {
+    fbb_.AddStruct(ParticleEmitterComponentData::VT_END_COLOR, end_color);
+  }
+  void add_start_size(float start_size) {
+    fbb_.AddElement<float>(ParticleEmitterComponentData::VT_START_SIZE, start_size, 0.0f);
+  }
+  void add_end_size(float end_size) {
+    fbb_.AddElement<float>(ParticleEmitterComponentData::VT_END_SIZE, end_size, 0.0f);
+  }
+  void add_initial_velocity(const PixelCraft::ECS::Vec3 *initial_velocity) {
+    fbb_.AddStruct(ParticleEmitterComponentData::VT_INITIAL_VELOCITY, initial_velocity);
+  }
+  void add_gravity_scale(float gravity_scale) {
+    fbb_.AddElement<float>(ParticleEmitterComponentData::VT_GRAVITY_SCALE, gravity_scale, 0.0f);
+  }
+  void add_max_particles(uint32_t max_particles) {
+    fbb_.AddElement<uint32_t>(ParticleEmitterComponentData::VT_MAX_PARTICLES, max_particles, 0);
+  }
+  explicit ParticleEmitterComponentDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<ParticleEmitterComponentData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<ParticleEmitterComponentData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<ParticleEmitterComponentData> CreateParticleEmitterComponentData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> texture_path = 0,
+    float emission_rate = 0.0f,
+    float lifetime = 0.0f,
+    const PixelCraft::ECS::Color *start_color = nullptr,
+    const PixelCraft::ECS::Color *end_color = nullptr,
+    float start_size = 0.0f,
+    float end_size = 0.0f,
+    const PixelCraft::ECS::Vec3 *initial_velocity = nullptr,
+    float gravity_scale = 0.0f,
+    uint32_t max_particles = 0) {
+  ParticleEmitterComponentDataBuilder builder_(_fbb);
+  builder_.add_max_particles(max_particles);
+  builder_.add_gravity_scale(gravity_scale);
+  builder_.add_initial_velocity(initial_velocity);
+  builder_.add_end_size(end_size);
+  builder_.add_start_size(start_size);
+  builder_.add_end_color(end_color);
+  builder_.add_start_color(start_color);
+  builder_.add_lifetime(lifetime);
+  builder_.add_emission_rate(emission_rate);
+  builder_.add_texture_path(texture_path);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<ParticleEmitterComponentData> CreateParticleEmitterComponentDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *texture_path = nullptr,
+    float emission_rate = 0.0f,
+    float lifetime = 0.0f,
+    const PixelCraft::ECS::Color *start_color = nullptr,
+    const PixelCraft::ECS::Color *end_color = nullptr,
+    float start_size = 0.0f,
+    float end_size = 0.0f,
+    const PixelCraft::ECS::Vec3 *initial_velocity = nullptr,
+    float gravity_scale = 0.0f,
+    uint32_t max_particles = 0) {
+  auto texture_path__ = texture_path ? _fbb.CreateString(texture_path) : 0;
+  return PixelCraft::ECS::CreateParticleEmitterComponentData(
+      _fbb,
+      texture_path__,
+      emission_rate,
+      lifetime,
+      start_color,
+      end_color,
+      start_size,
+      end_size,
+      initial_velocity,
+      gravity_scale,
+      max_particles);
+}
+
+inline const PixelCraft::ECS::ParticleEmitterComponentData *GetParticleEmitterComponentData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::ParticleEmitterComponentData>(buf);
+}
+
+inline const PixelCraft::ECS::ParticleEmitterComponentData *GetSizePrefixedParticleEmitterComponentData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::ParticleEmitterComponentData>(buf);
+}
+
+inline const char *ParticleEmitterComponentDataIdentifier() {
+  return "PART";
+}
+
+inline bool ParticleEmitterComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, ParticleEmitterComponentDataIdentifier());
+}
+
+inline bool SizePrefixedParticleEmitterComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, ParticleEmitterComponentDataIdentifier(), true);
+}
+
+inline bool VerifyParticleEmitterComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::ParticleEmitterComponentData>(ParticleEmitterComponentDataIdentifier());
+}
+
+inline bool VerifySizePrefixedParticleEmitterComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::ParticleEmitterComponentData>(ParticleEmitterComponentDataIdentifier());
+}
+
+inline void FinishParticleEmitterComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::ParticleEmitterComponentData> root) {
+  fbb.Finish(root, ParticleEmitterComponentDataIdentifier());
+}
+
+inline void FinishSizePrefixedParticleEmitterComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::ParticleEmitterComponentData> root) {
+  fbb.FinishSizePrefixed(root, ParticleEmitterComponentDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_PARTICLEEMITTERCOMPONENT_PIXELCRAFT_ECS_H_
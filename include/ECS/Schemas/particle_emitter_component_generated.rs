@@ -0,0 +1,371 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use crate::common_types_generated::*;
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use crate::common_types_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use crate::common_types_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum ParticleEmitterComponentDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ParticleEmitterComponentData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ParticleEmitterComponentData<'a> {
+  type Inner = ParticleEmitterComponentData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ParticleEmitterComponentData<'a> {
+  pub const VT_TEXTURE_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_EMISSION_RATE: flatbuffers::VOffsetT = 6;
+  pub const VT_LIFETIME: flatbuffers::VOffsetT = 8;
+  pub const VT_START_COLOR: flatbuffers::VOffsetT = 10;
+  pub const VT_END_COLOR: flatbuffers::VOffsetT = 12;
+  pub const VT_START_SIZE: flatbuffers::VOffsetT = 14;
+  pub const VT_END_SIZE: flatbuffers::VOffsetT = 16;
+  pub const VT_INITIAL_VELOCITY: flatbuffers::VOffsetT = 18;
+  pub const VT_GRAVITY_SCALE: flatbuffers::VOffsetT = 20;
+  pub const VT_MAX_PARTICLES: flatbuffers::VOffsetT = 22;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ParticleEmitterComponentData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ParticleEmitterComponentDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<ParticleEmitterComponentData<'bldr>> {
+    let mut builder = ParticleEmitterComponentDataBuilder::new(_fbb);
+    builder.add_max_particles(args.max_particles);
+    builder.add_gravity_scale(args.gravity_scale);
+    if let Some(x) = args.initial_velocity { builder.add_initial_velocity(x); }
+    builder.add_end_size(args.end_size);
+    builder.add_start_size(args.start_size);
+    if let Some(x) = args.end_color { builder.add_end_color(x); }
+    if let Some(x) = args.start_color { builder.add_start_color(x); }
+    builder.add_lifetime(args.lifetime);
+    builder.add_emission_rate(args.emission_rate);
+    if let Some(x) = args.texture_path { builder.add_texture_path(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn texture_path(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ParticleEmitterComponentData::VT_TEXTURE_PATH, None)}
+  }
+  /// Particles spawned per second.
+  #[inline]
+  pub fn emission_rate(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ParticleEmitterComponentData::VT_EMISSION_RATE, Some(0.0)).unwrap()}
+  }
+  /// Seconds each particle lives.
+  #[inline]
+  pub fn lifetime(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ParticleEmitterComponentData::VT_LIFETIME, Some(0.0)).unwrap()}
+  }
+  /// Color at spawn, blended towards end_color over the particle's lifetime.
+  #[inline]
+  pub fn start_color(&self) -> Option<&'a Color> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Color>(ParticleEmitterComponentData::VT_START_COLOR, None)}
+  }
+  #[inline]
+  pub fn end_color(&self) -> Option<&'a Color> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Color>(ParticleEmitterComponentData::VT_END_COLOR, None)}
+  }
+  #[inline]
+  pub fn start_size(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ParticleEmitterComponentData::VT_START_SIZE, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn end_size(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ParticleEmitterComponentData::VT_END_SIZE, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn initial_velocity(&self) -> Option<&'a Vec3> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec3>(ParticleEmitterComponentData::VT_INITIAL_VELOCITY, None)}
+  }
+  /// Multiplier on world gravity; 0 = unaffected.
+  #[inline]
+  pub fn gravity_scale(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ParticleEmitterComponentData::VT_GRAVITY_SCALE, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn max_particles(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ParticleEmitterComponentData::VT_MAX_PARTICLES, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ParticleEmitterComponentData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("texture_path", Self::VT_TEXTURE_PATH, false)?
+     .visit_field::<f32>("emission_rate", Self::VT_EMISSION_RATE, false)?
+     .visit_field::<f32>("lifetime", Self::VT_LIFETIME, false)?
+     .visit_field::<Color>("start_color", Self::VT_START_COLOR, false)?
+     .visit_field::<Color>("end_color", Self::VT_END_COLOR, false)?
+     .visit_field::<f32>("start_size", Self::VT_START_SIZE, false)?
+     .visit_field::<f32>("end_size", Self::VT_END_SIZE, false)?
+     .visit_field::<Vec3>("initial_velocity", Self::VT_INITIAL_VELOCITY, false)?
+     .visit_field::<f32>("gravity_scale", Self::VT_GRAVITY_SCALE, false)?
+     .visit_field::<u32>("max_particles", Self::VT_MAX_PARTICLES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ParticleEmitterComponentDataArgs<'a> {
+    pub texture_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub emission_rate: f32,
+    pub lifetime: f32,
+    pub start_color: Option<&'a Color>,
+    pub end_color: Option<&'a Color>,
+    pub start_size: f32,
+    pub end_size: f32,
+    pub initial_velocity: Option<&'a Vec3>,
+    pub gravity_scale: f32,
+    pub max_particles: u32,
+}
+impl<'a> Default for ParticleEmitterComponentDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ParticleEmitterComponentDataArgs {
+      texture_path: None,
+      emission_rate: 0.0,
+      lifetime: 0.0,
+      start_color: None,
+      end_color: None,
+      start_size: 0.0,
+      end_size: 0.0,
+      initial_velocity: None,
+      gravity_scale: 0.0,
+      max_particles: 0,
+    }
+  }
+}
+
+pub struct ParticleEmitterComponentDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ParticleEmitterComponentDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_texture_path(&mut self, texture_path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ParticleEmitterComponentData::VT_TEXTURE_PATH, texture_path);
+  }
+  #[inline]
+  pub fn add_emission_rate(&mut self, emission_rate: f32) {
+    self.fbb_.push_slot::<f32>(ParticleEmitterComponentData::VT_EMISSION_RATE, emission_rate, 0.0);
+  }
+  #[inline]
+  pub fn add_lifetime(&mut self, lifetime: f32) {
+    self.fbb_.push_slot::<f32>(ParticleEmitterComponentData::VT_LIFETIME, lifetime, 0.0);
+  }
+  #[inline]
+  pub fn add_start_color(&mut self, start_color: &Color) {
+    self.fbb_.push_slot_always::<&Color>(ParticleEmitterComponentData::VT_START_COLOR, start_color);
+  }
+  #[inline]
+  pub fn add_end_color(&mut self, end_color: &Color) {
+    self.fbb_.push_slot_always::<&Color>(ParticleEmitterComponentData::VT_END_COLOR, end_color);
+  }
+  #[inline]
+  pub fn add_start_size(&mut self, start_size: f32) {
+    self.fbb_.push_slot::<f32>(ParticleEmitterComponentData::VT_START_SIZE, start_size, 0.0);
+  }
+  #[inline]
+  pub fn add_end_size(&mut self, end_size: f32) {
+    self.fbb_.push_slot::<f32>(ParticleEmitterComponentData::VT_END_SIZE, end_size, 0.0);
+  }
+  #[inline]
+  pub fn add_initial_velocity(&mut self, initial_velocity: &Vec3) {
+    self.fbb_.push_slot_always::<&Vec3>(ParticleEmitterComponentData::VT_INITIAL_VELOCITY, initial_velocity);
+  }
+  #[inline]
+  pub fn add_gravity_scale(&mut self, gravity_scale: f32) {
+    self.fbb_.push_slot::<f32>(ParticleEmitterComponentData::VT_GRAVITY_SCALE, gravity_scale, 0.0);
+  }
+  #[inline]
+  pub fn add_max_particles(&mut self, max_particles: u32) {
+    self.fbb_.push_slot::<u32>(ParticleEmitterComponentData::VT_MAX_PARTICLES, max_particles, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ParticleEmitterComponentDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ParticleEmitterComponentDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ParticleEmitterComponentData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ParticleEmitterComponentData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ParticleEmitterComponentData");
+      ds.field("texture_path", &self.texture_path());
+      ds.field("emission_rate", &self.emission_rate());
+      ds.field("lifetime", &self.lifetime());
+      ds.field("start_color", &self.start_color());
+      ds.field("end_color", &self.end_color());
+      ds.field("start_size", &self.start_size());
+      ds.field("end_size", &self.end_size());
+      ds.field("initial_velocity", &self.initial_velocity());
+      ds.field("gravity_scale", &self.gravity_scale());
+      ds.field("max_particles", &self.max_particles());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `ParticleEmitterComponentData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_particle_emitter_component_data_unchecked`.
+pub fn root_as_particle_emitter_component_data(buf: &[u8]) -> Result<ParticleEmitterComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<ParticleEmitterComponentData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `ParticleEmitterComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_particle_emitter_component_data_unchecked`.
+pub fn size_prefixed_root_as_particle_emitter_component_data(buf: &[u8]) -> Result<ParticleEmitterComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<ParticleEmitterComponentData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `ParticleEmitterComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_particle_emitter_component_data_unchecked`.
+pub fn root_as_particle_emitter_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ParticleEmitterComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<ParticleEmitterComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `ParticleEmitterComponentData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_particle_emitter_component_data_unchecked`.
+pub fn size_prefixed_root_as_particle_emitter_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ParticleEmitterComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<ParticleEmitterComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a ParticleEmitterComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `ParticleEmitterComponentData`.
+pub unsafe fn root_as_particle_emitter_component_data_unchecked(buf: &[u8]) -> ParticleEmitterComponentData {
+  flatbuffers::root_unchecked::<ParticleEmitterComponentData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed ParticleEmitterComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `ParticleEmitterComponentData`.
+pub unsafe fn size_prefixed_root_as_particle_emitter_component_data_unchecked(buf: &[u8]) -> ParticleEmitterComponentData {
+  flatbuffers::size_prefixed_root_unchecked::<ParticleEmitterComponentData>(buf)
+}
+pub const PARTICLE_EMITTER_COMPONENT_DATA_IDENTIFIER: &str = "PART";
+
+#[inline]
+pub fn particle_emitter_component_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, PARTICLE_EMITTER_COMPONENT_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn particle_emitter_component_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, PARTICLE_EMITTER_COMPONENT_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_particle_emitter_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<ParticleEmitterComponentData<'a>>) {
+  fbb.finish(root, Some(PARTICLE_EMITTER_COMPONENT_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_particle_emitter_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<ParticleEmitterComponentData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(PARTICLE_EMITTER_COMPONENT_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
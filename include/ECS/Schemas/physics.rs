@@ -0,0 +1,124 @@
+// Minimal rigid body stepping and broadphase over collider tables, for tools
+// and tests that do not pull in the full physics backend.
+
+use crate::collider_component_generated::*;
+use crate::collider_geometry::*;
+use crate::common_types_generated::*;
+
+/// Position and linear velocity of a body, with no rotation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SimpleBodyState {
+    pub position: Vec3,
+    pub velocity: Vec3,
+}
+
+/// Advances `state` by `dt` seconds under `gravity` with semi-implicit Euler:
+/// velocity is updated first and the new velocity moves the body.
+pub fn integrate_body(state: &mut SimpleBodyState, gravity: &Vec3, dt: f32) {
+    let v = &state.velocity;
+    state.velocity = Vec3::new(
+        v.x() + gravity.x() * dt,
+        v.y() + gravity.y() * dt,
+        v.z() + gravity.z() * dt,
+    );
+    let (p, v) = (&state.position, &state.velocity);
+    state.position = Vec3::new(p.x() + v.x() * dt, p.y() + v.y() * dt, p.z() + v.z() * dt);
+}
+
+/// Index pairs `(i, j)` with `i < j` whose world AABBs overlap, sorted.
+///
+/// Each collider's `collider_local_aabb` is translated by its body's position;
+/// bodies carry no rotation. Touching boxes count as overlapping. Colliders
+/// without local bounds (meshes, unknown types) are never reported. Uses sort
+/// and sweep on X, so the cost grows with the number of overlaps on that axis
+/// rather than with every pair.
+pub fn broadphase_pairs(
+    colliders: &[(ColliderComponentData, SimpleBodyState)],
+) -> Vec<(usize, usize)> {
+    let mut boxes: Vec<(usize, [f32; 3], [f32; 3])> = colliders
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (collider, body))| {
+            let (min, max) = collider_local_aabb(collider)?;
+            let p = &body.position;
+            Some((
+                i,
+                [min.x() + p.x(), min.y() + p.y(), min.z() + p.z()],
+                [max.x() + p.x(), max.y() + p.y(), max.z() + p.z()],
+            ))
+        })
+        .collect();
+    boxes.sort_by(|a, b| a.1[0].total_cmp(&b.1[0]));
+
+    let mut pairs = Vec::new();
+    for (n, (i, min_a, max_a)) in boxes.iter().enumerate() {
+        for (j, min_b, max_b) in &boxes[n + 1..] {
+            if min_b[0] > max_a[0] {
+                break;
+            }
+            if (1..3).all(|axis| min_a[axis] <= max_b[axis] && min_b[axis] <= max_a[axis]) {
+                pairs.push((*i.min(j), *i.max(j)));
+            }
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collider_builders::make_collider_buffer;
+    use crate::collider_component::ColliderComponent;
+
+    fn at(x: f32, y: f32) -> SimpleBodyState {
+        SimpleBodyState {
+            position: Vec3::new(x, y, 0.0),
+            velocity: Vec3::default(),
+        }
+    }
+
+    #[test]
+    fn falling_body_uses_semi_implicit_euler() {
+        let mut body = at(0.0, 10.0);
+        let gravity = Vec3::new(0.0, -10.0, 0.0);
+        for _ in 0..10 {
+            integrate_body(&mut body, &gravity, 0.1);
+        }
+        assert!((body.velocity.y() + 10.0).abs() < 1e-4);
+        // Each step moves by the updated velocity: 10 - 0.1 * (1 + 2 + ... + 10).
+        assert!(
+            (body.position.y() - 4.5).abs() < 1e-4,
+            "{:?}",
+            body.position
+        );
+    }
+
+    #[test]
+    fn broadphase_reports_overlapping_bounds_only() {
+        let sphere = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Sphere,
+            radius: 1.0,
+            ..Default::default()
+        });
+        let cube = make_collider_buffer(&ColliderComponent {
+            size: Some(Vec3::new(2.0, 2.0, 2.0)),
+            ..Default::default()
+        });
+        let mesh = make_collider_buffer(&ColliderComponent {
+            collider_type: ColliderType::Mesh,
+            mesh_path: Some("rock.obj".into()),
+            ..Default::default()
+        });
+        let read = |buf| root_as_collider_component_data(buf).unwrap();
+        let bodies = [
+            (read(&sphere), at(0.0, 0.0)),
+            (read(&mesh), at(0.0, 0.0)),
+            (read(&cube), at(10.0, 0.0)),
+            (read(&cube), at(1.5, 0.0)),
+            (read(&sphere), at(1.5, 5.0)),
+            (read(&cube), at(12.0, 0.0)),
+        ];
+        assert_eq!(broadphase_pairs(&bodies), [(0, 3), (2, 5)]);
+    }
+}
@@ -0,0 +1,204 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_PHYSICSMATERIAL_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_PHYSICSMATERIAL_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+namespace PixelCraft {
+namespace ECS {
+
+struct PhysicsMaterialData;
+struct PhysicsMaterialDataBuilder;
+
+enum CombineMode : int8_t {
+  CombineMode_Average = 0,
+  CombineMode_Min = 1,
+  CombineMode_Max = 2,
+  CombineMode_Multiply = 3,
+  CombineMode_MIN = CombineMode_Average,
+  CombineMode_MAX = CombineMode_Multiply
+};
+
+inline const CombineMode (&EnumValuesCombineMode())[4] {
+  static const CombineMode values[] = {
+    CombineMode_Average,
+    CombineMode_Min,
+    CombineMode_Max,
+    CombineMode_Multiply
+  };
+  return values;
+}
+
+inline const char * const *EnumNamesCombineMode() {
+  static const char * const names[5] = {
+    "Average",
+    "Min",
+    "Max",
+    "Multiply",
+    nullptr
+  };
+  return names;
+}
+
+inline const char *EnumNameCombineMode(CombineMode e) {
+  if (::flatbuffers::IsOutRange(e, CombineMode_Average, CombineMode_Multiply)) return "";
+  const size_t index = static_cast<size_t>(e);
+  return EnumNamesCombineMode()[index];
+}
+
+struct PhysicsMaterialData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef PhysicsMaterialDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_NAME = 4,
+    VT_STATIC_FRICTION = 6,
+    VT_DYNAMIC_FRICTION = 8,
+    VT_RESTITUTION = 10,
+    VT_COMBINE_MODE = 12
+  };
+  const ::flatbuffers::String *name() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_NAME);
+  }
+  float static_friction() const {
+    return GetField<float>(VT_STATIC_FRICTION, 0.0f);
+  }
+  float dynamic_friction() const {
+    return GetField<float>(VT_DYNAMIC_FRICTION, 0.0f);
+  }
+  float restitution() const {
+    return GetField<float>(VT_RESTITUTION, 0.0f);
+  }
+  PixelCraft::ECS::CombineMode combine_mode() const {
+    return static_cast<PixelCraft::ECS::CombineMode>(GetField<int8_t>(VT_COMBINE_MODE, 0));
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_NAME) &&
+           verifier.VerifyString(name()) &&
+           VerifyField<float>(verifier, VT_STATIC_FRICTION, 4) &&
+           VerifyField<float>(verifier, VT_DYNAMIC_FRICTION, 4) &&
+           VerifyField<float>(verifier, VT_RESTITUTION, 4) &&
+           VerifyField<int8_t>(verifier, VT_COMBINE_MODE, 1) &&
+           verifier.EndTable();
+  }
+};
+
+struct PhysicsMaterialDataBuilder {
+  typedef PhysicsMaterialData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_name(::flatbuffers::Offset<::flatbuffers::String> name) {
+    fbb_.AddOffset(PhysicsMaterialData::VT_NAME, name);
+  }
+  void add_static_friction(float static_friction) {
+    fbb_.AddElement<float>(PhysicsMaterialData::VT_STATIC_FRICTION, static_friction, 0.0f);
+  }
+  void add_dynamic_friction(float dynamic_friction) {
+    fbb_.AddElement<float>(PhysicsMaterialData::VT_DYNAMIC_FRICTION, dynamic_friction, 0.0f);
+  }
+  void add_restitution(float restitution) {
+    fbb_.AddElement<float>(PhysicsMaterialData::VT_RESTITUTION, restitution, 0.0f);
+  }
+  void add_combine_mode(PixelCraft::ECS::CombineMode combine_mode) {
+    fbb_.AddElement<int8_t>(PhysicsMaterialData::VT_COMBINE_MODE, static_cast<int8_t>(combine_mode), 0);
+  }
+  explicit PhysicsMaterialDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<PhysicsMaterialData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<PhysicsMaterialData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<PhysicsMaterialData> CreatePhysicsMaterialData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> name = 0,
+    float static_friction = 0.0f,
+    float dynamic_friction = 0.0f,
+    float restitution = 0.0f,
+    PixelCraft::ECS::CombineMode combine_mode = PixelCraft::ECS::CombineMode_Average) {
+  PhysicsMaterialDataBuilder builder_(_fbb);
+  builder_.add_restitution(restitution);
+  builder_.add_dynamic_friction(dynamic_friction);
+  builder_.add_static_friction(static_friction);
+  builder_.add_name(name);
+  builder_.add_combine_mode(combine_mode);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<PhysicsMaterialData> CreatePhysicsMaterialDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *name = nullptr,
+    float static_friction = 0.0f,
+    float dynamic_friction = 0.0f,
+    float restitution = 0.0f,
+    PixelCraft::ECS::CombineMode combine_mode = PixelCraft::ECS::CombineMode_Average) {
+  auto name__ = name ? _fbb.CreateString(name) : 0;
+  return PixelCraft::ECS::CreatePhysicsMaterialData(
+      _fbb,
+      name__,
+      static_friction,
+      dynamic_friction,
+      restitution,
+      combine_mode);
+}
+
+inline const PixelCraft::ECS::PhysicsMaterialData *GetPhysicsMaterialData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::PhysicsMaterialData>(buf);
+}
+
+inline const PixelCraft::ECS::PhysicsMaterialData *GetSizePrefixedPhysicsMaterialData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::PhysicsMaterialData>(buf);
+}
+
+inline const char *PhysicsMaterialDataIdentifier() {
+  return "PMAT";
+}
+
+inline bool PhysicsMaterialDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, PhysicsMaterialDataIdentifier());
+}
+
+inline bool SizePrefixedPhysicsMaterialDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, PhysicsMaterialDataIdentifier(), true);
+}
+
+inline bool VerifyPhysicsMaterialDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::PhysicsMaterialData>(PhysicsMaterialDataIdentifier());
+}
+
+inline bool VerifySizePrefixedPhysicsMaterialDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::PhysicsMaterialData>(PhysicsMaterialDataIdentifier());
+}
+
+inline void FinishPhysicsMaterialDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::PhysicsMaterialData> root) {
+  fbb.Finish(root, PhysicsMaterialDataIdentifier());
+}
+
+inline void FinishSizePrefixedPhysicsMaterialDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::PhysicsMaterialData> root) {
+  fbb.FinishSizePrefixed(root, PhysicsMaterialDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_PHYSICSMATERIAL_PIXELCRAFT_ECS_H_
@@ -0,0 +1,372 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_COMBINE_MODE: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_COMBINE_MODE: i8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_COMBINE_MODE: [CombineMode; 4] = [
+  CombineMode::Average,
+  CombineMode::Min,
+  CombineMode::Max,
+  CombineMode::Multiply,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct CombineMode(pub i8);
+#[allow(non_upper_case_globals)]
+impl CombineMode {
+  pub const Average: Self = Self(0);
+  pub const Min: Self = Self(1);
+  pub const Max: Self = Self(2);
+  pub const Multiply: Self = Self(3);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Average,
+    Self::Min,
+    Self::Max,
+    Self::Multiply,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Average => Some("Average"),
+      Self::Min => Some("Min"),
+      Self::Max => Some("Max"),
+      Self::Multiply => Some("Multiply"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for CombineMode {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for CombineMode {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for CombineMode {
+    type Output = CombineMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for CombineMode {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for CombineMode {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for CombineMode {}
+pub enum PhysicsMaterialDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PhysicsMaterialData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PhysicsMaterialData<'a> {
+  type Inner = PhysicsMaterialData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> PhysicsMaterialData<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_STATIC_FRICTION: flatbuffers::VOffsetT = 6;
+  pub const VT_DYNAMIC_FRICTION: flatbuffers::VOffsetT = 8;
+  pub const VT_RESTITUTION: flatbuffers::VOffsetT = 10;
+  pub const VT_COMBINE_MODE: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PhysicsMaterialData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PhysicsMaterialDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<PhysicsMaterialData<'bldr>> {
+    let mut builder = PhysicsMaterialDataBuilder::new(_fbb);
+    builder.add_restitution(args.restitution);
+    builder.add_dynamic_friction(args.dynamic_friction);
+    builder.add_static_friction(args.static_friction);
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.add_combine_mode(args.combine_mode);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(PhysicsMaterialData::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn static_friction(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(PhysicsMaterialData::VT_STATIC_FRICTION, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn dynamic_friction(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(PhysicsMaterialData::VT_DYNAMIC_FRICTION, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn restitution(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(PhysicsMaterialData::VT_RESTITUTION, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn combine_mode(&self) -> CombineMode {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<CombineMode>(PhysicsMaterialData::VT_COMBINE_MODE, Some(CombineMode::Average)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for PhysicsMaterialData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<f32>("static_friction", Self::VT_STATIC_FRICTION, false)?
+     .visit_field::<f32>("dynamic_friction", Self::VT_DYNAMIC_FRICTION, false)?
+     .visit_field::<f32>("restitution", Self::VT_RESTITUTION, false)?
+     .visit_field::<CombineMode>("combine_mode", Self::VT_COMBINE_MODE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PhysicsMaterialDataArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub static_friction: f32,
+    pub dynamic_friction: f32,
+    pub restitution: f32,
+    pub combine_mode: CombineMode,
+}
+impl<'a> Default for PhysicsMaterialDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PhysicsMaterialDataArgs {
+      name: None,
+      static_friction: 0.0,
+      dynamic_friction: 0.0,
+      restitution: 0.0,
+      combine_mode: CombineMode::Average,
+    }
+  }
+}
+
+pub struct PhysicsMaterialDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PhysicsMaterialDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PhysicsMaterialData::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_static_friction(&mut self, static_friction: f32) {
+    self.fbb_.push_slot::<f32>(PhysicsMaterialData::VT_STATIC_FRICTION, static_friction, 0.0);
+  }
+  #[inline]
+  pub fn add_dynamic_friction(&mut self, dynamic_friction: f32) {
+    self.fbb_.push_slot::<f32>(PhysicsMaterialData::VT_DYNAMIC_FRICTION, dynamic_friction, 0.0);
+  }
+  #[inline]
+  pub fn add_restitution(&mut self, restitution: f32) {
+    self.fbb_.push_slot::<f32>(PhysicsMaterialData::VT_RESTITUTION, restitution, 0.0);
+  }
+  #[inline]
+  pub fn add_combine_mode(&mut self, combine_mode: CombineMode) {
+    self.fbb_.push_slot::<CombineMode>(PhysicsMaterialData::VT_COMBINE_MODE, combine_mode, CombineMode::Average);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PhysicsMaterialDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PhysicsMaterialDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PhysicsMaterialData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PhysicsMaterialData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PhysicsMaterialData");
+      ds.field("name", &self.name());
+      ds.field("static_friction", &self.static_friction());
+      ds.field("dynamic_friction", &self.dynamic_friction());
+      ds.field("restitution", &self.restitution());
+      ds.field("combine_mode", &self.combine_mode());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `PhysicsMaterialData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_physics_material_data_unchecked`.
+pub fn root_as_physics_material_data(buf: &[u8]) -> Result<PhysicsMaterialData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<PhysicsMaterialData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `PhysicsMaterialData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_physics_material_data_unchecked`.
+pub fn size_prefixed_root_as_physics_material_data(buf: &[u8]) -> Result<PhysicsMaterialData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<PhysicsMaterialData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `PhysicsMaterialData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_physics_material_data_unchecked`.
+pub fn root_as_physics_material_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PhysicsMaterialData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<PhysicsMaterialData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `PhysicsMaterialData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_physics_material_data_unchecked`.
+pub fn size_prefixed_root_as_physics_material_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<PhysicsMaterialData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<PhysicsMaterialData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a PhysicsMaterialData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `PhysicsMaterialData`.
+pub unsafe fn root_as_physics_material_data_unchecked(buf: &[u8]) -> PhysicsMaterialData {
+  flatbuffers::root_unchecked::<PhysicsMaterialData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed PhysicsMaterialData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `PhysicsMaterialData`.
+pub unsafe fn size_prefixed_root_as_physics_material_data_unchecked(buf: &[u8]) -> PhysicsMaterialData {
+  flatbuffers::size_prefixed_root_unchecked::<PhysicsMaterialData>(buf)
+}
+pub const PHYSICS_MATERIAL_DATA_IDENTIFIER: &str = "PMAT";
+
+#[inline]
+pub fn physics_material_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, PHYSICS_MATERIAL_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn physics_material_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, PHYSICS_MATERIAL_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_physics_material_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<PhysicsMaterialData<'a>>) {
+  fbb.finish(root, Some(PHYSICS_MATERIAL_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_physics_material_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<PhysicsMaterialData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(PHYSICS_MATERIAL_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
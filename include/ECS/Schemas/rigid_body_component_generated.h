@@ -64,7 +64,9 @@ struct RigidBodyComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::T
     VT_USE_GRAVITY = 12,
     VT_IS_KINEMATIC = 14,
     VT_LINEAR_VELOCITY = 16,
-    VT_ANGULAR_VELOCITY = 18
+    VT_ANGULAR_VELOCITY = 18,
+    VT_GRAVITY_SCALE = 20,
+    VT_LOCK_ROTATION = 22
   };
   PixelCraft::ECS::BodyType body_type() const {
     return static_cast<PixelCraft::ECS::BodyType>(GetField<int8_t>(VT_BODY_TYPE, 0));
@@ -90,6 +92,14 @@ struct RigidBodyComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::T
   const PixelCraft::ECS::Vec3 *angular_velocity() const {
     return GetStruct<const PixelCraft::ECS::Vec3 *>(VT_ANGULAR_VELOCITY);
   }
+  /// Multiplier on world gravity for this body; only applies with use_gravity.
+  float gravity_scale() const {
+    return GetField<float>(VT_GRAVITY_SCALE, 1.0f);
+  }
+  /// Freezes rotation so collisions only move the body (e.g. player characters).
+  bool lock_rotation() const {
+    return GetField<uint8_t>(VT_LOCK_ROTATION, 0) != 0;
+  }
   bool Verify(::flatbuffers::Verifier &verifier) const {
     return VerifyTableStart(verifier) &&
            VerifyField<int8_t>(verifier, VT_BODY_TYPE, 1) &&
@@ -100,6 +110,8 @@ struct RigidBodyComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::T
            VerifyField<uint8_t>(verifier, VT_IS_KINEMATIC, 1) &&
            VerifyField<PixelCraft::ECS::Vec3>(verifier, VT_LINEAR_VELOCITY, 4) &&
            VerifyField<PixelCraft::ECS::Vec3>(verifier, VT_ANGULAR_VELOCITY, 4) &&
+           VerifyField<float>(verifier, VT_GRAVITY_SCALE, 4) &&
+           VerifyField<uint8_t>(verifier, VT_LOCK_ROTATION, 1) &&
            verifier.EndTable();
   }
 };
@@ -132,6 +144,12 @@ struct RigidBodyComponentDataBuilder {
   void add_angular_velocity(const PixelCraft::ECS::Vec3 *angular_velocity) {
     fbb_.AddStruct(RigidBodyComponentData::VT_ANGULAR_VELOCITY, angular_velocity);
   }
+  void add_gravity_scale(float gravity_scale) {
+    fbb_.AddElement<float>(RigidBodyComponentData::VT_GRAVITY_SCALE, gravity_scale, 1.0f);
+  }
+  void add_lock_rotation(bool lock_rotation) {
+    fbb_.AddElement<uint8_t>(RigidBodyComponentData::VT_LOCK_ROTATION, static_cast<uint8_t>(lock_rotation), 0);
+  }
   explicit RigidBodyComponentDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
         : fbb_(_fbb) {
     start_ = fbb_.StartTable();
@@ -152,13 +170,17 @@ inline ::flatbuffers::Offset<RigidBodyComponentData> CreateRigidBodyComponentDat
     bool use_gravity = false,
     bool is_kinematic = false,
     const PixelCraft::ECS::Vec3 *linear_velocity = nullptr,
-    const PixelCraft::ECS::Vec3 *angular_velocity = nullptr) {
+    const PixelCraft::ECS::Vec3 *angular_velocity = nullptr,
+    float gravity_scale = 1.0f,
+    bool lock_rotation = false) {
   RigidBodyComponentDataBuilder builder_(_fbb);
+  builder_.add_gravity_scale(gravity_scale);
   builder_.add_angular_velocity(angular_velocity);
   builder_.add_linear_velocity(linear_velocity);
   builder_.add_angular_drag(angular_drag);
   builder_.add_drag(drag);
   builder_.add_mass(mass);
+  builder_.add_lock_rotation(lock_rotation);
   builder_.add_is_kinematic(is_kinematic);
   builder_.add_use_gravity(use_gravity);
   builder_.add_body_type(body_type);
@@ -142,6 +142,8 @@ impl<'a> RigidBodyComponentData<'a> {
   pub const VT_IS_KINEMATIC: flatbuffers::VOffsetT = 14;
   pub const VT_LINEAR_VELOCITY: flatbuffers::VOffsetT = 16;
   pub const VT_ANGULAR_VELOCITY: flatbuffers::VOffsetT = 18;
+  pub const VT_GRAVITY_SCALE: flatbuffers::VOffsetT = 20;
+  pub const VT_LOCK_ROTATION: flatbuffers::VOffsetT = 22;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -153,11 +155,13 @@ impl<'a> RigidBodyComponentData<'a> {
     args: &'args RigidBodyComponentDataArgs<'args>
   ) -> flatbuffers::WIPOffset<RigidBodyComponentData<'bldr>> {
     let mut builder = RigidBodyComponentDataBuilder::new(_fbb);
+    builder.add_gravity_scale(args.gravity_scale);
     if let Some(x) = args.angular_velocity { builder.add_angular_velocity(x); }
     if let Some(x) = args.linear_velocity { builder.add_linear_velocity(x); }
     builder.add_angular_drag(args.angular_drag);
     builder.add_drag(args.drag);
     builder.add_mass(args.mass);
+    builder.add_lock_rotation(args.lock_rotation);
     builder.add_is_kinematic(args.is_kinematic);
     builder.add_use_gravity(args.use_gravity);
     builder.add_body_type(args.body_type);
@@ -221,6 +225,22 @@ impl<'a> RigidBodyComponentData<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<Vec3>(RigidBodyComponentData::VT_ANGULAR_VELOCITY, None)}
   }
+  /// Multiplier on world gravity for this body; only applies with use_gravity.
+  #[inline]
+  pub fn gravity_scale(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(RigidBodyComponentData::VT_GRAVITY_SCALE, Some(1.0)).unwrap()}
+  }
+  /// Freezes rotation so collisions only move the body (e.g. player characters).
+  #[inline]
+  pub fn lock_rotation(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(RigidBodyComponentData::VT_LOCK_ROTATION, Some(false)).unwrap()}
+  }
 }
 
 impl flatbuffers::Verifiable for RigidBodyComponentData<'_> {
@@ -238,6 +258,8 @@ impl flatbuffers::Verifiable for RigidBodyComponentData<'_> {
      .visit_field::<bool>("is_kinematic", Self::VT_IS_KINEMATIC, false)?
      .visit_field::<Vec3>("linear_velocity", Self::VT_LINEAR_VELOCITY, false)?
      .visit_field::<Vec3>("angular_velocity", Self::VT_ANGULAR_VELOCITY, false)?
+     .visit_field::<f32>("gravity_scale", Self::VT_GRAVITY_SCALE, false)?
+     .visit_field::<bool>("lock_rotation", Self::VT_LOCK_ROTATION, false)?
      .finish();
     Ok(())
   }
@@ -251,6 +273,8 @@ pub struct RigidBodyComponentDataArgs<'a> {
     pub is_kinematic: bool,
     pub linear_velocity: Option<&'a Vec3>,
     pub angular_velocity: Option<&'a Vec3>,
+    pub gravity_scale: f32,
+    pub lock_rotation: bool,
 }
 impl<'a> Default for RigidBodyComponentDataArgs<'a> {
   #[inline]
@@ -264,6 +288,8 @@ impl<'a> Default for RigidBodyComponentDataArgs<'a> {
       is_kinematic: false,
       linear_velocity: None,
       angular_velocity: None,
+      gravity_scale: 1.0,
+      lock_rotation: false,
     }
   }
 }
@@ -306,6 +332,14 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RigidBodyComponentDataBuilder<'
     self.fbb_.push_slot_always::<&Vec3>(RigidBodyComponentData::VT_ANGULAR_VELOCITY, angular_velocity);
   }
   #[inline]
+  pub fn add_gravity_scale(&mut self, gravity_scale: f32) {
+    self.fbb_.push_slot::<f32>(RigidBodyComponentData::VT_GRAVITY_SCALE, gravity_scale, 1.0);
+  }
+  #[inline]
+  pub fn add_lock_rotation(&mut self, lock_rotation: bool) {
+    self.fbb_.push_slot::<bool>(RigidBodyComponentData::VT_LOCK_ROTATION, lock_rotation, false);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> RigidBodyComponentDataBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     RigidBodyComponentDataBuilder {
@@ -331,6 +365,8 @@ impl core::fmt::Debug for RigidBodyComponentData<'_> {
       ds.field("is_kinematic", &self.is_kinematic());
       ds.field("linear_velocity", &self.linear_velocity());
       ds.field("angular_velocity", &self.angular_velocity());
+      ds.field("gravity_scale", &self.gravity_scale());
+      ds.field("lock_rotation", &self.lock_rotation());
       ds.finish()
   }
 }
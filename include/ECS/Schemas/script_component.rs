@@ -0,0 +1,74 @@
+// Helpers for reading the generated `ScriptComponentData` table.
+
+use crate::script_component_generated::*;
+
+/// Value of the property named `key`, or `None` if it is not set.
+///
+/// Properties are searched in order, so the first entry wins if a key
+/// appears twice. A property without a value reads as `None`.
+pub fn script_property<'a>(data: &ScriptComponentData<'a>, key: &str) -> Option<&'a str> {
+    data.properties()?
+        .iter()
+        .find(|property| property.key() == Some(key))?
+        .value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs_component::load_component;
+
+    use flatbuffers::FlatBufferBuilder;
+
+    fn script_with(properties: &[(&str, Option<&str>)]) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let properties: Vec<_> = properties
+            .iter()
+            .map(|(key, value)| {
+                let key = fbb.create_string(key);
+                let value = value.map(|value| fbb.create_string(value));
+                ScriptProperty::create(
+                    &mut fbb,
+                    &ScriptPropertyArgs {
+                        key: Some(key),
+                        value,
+                    },
+                )
+            })
+            .collect();
+        let properties = fbb.create_vector(&properties);
+        let path = fbb.create_string("scripts/enemy.lua");
+        let class = fbb.create_string("Enemy");
+        let offset = ScriptComponentData::create(
+            &mut fbb,
+            &ScriptComponentDataArgs {
+                script_path: Some(path),
+                class_name: Some(class),
+                properties: Some(properties),
+                ..Default::default()
+            },
+        );
+        finish_script_component_data_buffer(&mut fbb, offset);
+        fbb.finished_data().to_vec()
+    }
+
+    #[test]
+    fn script_loads_and_properties_are_found_by_key() {
+        let buf = script_with(&[("speed", Some("4.5")), ("target", Some("player"))]);
+        let script = load_component::<ScriptComponentData>(&buf).unwrap();
+        assert_eq!(script.script_path(), Some("scripts/enemy.lua"));
+        assert_eq!(script.class_name(), Some("Enemy"));
+        assert!(script.enabled());
+        assert_eq!(script_property(&script, "speed"), Some("4.5"));
+        assert_eq!(script_property(&script, "target"), Some("player"));
+        assert_eq!(script_property(&script, "missing"), None);
+    }
+
+    #[test]
+    fn first_duplicate_key_wins_and_missing_values_read_as_none() {
+        let buf = script_with(&[("speed", Some("1")), ("speed", Some("2")), ("silent", None)]);
+        let script = root_as_script_component_data(&buf).unwrap();
+        assert_eq!(script_property(&script, "speed"), Some("1"));
+        assert_eq!(script_property(&script, "silent"), None);
+    }
+}
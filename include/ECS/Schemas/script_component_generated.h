@@ -0,0 +1,232 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_SCRIPTCOMPONENT_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_SCRIPTCOMPONENT_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+namespace PixelCraft {
+namespace ECS {
+
+struct ScriptProperty;
+struct ScriptPropertyBuilder;
+
+struct ScriptComponentData;
+struct ScriptComponentDataBuilder;
+
+/// One editor-exposed script setting, passed to the script as a string.
+struct ScriptProperty FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef ScriptPropertyBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_KEY = 4,
+    VT_VALUE = 6
+  };
+  const ::flatbuffers::String *key() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_KEY);
+  }
+  const ::flatbuffers::String *value() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_VALUE);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_KEY) &&
+           verifier.VerifyString(key()) &&
+           VerifyOffset(verifier, VT_VALUE) &&
+           verifier.VerifyString(value()) &&
+           verifier.EndTable();
+  }
+};
+
+struct ScriptPropertyBuilder {
+  typedef ScriptProperty Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_key(::flatbuffers::Offset<::flatbuffers::String> key) {
+    fbb_.AddOffset(ScriptProperty::VT_KEY, key);
+  }
+  void add_value(::flatbuffers::Offset<::flatbuffers::String> value) {
+    fbb_.AddOffset(ScriptProperty::VT_VALUE, value);
+  }
+  explicit ScriptPropertyBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<ScriptProperty> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<ScriptProperty>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<ScriptProperty> CreateScriptProperty(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> key = 0,
+    ::flatbuffers::Offset<::flatbuffers::String> value = 0) {
+  ScriptPropertyBuilder builder_(_fbb);
+  builder_.add_value(value);
+  builder_.add_key(key);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<ScriptProperty> CreateScriptPropertyDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *key = nullptr,
+    const char *value = nullptr) {
+  auto key__ = key ? _fbb.CreateString(key) : 0;
+  auto value__ = value ? _fbb.CreateString(value) : 0;
+  return PixelCraft::ECS::CreateScriptProperty(
+      _fbb,
+      key__,
+      value__);
+}
+
+struct ScriptComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef ScriptComponentDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_SCRIPT_PATH = 4,
+    VT_CLASS_NAME = 6,
+    VT_ENABLED = 8,
+    VT_PROPERTIES = 10
+  };
+  /// Script asset, relative to the project's asset root.
+  const ::flatbuffers::String *script_path() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_SCRIPT_PATH);
+  }
+  /// Class within the script to instantiate.
+  const ::flatbuffers::String *class_name() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_CLASS_NAME);
+  }
+  bool enabled() const {
+    return GetField<uint8_t>(VT_ENABLED, 1) != 0;
+  }
+  const ::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ScriptProperty>> *properties() const {
+    return GetPointer<const ::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ScriptProperty>> *>(VT_PROPERTIES);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_SCRIPT_PATH) &&
+           verifier.VerifyString(script_path()) &&
+           VerifyOffset(verifier, VT_CLASS_NAME) &&
+           verifier.VerifyString(class_name()) &&
+           VerifyField<uint8_t>(verifier, VT_ENABLED, 1) &&
+           VerifyOffset(verifier, VT_PROPERTIES) &&
+           verifier.VerifyVector(properties()) &&
+           verifier.VerifyVectorOfTables(properties()) &&
+           verifier.EndTable();
+  }
+};
+
+struct ScriptComponentDataBuilder {
+  typedef ScriptComponentData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_script_path(::flatbuffers::Offset<::flatbuffers::String> script_path) {
+    fbb_.AddOffset(ScriptComponentData::VT_SCRIPT_PATH, script_path);
+  }
+  void add_class_name(::flatbuffers::Offset<::flatbuffers::String> class_name) {
+    fbb_.AddOffset(ScriptComponentData::VT_CLASS_NAME, class_name);
+  }
+  void add_enabled(bool enabled) {
+    fbb_.AddElement<uint8_t>(ScriptComponentData::VT_ENABLED, static_cast<uint8_t>(enabled), 1);
+  }
+  void add_properties(::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ScriptProperty>>> properties) {
+    fbb_.AddOffset(ScriptComponentData::VT_PROPERTIES, properties);
+  }
+  explicit ScriptComponentDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<ScriptComponentData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<ScriptComponentData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<ScriptComponentData> CreateScriptComponentData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> script_path = 0,
+    ::flatbuffers::Offset<::flatbuffers::String> class_name = 0,
+    bool enabled = true,
+    ::flatbuffers::Offset<::flatbuffers::Vector<::flatbuffers::Offset<PixelCraft::ECS::ScriptProperty>>> properties = 0) {
+  ScriptComponentDataBuilder builder_(_fbb);
+  builder_.add_properties(properties);
+  builder_.add_class_name(class_name);
+  builder_.add_script_path(script_path);
+  builder_.add_enabled(enabled);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<ScriptComponentData> CreateScriptComponentDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *script_path = nullptr,
+    const char *class_name = nullptr,
+    bool enabled = true,
+    const std::vector<::flatbuffers::Offset<PixelCraft::ECS::ScriptProperty>> *properties = nullptr) {
+  auto script_path__ = script_path ? _fbb.CreateString(script_path) : 0;
+  auto class_name__ = class_name ? _fbb.CreateString(class_name) : 0;
+  auto properties__ = properties ? _fbb.CreateVector<::flatbuffers::Offset<PixelCraft::ECS::ScriptProperty>>(*properties) : 0;
+  return PixelCraft::ECS::CreateScriptComponentData(
+      _fbb,
+      script_path__,
+      class_name__,
+      enabled,
+      properties__);
+}
+
+inline const PixelCraft::ECS::ScriptComponentData *GetScriptComponentData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::ScriptComponentData>(buf);
+}
+
+inline const PixelCraft::ECS::ScriptComponentData *GetSizePrefixedScriptComponentData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::ScriptComponentData>(buf);
+}
+
+inline const char *ScriptComponentDataIdentifier() {
+  return "SCPT";
+}
+
+inline bool ScriptComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, ScriptComponentDataIdentifier());
+}
+
+inline bool SizePrefixedScriptComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, ScriptComponentDataIdentifier(), true);
+}
+
+inline bool VerifyScriptComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::ScriptComponentData>(ScriptComponentDataIdentifier());
+}
+
+inline bool VerifySizePrefixedScriptComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::ScriptComponentData>(ScriptComponentDataIdentifier());
+}
+
+inline void FinishScriptComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::ScriptComponentData> root) {
+  fbb.Finish(root, ScriptComponentDataIdentifier());
+}
+
+inline void FinishSizePrefixedScriptComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::ScriptComponentData> root) {
+  fbb.FinishSizePrefixed(root, ScriptComponentDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_SCRIPTCOMPONENT_PIXELCRAFT_ECS_H_
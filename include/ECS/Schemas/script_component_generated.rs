@@ -0,0 +1,379 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum ScriptPropertyOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+/// One editor-exposed script setting, passed to the script as a string.
+pub struct ScriptProperty<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScriptProperty<'a> {
+  type Inner = ScriptProperty<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ScriptProperty<'a> {
+  pub const VT_KEY: flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ScriptProperty { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ScriptPropertyArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScriptProperty<'bldr>> {
+    let mut builder = ScriptPropertyBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    if let Some(x) = args.key { builder.add_key(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn key(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ScriptProperty::VT_KEY, None)}
+  }
+  #[inline]
+  pub fn value(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ScriptProperty::VT_VALUE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ScriptProperty<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScriptPropertyArgs<'a> {
+    pub key: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub value: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for ScriptPropertyArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ScriptPropertyArgs {
+      key: None,
+      value: None,
+    }
+  }
+}
+
+pub struct ScriptPropertyBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScriptPropertyBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_key(&mut self, key: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptProperty::VT_KEY, key);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptProperty::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScriptPropertyBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ScriptPropertyBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScriptProperty<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ScriptProperty<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ScriptProperty");
+      ds.field("key", &self.key());
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum ScriptComponentDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScriptComponentData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScriptComponentData<'a> {
+  type Inner = ScriptComponentData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> ScriptComponentData<'a> {
+  pub const VT_SCRIPT_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_CLASS_NAME: flatbuffers::VOffsetT = 6;
+  pub const VT_ENABLED: flatbuffers::VOffsetT = 8;
+  pub const VT_PROPERTIES: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ScriptComponentData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ScriptComponentDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScriptComponentData<'bldr>> {
+    let mut builder = ScriptComponentDataBuilder::new(_fbb);
+    if let Some(x) = args.properties { builder.add_properties(x); }
+    if let Some(x) = args.class_name { builder.add_class_name(x); }
+    if let Some(x) = args.script_path { builder.add_script_path(x); }
+    builder.add_enabled(args.enabled);
+    builder.finish()
+  }
+
+
+  /// Script asset, relative to the project's asset root.
+  #[inline]
+  pub fn script_path(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ScriptComponentData::VT_SCRIPT_PATH, None)}
+  }
+  /// Class within the script to instantiate.
+  #[inline]
+  pub fn class_name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(ScriptComponentData::VT_CLASS_NAME, None)}
+  }
+  #[inline]
+  pub fn enabled(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ScriptComponentData::VT_ENABLED, Some(true)).unwrap()}
+  }
+  #[inline]
+  pub fn properties(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ScriptProperty<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ScriptProperty>>>>(ScriptComponentData::VT_PROPERTIES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ScriptComponentData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("script_path", Self::VT_SCRIPT_PATH, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("class_name", Self::VT_CLASS_NAME, false)?
+     .visit_field::<bool>("enabled", Self::VT_ENABLED, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<ScriptProperty>>>>("properties", Self::VT_PROPERTIES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScriptComponentDataArgs<'a> {
+    pub script_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub class_name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub enabled: bool,
+    pub properties: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<ScriptProperty<'a>>>>>,
+}
+impl<'a> Default for ScriptComponentDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ScriptComponentDataArgs {
+      script_path: None,
+      class_name: None,
+      enabled: true,
+      properties: None,
+    }
+  }
+}
+
+pub struct ScriptComponentDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScriptComponentDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_script_path(&mut self, script_path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptComponentData::VT_SCRIPT_PATH, script_path);
+  }
+  #[inline]
+  pub fn add_class_name(&mut self, class_name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptComponentData::VT_CLASS_NAME, class_name);
+  }
+  #[inline]
+  pub fn add_enabled(&mut self, enabled: bool) {
+    self.fbb_.push_slot::<bool>(ScriptComponentData::VT_ENABLED, enabled, true);
+  }
+  #[inline]
+  pub fn add_properties(&mut self, properties: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<ScriptProperty<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScriptComponentData::VT_PROPERTIES, properties);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScriptComponentDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ScriptComponentDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScriptComponentData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ScriptComponentData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ScriptComponentData");
+      ds.field("script_path", &self.script_path());
+      ds.field("class_name", &self.class_name());
+      ds.field("enabled", &self.enabled());
+      ds.field("properties", &self.properties());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `ScriptComponentData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_script_component_data_unchecked`.
+pub fn root_as_script_component_data(buf: &[u8]) -> Result<ScriptComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<ScriptComponentData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `ScriptComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_script_component_data_unchecked`.
+pub fn size_prefixed_root_as_script_component_data(buf: &[u8]) -> Result<ScriptComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<ScriptComponentData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `ScriptComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_script_component_data_unchecked`.
+pub fn root_as_script_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ScriptComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<ScriptComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `ScriptComponentData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_script_component_data_unchecked`.
+pub fn size_prefixed_root_as_script_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<ScriptComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<ScriptComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a ScriptComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `ScriptComponentData`.
+pub unsafe fn root_as_script_component_data_unchecked(buf: &[u8]) -> ScriptComponentData {
+  flatbuffers::root_unchecked::<ScriptComponentData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed ScriptComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `ScriptComponentData`.
+pub unsafe fn size_prefixed_root_as_script_component_data_unchecked(buf: &[u8]) -> ScriptComponentData {
+  flatbuffers::size_prefixed_root_unchecked::<ScriptComponentData>(buf)
+}
+pub const SCRIPT_COMPONENT_DATA_IDENTIFIER: &str = "SCPT";
+
+#[inline]
+pub fn script_component_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, SCRIPT_COMPONENT_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn script_component_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, SCRIPT_COMPONENT_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_script_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<ScriptComponentData<'a>>) {
+  fbb.finish(root, Some(SCRIPT_COMPONENT_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_script_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<ScriptComponentData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(SCRIPT_COMPONENT_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
@@ -0,0 +1,55 @@
+// Default-aware accessors for the generated `SpriteComponentData` table.
+
+use crate::common_types_generated::*;
+use crate::sprite_component_generated::*;
+
+/// `tint`, or opaque white (no tint) when the field is absent.
+pub fn sprite_tint(data: &SpriteComponentData) -> Color {
+    data.tint()
+        .copied()
+        .unwrap_or(Color::new(255, 255, 255, 255))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flatbuffers::FlatBufferBuilder;
+
+    #[test]
+    fn sprite_round_trips() {
+        let mut fbb = FlatBufferBuilder::new();
+        let texture_path = fbb.create_string("hero.png");
+        let source_rect = Vec4::new(0.0, 0.0, 16.0, 32.0);
+        let tint = Color::new(255, 0, 0, 128);
+        let offset = SpriteComponentData::create(
+            &mut fbb,
+            &SpriteComponentDataArgs {
+                texture_path: Some(texture_path),
+                source_rect: Some(&source_rect),
+                tint: Some(&tint),
+                flip_x: true,
+                layer: -2,
+                ..Default::default()
+            },
+        );
+        finish_sprite_component_data_buffer(&mut fbb, offset);
+        let sprite = root_as_sprite_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(sprite.texture_path(), Some("hero.png"));
+        assert_eq!(sprite.source_rect(), Some(&source_rect));
+        assert_eq!(sprite_tint(&sprite), tint);
+        assert!(sprite.flip_x());
+        assert!(!sprite.flip_y());
+        assert_eq!(sprite.layer(), -2);
+    }
+
+    #[test]
+    fn absent_fields_read_as_defaults() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = SpriteComponentData::create(&mut fbb, &Default::default());
+        finish_sprite_component_data_buffer(&mut fbb, offset);
+        let sprite = root_as_sprite_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(sprite.pixels_per_unit(), 16.0);
+        assert_eq!(sprite.source_rect(), None);
+        assert_eq!(sprite_tint(&sprite), Color::new(255, 255, 255, 255));
+    }
+}
@@ -0,0 +1,197 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_SPRITECOMPONENT_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_SPRITECOMPONENT_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+#include "common_types_generated.h"
+
+namespace PixelCraft {
+namespace ECS {
+
+struct SpriteComponentData;
+struct SpriteComponentDataBuilder;
+
+struct SpriteComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef SpriteComponentDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_TEXTURE_PATH = 4,
+    VT_SOURCE_RECT = 6,
+    VT_TINT = 8,
+    VT_FLIP_X = 10,
+    VT_FLIP_Y = 12,
+    VT_LAYER = 14,
+    VT_PIXELS_PER_UNIT = 16
+  };
+  const ::flatbuffers::String *texture_path() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_TEXTURE_PATH);
+  }
+  /// Region of the texture to draw, in pixels: x, y, width, height.
+  /// Absent means the whole texture.
+  const PixelCraft::ECS::Vec4 *source_rect() const {
+    return GetStruct<const PixelCraft::ECS::Vec4 *>(VT_SOURCE_RECT);
+  }
+  /// Multiplied with the texture color. Absent means opaque white.
+  const PixelCraft::ECS::Color *tint() const {
+    return GetStruct<const PixelCraft::ECS::Color *>(VT_TINT);
+  }
+  bool flip_x() const {
+    return GetField<uint8_t>(VT_FLIP_X, 0) != 0;
+  }
+  bool flip_y() const {
+    return GetField<uint8_t>(VT_FLIP_Y, 0) != 0;
+  }
+  int32_t layer() const {
+    return GetField<int32_t>(VT_LAYER, 0);
+  }
+  float pixels_per_unit() const {
+    return GetField<float>(VT_PIXELS_PER_UNIT, 16.0f);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_TEXTURE_PATH) &&
+           verifier.VerifyString(texture_path()) &&
+           VerifyField<PixelCraft::ECS::Vec4>(verifier, VT_SOURCE_RECT, 4) &&
+           VerifyField<PixelCraft::ECS::Color>(verifier, VT_TINT, 1) &&
+           VerifyField<uint8_t>(verifier, VT_FLIP_X, 1) &&
+           VerifyField<uint8_t>(verifier, VT_FLIP_Y, 1) &&
+           VerifyField<int32_t>(verifier, VT_LAYER, 4) &&
+           VerifyField<float>(verifier, VT_PIXELS_PER_UNIT, 4) &&
+           verifier.EndTable();
+  }
+};
+
+struct SpriteComponentDataBuilder {
+  typedef SpriteComponentData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_texture_path(::flatbuffers::Offset<::flatbuffers::String> texture_path) {
+    fbb_.AddOffset(SpriteComponentData::VT_TEXTURE_PATH, texture_path);
+  }
+  void add_source_rect(const PixelCraft::ECS::Vec4 *source_rect) {
+    fbb_.AddStruct(SpriteComponentData::VT_SOURCE_RECT, source_rect);
+  }
+  void add_tint(const PixelCraft::ECS::Color *tint) {
+    fbb_.AddStruct(SpriteComponentData::VT_TINT, tint);
+  }
+  void add_flip_x(bool flip_x) {
+    fbb_.AddElement<uint8_t>(SpriteComponentData::VT_FLIP_X, static_cast<uint8_t>(flip_x), 0);
+  }
+  void add_flip_y(bool flip_y) {
+    fbb_.AddElement<uint8_t>(SpriteComponentData::VT_FLIP_Y, static_cast<uint8_t>(flip_y), 0);
+  }
+  void add_layer(int32_t layer) {
+    fbb_.AddElement<int32_t>(SpriteComponentData::VT_LAYER, layer, 0);
+  }
+  void add_pixels_per_unit(float pixels_per_unit) {
+    fbb_.AddElement<float>(SpriteComponentData::VT_PIXELS_PER_UNIT, pixels_per_unit, 16.0f);
+  }
+  explicit SpriteComponentDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<SpriteComponentData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<SpriteComponentData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<SpriteComponentData> CreateSpriteComponentData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> texture_path = 0,
+    const PixelCraft::ECS::Vec4 *source_rect = nullptr,
+    const PixelCraft::ECS::Color *tint = nullptr,
+    bool flip_x = false,
+    bool flip_y = false,
+    int32_t layer = 0,
+    float pixels_per_unit = 16.0f) {
+  SpriteComponentDataBuilder builder_(_fbb);
+  builder_.add_pixels_per_unit(pixels_per_unit);
+  builder_.add_layer(layer);
+  builder_.add_tint(tint);
+  builder_.add_source_rect(source_rect);
+  builder_.add_texture_path(texture_path);
+  builder_.add_flip_y(flip_y);
+  builder_.add_flip_x(flip_x);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<SpriteComponentData> CreateSpriteComponentDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *texture_path = nullptr,
+    const PixelCraft::ECS::Vec4 *source_rect = nullptr,
+    const PixelCraft::ECS::Color *tint = nullptr,
+    bool flip_x = false,
+    bool flip_y = false,
+    int32_t layer = 0,
+    float pixels_per_unit = 16.0f) {
+  auto texture_path__ = texture_path ? _fbb.CreateString(texture_path) : 0;
+  return PixelCraft::ECS::CreateSpriteComponentData(
+      _fbb,
+      texture_path__,
+      source_rect,
+      tint,
+      flip_x,
+      flip_y,
+      layer,
+      pixels_per_unit);
+}
+
+inline const PixelCraft::ECS::SpriteComponentData *GetSpriteComponentData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::SpriteComponentData>(buf);
+}
+
+inline const PixelCraft::ECS::SpriteComponentData *GetSizePrefixedSpriteComponentData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::SpriteComponentData>(buf);
+}
+
+inline const char *SpriteComponentDataIdentifier() {
+  return "SPRT";
+}
+
+inline bool SpriteComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, SpriteComponentDataIdentifier());
+}
+
+inline bool SizePrefixedSpriteComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, SpriteComponentDataIdentifier(), true);
+}
+
+inline bool VerifySpriteComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::SpriteComponentData>(SpriteComponentDataIdentifier());
+}
+
+inline bool VerifySizePrefixedSpriteComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::SpriteComponentData>(SpriteComponentDataIdentifier());
+}
+
+inline void FinishSpriteComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::SpriteComponentData> root) {
+  fbb.Finish(root, SpriteComponentDataIdentifier());
+}
+
+inline void FinishSizePrefixedSpriteComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::SpriteComponentData> root) {
+  fbb.FinishSizePrefixed(root, SpriteComponentDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_SPRITECOMPONENT_PIXELCRAFT_ECS_H_
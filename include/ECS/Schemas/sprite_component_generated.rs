@@ -0,0 +1,319 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use crate::common_types_generated::*;
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use crate::common_types_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use crate::common_types_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum SpriteComponentDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpriteComponentData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpriteComponentData<'a> {
+  type Inner = SpriteComponentData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> SpriteComponentData<'a> {
+  pub const VT_TEXTURE_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_SOURCE_RECT: flatbuffers::VOffsetT = 6;
+  pub const VT_TINT: flatbuffers::VOffsetT = 8;
+  pub const VT_FLIP_X: flatbuffers::VOffsetT = 10;
+  pub const VT_FLIP_Y: flatbuffers::VOffsetT = 12;
+  pub const VT_LAYER: flatbuffers::VOffsetT = 14;
+  pub const VT_PIXELS_PER_UNIT: flatbuffers::VOffsetT = 16;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    SpriteComponentData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args SpriteComponentDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<SpriteComponentData<'bldr>> {
+    let mut builder = SpriteComponentDataBuilder::new(_fbb);
+    builder.add_pixels_per_unit(args.pixels_per_unit);
+    builder.add_layer(args.layer);
+    if let Some(x) = args.tint { builder.add_tint(x); }
+    if let Some(x) = args.source_rect { builder.add_source_rect(x); }
+    if let Some(x) = args.texture_path { builder.add_texture_path(x); }
+    builder.add_flip_y(args.flip_y);
+    builder.add_flip_x(args.flip_x);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn texture_path(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(SpriteComponentData::VT_TEXTURE_PATH, None)}
+  }
+  /// Region of the texture to draw, in pixels: x, y, width, height.
+  /// Absent means the whole texture.
+  #[inline]
+  pub fn source_rect(&self) -> Option<&'a Vec4> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec4>(SpriteComponentData::VT_SOURCE_RECT, None)}
+  }
+  /// Multiplied with the texture color. Absent means opaque white.
+  #[inline]
+  pub fn tint(&self) -> Option<&'a Color> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Color>(SpriteComponentData::VT_TINT, None)}
+  }
+  #[inline]
+  pub fn flip_x(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SpriteComponentData::VT_FLIP_X, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn flip_y(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(SpriteComponentData::VT_FLIP_Y, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn layer(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(SpriteComponentData::VT_LAYER, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn pixels_per_unit(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(SpriteComponentData::VT_PIXELS_PER_UNIT, Some(16.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for SpriteComponentData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("texture_path", Self::VT_TEXTURE_PATH, false)?
+     .visit_field::<Vec4>("source_rect", Self::VT_SOURCE_RECT, false)?
+     .visit_field::<Color>("tint", Self::VT_TINT, false)?
+     .visit_field::<bool>("flip_x", Self::VT_FLIP_X, false)?
+     .visit_field::<bool>("flip_y", Self::VT_FLIP_Y, false)?
+     .visit_field::<i32>("layer", Self::VT_LAYER, false)?
+     .visit_field::<f32>("pixels_per_unit", Self::VT_PIXELS_PER_UNIT, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct SpriteComponentDataArgs<'a> {
+    pub texture_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub source_rect: Option<&'a Vec4>,
+    pub tint: Option<&'a Color>,
+    pub flip_x: bool,
+    pub flip_y: bool,
+    pub layer: i32,
+    pub pixels_per_unit: f32,
+}
+impl<'a> Default for SpriteComponentDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    SpriteComponentDataArgs {
+      texture_path: None,
+      source_rect: None,
+      tint: None,
+      flip_x: false,
+      flip_y: false,
+      layer: 0,
+      pixels_per_unit: 16.0,
+    }
+  }
+}
+
+pub struct SpriteComponentDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpriteComponentDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_texture_path(&mut self, texture_path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(SpriteComponentData::VT_TEXTURE_PATH, texture_path);
+  }
+  #[inline]
+  pub fn add_source_rect(&mut self, source_rect: &Vec4) {
+    self.fbb_.push_slot_always::<&Vec4>(SpriteComponentData::VT_SOURCE_RECT, source_rect);
+  }
+  #[inline]
+  pub fn add_tint(&mut self, tint: &Color) {
+    self.fbb_.push_slot_always::<&Color>(SpriteComponentData::VT_TINT, tint);
+  }
+  #[inline]
+  pub fn add_flip_x(&mut self, flip_x: bool) {
+    self.fbb_.push_slot::<bool>(SpriteComponentData::VT_FLIP_X, flip_x, false);
+  }
+  #[inline]
+  pub fn add_flip_y(&mut self, flip_y: bool) {
+    self.fbb_.push_slot::<bool>(SpriteComponentData::VT_FLIP_Y, flip_y, false);
+  }
+  #[inline]
+  pub fn add_layer(&mut self, layer: i32) {
+    self.fbb_.push_slot::<i32>(SpriteComponentData::VT_LAYER, layer, 0);
+  }
+  #[inline]
+  pub fn add_pixels_per_unit(&mut self, pixels_per_unit: f32) {
+    self.fbb_.push_slot::<f32>(SpriteComponentData::VT_PIXELS_PER_UNIT, pixels_per_unit, 16.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SpriteComponentDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    SpriteComponentDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<SpriteComponentData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for SpriteComponentData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("SpriteComponentData");
+      ds.field("texture_path", &self.texture_path());
+      ds.field("source_rect", &self.source_rect());
+      ds.field("tint", &self.tint());
+      ds.field("flip_x", &self.flip_x());
+      ds.field("flip_y", &self.flip_y());
+      ds.field("layer", &self.layer());
+      ds.field("pixels_per_unit", &self.pixels_per_unit());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `SpriteComponentData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_sprite_component_data_unchecked`.
+pub fn root_as_sprite_component_data(buf: &[u8]) -> Result<SpriteComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<SpriteComponentData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `SpriteComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_sprite_component_data_unchecked`.
+pub fn size_prefixed_root_as_sprite_component_data(buf: &[u8]) -> Result<SpriteComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<SpriteComponentData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `SpriteComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_sprite_component_data_unchecked`.
+pub fn root_as_sprite_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<SpriteComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<SpriteComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `SpriteComponentData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_sprite_component_data_unchecked`.
+pub fn size_prefixed_root_as_sprite_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<SpriteComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<SpriteComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a SpriteComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `SpriteComponentData`.
+pub unsafe fn root_as_sprite_component_data_unchecked(buf: &[u8]) -> SpriteComponentData {
+  flatbuffers::root_unchecked::<SpriteComponentData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed SpriteComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `SpriteComponentData`.
+pub unsafe fn size_prefixed_root_as_sprite_component_data_unchecked(buf: &[u8]) -> SpriteComponentData {
+  flatbuffers::size_prefixed_root_unchecked::<SpriteComponentData>(buf)
+}
+pub const SPRITE_COMPONENT_DATA_IDENTIFIER: &str = "SPRT";
+
+#[inline]
+pub fn sprite_component_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, SPRITE_COMPONENT_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn sprite_component_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, SPRITE_COMPONENT_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_sprite_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<SpriteComponentData<'a>>) {
+  fbb.finish(root, Some(SPRITE_COMPONENT_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_sprite_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<SpriteComponentData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(SPRITE_COMPONENT_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
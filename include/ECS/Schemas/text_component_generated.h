@@ -0,0 +1,220 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_TEXTCOMPONENT_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_TEXTCOMPONENT_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+#include "common_types_generated.h"
+
+namespace PixelCraft {
+namespace ECS {
+
+struct TextComponentData;
+struct TextComponentDataBuilder;
+
+/// Horizontal alignment of each line within the text block.
+enum TextAlignment : int8_t {
+  TextAlignment_Left = 0,
+  TextAlignment_Center = 1,
+  TextAlignment_Right = 2,
+  TextAlignment_MIN = TextAlignment_Left,
+  TextAlignment_MAX = TextAlignment_Right
+};
+
+inline const TextAlignment (&EnumValuesTextAlignment())[3] {
+  static const TextAlignment values[] = {
+    TextAlignment_Left,
+    TextAlignment_Center,
+    TextAlignment_Right
+  };
+  return values;
+}
+
+inline const char * const *EnumNamesTextAlignment() {
+  static const char * const names[4] = {
+    "Left",
+    "Center",
+    "Right",
+    nullptr
+  };
+  return names;
+}
+
+inline const char *EnumNameTextAlignment(TextAlignment e) {
+  if (::flatbuffers::IsOutRange(e, TextAlignment_Left, TextAlignment_Right)) return "";
+  const size_t index = static_cast<size_t>(e);
+  return EnumNamesTextAlignment()[index];
+}
+
+struct TextComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef TextComponentDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_CONTENT = 4,
+    VT_FONT_PATH = 6,
+    VT_FONT_SIZE = 8,
+    VT_COLOR = 10,
+    VT_ALIGNMENT = 12,
+    VT_WRAP_WIDTH = 14
+  };
+  const ::flatbuffers::String *content() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_CONTENT);
+  }
+  const ::flatbuffers::String *font_path() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_FONT_PATH);
+  }
+  /// Font size in points.
+  float font_size() const {
+    return GetField<float>(VT_FONT_SIZE, 16.0f);
+  }
+  const PixelCraft::ECS::Color *color() const {
+    return GetStruct<const PixelCraft::ECS::Color *>(VT_COLOR);
+  }
+  PixelCraft::ECS::TextAlignment alignment() const {
+    return static_cast<PixelCraft::ECS::TextAlignment>(GetField<int8_t>(VT_ALIGNMENT, 0));
+  }
+  /// Width at which lines wrap; 0 = no wrap.
+  float wrap_width() const {
+    return GetField<float>(VT_WRAP_WIDTH, 0.0f);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_CONTENT) &&
+           verifier.VerifyString(content()) &&
+           VerifyOffset(verifier, VT_FONT_PATH) &&
+           verifier.VerifyString(font_path()) &&
+           VerifyField<float>(verifier, VT_FONT_SIZE, 4) &&
+           VerifyField<PixelCraft::ECS::Color>(verifier, VT_COLOR, 1) &&
+           VerifyField<int8_t>(verifier, VT_ALIGNMENT, 1) &&
+           VerifyField<float>(verifier, VT_WRAP_WIDTH, 4) &&
+           verifier.EndTable();
+  }
+};
+
+struct TextComponentDataBuilder {
+  typedef TextComponentData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_content(::flatbuffers::Offset<::flatbuffers::String> content) {
+    fbb_.AddOffset(TextComponentData::VT_CONTENT, content);
+  }
+  void add_font_path(::flatbuffers::Offset<::flatbuffers::String> font_path) {
+    fbb_.AddOffset(TextComponentData::VT_FONT_PATH, font_path);
+  }
+  void add_font_size(float font_size) {
+    fbb_.AddElement<float>(TextComponentData::VT_FONT_SIZE, font_size, 16.0f);
+  }
+  void add_color(const PixelCraft::ECS::Color *color) {
+    fbb_.AddStruct(TextComponentData::VT_COLOR, color);
+  }
+  void add_alignment(PixelCraft::ECS::TextAlignment alignment) {
+    fbb_.AddElement<int8_t>(TextComponentData::VT_ALIGNMENT, static_cast<int8_t>(alignment), 0);
+  }
+  void add_wrap_width(float wrap_width) {
+    fbb_.AddElement<float>(TextComponentData::VT_WRAP_WIDTH, wrap_width, 0.0f);
+  }
+  explicit TextComponentDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<TextComponentData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<TextComponentData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<TextComponentData> CreateTextComponentData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> content = 0,
+    ::flatbuffers::Offset<::flatbuffers::String> font_path = 0,
+    float font_size = 16.0f,
+    const PixelCraft::ECS::Color *color = nullptr,
+    PixelCraft::ECS::TextAlignment alignment = PixelCraft::ECS::TextAlignment_Left,
+    float wrap_width = 0.0f) {
+  TextComponentDataBuilder builder_(_fbb);
+  builder_.add_wrap_width(wrap_width);
+  builder_.add_color(color);
+  builder_.add_font_size(font_size);
+  builder_.add_font_path(font_path);
+  builder_.add_content(content);
+  builder_.add_alignment(alignment);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<TextComponentData> CreateTextComponentDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *content = nullptr,
+    const char *font_path = nullptr,
+    float font_size = 16.0f,
+    const PixelCraft::ECS::Color *color = nullptr,
+    PixelCraft::ECS::TextAlignment alignment = PixelCraft::ECS::TextAlignment_Left,
+    float wrap_width = 0.0f) {
+  auto content__ = content ? _fbb.CreateString(content) : 0;
+  auto font_path__ = font_path ? _fbb.CreateString(font_path) : 0;
+  return PixelCraft::ECS::CreateTextComponentData(
+      _fbb,
+      content__,
+      font_path__,
+      font_size,
+      color,
+      alignment,
+      wrap_width);
+}
+
+inline const PixelCraft::ECS::TextComponentData *GetTextComponentData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::TextComponentData>(buf);
+}
+
+inline const PixelCraft::ECS::TextComponentData *GetSizePrefixedTextComponentData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::TextComponentData>(buf);
+}
+
+inline const char *TextComponentDataIdentifier() {
+  return "TEXT";
+}
+
+inline bool TextComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, TextComponentDataIdentifier());
+}
+
+inline bool SizePrefixedTextComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, TextComponentDataIdentifier(), true);
+}
+
+inline bool VerifyTextComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::TextComponentData>(TextComponentDataIdentifier());
+}
+
+inline bool VerifySizePrefixedTextComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::TextComponentData>(TextComponentDataIdentifier());
+}
+
+inline void FinishTextComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::TextComponentData> root) {
+  fbb.Finish(root, TextComponentDataIdentifier());
+}
+
+inline void FinishSizePrefixedTextComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::TextComponentData> root) {
+  fbb.FinishSizePrefixed(root, TextComponentDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_TEXTCOMPONENT_PIXELCRAFT_ECS_H_
@@ -0,0 +1,391 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use crate::common_types_generated::*;
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use crate::common_types_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use crate::common_types_generated::*;
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_TEXT_ALIGNMENT: i8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_TEXT_ALIGNMENT: i8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_TEXT_ALIGNMENT: [TextAlignment; 3] = [
+  TextAlignment::Left,
+  TextAlignment::Center,
+  TextAlignment::Right,
+];
+
+/// Horizontal alignment of each line within the text block.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct TextAlignment(pub i8);
+#[allow(non_upper_case_globals)]
+impl TextAlignment {
+  pub const Left: Self = Self(0);
+  pub const Center: Self = Self(1);
+  pub const Right: Self = Self(2);
+
+  pub const ENUM_MIN: i8 = 0;
+  pub const ENUM_MAX: i8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Left,
+    Self::Center,
+    Self::Right,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Left => Some("Left"),
+      Self::Center => Some("Center"),
+      Self::Right => Some("Right"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for TextAlignment {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for TextAlignment {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = flatbuffers::read_scalar_at::<i8>(buf, loc);
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for TextAlignment {
+    type Output = TextAlignment;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        flatbuffers::emplace_scalar::<i8>(dst, self.0);
+    }
+}
+
+impl flatbuffers::EndianScalar for TextAlignment {
+  type Scalar = i8;
+  #[inline]
+  fn to_little_endian(self) -> i8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i8) -> Self {
+    let b = i8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for TextAlignment {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for TextAlignment {}
+pub enum TextComponentDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TextComponentData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TextComponentData<'a> {
+  type Inner = TextComponentData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TextComponentData<'a> {
+  pub const VT_CONTENT: flatbuffers::VOffsetT = 4;
+  pub const VT_FONT_PATH: flatbuffers::VOffsetT = 6;
+  pub const VT_FONT_SIZE: flatbuffers::VOffsetT = 8;
+  pub const VT_COLOR: flatbuffers::VOffsetT = 10;
+  pub const VT_ALIGNMENT: flatbuffers::VOffsetT = 12;
+  pub const VT_WRAP_WIDTH: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TextComponentData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TextComponentDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<TextComponentData<'bldr>> {
+    let mut builder = TextComponentDataBuilder::new(_fbb);
+    builder.add_wrap_width(args.wrap_width);
+    if let Some(x) = args.color { builder.add_color(x); }
+    builder.add_font_size(args.font_size);
+    if let Some(x) = args.font_path { builder.add_font_path(x); }
+    if let Some(x) = args.content { builder.add_content(x); }
+    builder.add_alignment(args.alignment);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn content(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TextComponentData::VT_CONTENT, None)}
+  }
+  #[inline]
+  pub fn font_path(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TextComponentData::VT_FONT_PATH, None)}
+  }
+  /// Font size in points.
+  #[inline]
+  pub fn font_size(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(TextComponentData::VT_FONT_SIZE, Some(16.0)).unwrap()}
+  }
+  #[inline]
+  pub fn color(&self) -> Option<&'a Color> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Color>(TextComponentData::VT_COLOR, None)}
+  }
+  #[inline]
+  pub fn alignment(&self) -> TextAlignment {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<TextAlignment>(TextComponentData::VT_ALIGNMENT, Some(TextAlignment::Left)).unwrap()}
+  }
+  /// Width at which lines wrap; 0 = no wrap.
+  #[inline]
+  pub fn wrap_width(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(TextComponentData::VT_WRAP_WIDTH, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for TextComponentData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("content", Self::VT_CONTENT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("font_path", Self::VT_FONT_PATH, false)?
+     .visit_field::<f32>("font_size", Self::VT_FONT_SIZE, false)?
+     .visit_field::<Color>("color", Self::VT_COLOR, false)?
+     .visit_field::<TextAlignment>("alignment", Self::VT_ALIGNMENT, false)?
+     .visit_field::<f32>("wrap_width", Self::VT_WRAP_WIDTH, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TextComponentDataArgs<'a> {
+    pub content: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub font_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub font_size: f32,
+    pub color: Option<&'a Color>,
+    pub alignment: TextAlignment,
+    pub wrap_width: f32,
+}
+impl<'a> Default for TextComponentDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TextComponentDataArgs {
+      content: None,
+      font_path: None,
+      font_size: 16.0,
+      color: None,
+      alignment: TextAlignment::Left,
+      wrap_width: 0.0,
+    }
+  }
+}
+
+pub struct TextComponentDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TextComponentDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_content(&mut self, content: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TextComponentData::VT_CONTENT, content);
+  }
+  #[inline]
+  pub fn add_font_path(&mut self, font_path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TextComponentData::VT_FONT_PATH, font_path);
+  }
+  #[inline]
+  pub fn add_font_size(&mut self, font_size: f32) {
+    self.fbb_.push_slot::<f32>(TextComponentData::VT_FONT_SIZE, font_size, 16.0);
+  }
+  #[inline]
+  pub fn add_color(&mut self, color: &Color) {
+    self.fbb_.push_slot_always::<&Color>(TextComponentData::VT_COLOR, color);
+  }
+  #[inline]
+  pub fn add_alignment(&mut self, alignment: TextAlignment) {
+    self.fbb_.push_slot::<TextAlignment>(TextComponentData::VT_ALIGNMENT, alignment, TextAlignment::Left);
+  }
+  #[inline]
+  pub fn add_wrap_width(&mut self, wrap_width: f32) {
+    self.fbb_.push_slot::<f32>(TextComponentData::VT_WRAP_WIDTH, wrap_width, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TextComponentDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TextComponentDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TextComponentData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TextComponentData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TextComponentData");
+      ds.field("content", &self.content());
+      ds.field("font_path", &self.font_path());
+      ds.field("font_size", &self.font_size());
+      ds.field("color", &self.color());
+      ds.field("alignment", &self.alignment());
+      ds.field("wrap_width", &self.wrap_width());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `TextComponentData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_text_component_data_unchecked`.
+pub fn root_as_text_component_data(buf: &[u8]) -> Result<TextComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<TextComponentData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `TextComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_text_component_data_unchecked`.
+pub fn size_prefixed_root_as_text_component_data(buf: &[u8]) -> Result<TextComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<TextComponentData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `TextComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_text_component_data_unchecked`.
+pub fn root_as_text_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<TextComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<TextComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `TextComponentData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_text_component_data_unchecked`.
+pub fn size_prefixed_root_as_text_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<TextComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<TextComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a TextComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `TextComponentData`.
+pub unsafe fn root_as_text_component_data_unchecked(buf: &[u8]) -> TextComponentData {
+  flatbuffers::root_unchecked::<TextComponentData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed TextComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `TextComponentData`.
+pub unsafe fn size_prefixed_root_as_text_component_data_unchecked(buf: &[u8]) -> TextComponentData {
+  flatbuffers::size_prefixed_root_unchecked::<TextComponentData>(buf)
+}
+pub const TEXT_COMPONENT_DATA_IDENTIFIER: &str = "TEXT";
+
+#[inline]
+pub fn text_component_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, TEXT_COMPONENT_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn text_component_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, TEXT_COMPONENT_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_text_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<TextComponentData<'a>>) {
+  fbb.finish(root, Some(TEXT_COMPONENT_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_text_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<TextComponentData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(TEXT_COMPONENT_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
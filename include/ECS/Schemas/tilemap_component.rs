@@ -0,0 +1,67 @@
+// Helpers for reading the generated `TilemapComponentData` table.
+
+use crate::tilemap_component_generated::*;
+
+/// Tile index at column `x`, row `y`, or `None` if the cell is outside the
+/// map or missing from a `tiles` vector shorter than `columns * rows`.
+pub fn tile_at(data: &TilemapComponentData, x: u32, y: u32) -> Option<u32> {
+    if x >= data.columns() || y >= data.rows() {
+        return None;
+    }
+    let index = y as usize * data.columns() as usize + x as usize;
+    let tiles = data.tiles()?;
+    (index < tiles.len()).then(|| tiles.get(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs_component::load_component;
+
+    use flatbuffers::FlatBufferBuilder;
+
+    fn tilemap(columns: u32, rows: u32, tiles: &[u32]) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let tiles = fbb.create_vector(tiles);
+        let path = fbb.create_string("tiles/grass.png");
+        let offset = TilemapComponentData::create(
+            &mut fbb,
+            &TilemapComponentDataArgs {
+                tileset_path: Some(path),
+                tile_width: 16,
+                tile_height: 16,
+                columns,
+                rows,
+                tiles: Some(tiles),
+            },
+        );
+        finish_tilemap_component_data_buffer(&mut fbb, offset);
+        fbb.finished_data().to_vec()
+    }
+
+    #[test]
+    fn tiles_are_read_row_major() {
+        let buf = tilemap(3, 2, &[1, 2, 3, 4, 5, 6]);
+        let map = load_component::<TilemapComponentData>(&buf).unwrap();
+        assert_eq!(map.tileset_path(), Some("tiles/grass.png"));
+        assert_eq!(map.tile_width(), 16);
+        assert_eq!(tile_at(&map, 0, 0), Some(1));
+        assert_eq!(tile_at(&map, 2, 0), Some(3));
+        assert_eq!(tile_at(&map, 0, 1), Some(4));
+        assert_eq!(tile_at(&map, 2, 1), Some(6));
+    }
+
+    #[test]
+    fn cells_outside_the_map_or_the_tiles_vector_are_none() {
+        let buf = tilemap(3, 2, &[1, 2, 3, 4, 5, 6]);
+        let map = root_as_tilemap_component_data(&buf).unwrap();
+        assert_eq!(tile_at(&map, 3, 0), None);
+        assert_eq!(tile_at(&map, 0, 2), None);
+        assert_eq!(tile_at(&map, u32::MAX, u32::MAX), None);
+
+        let buf = tilemap(3, 2, &[1]);
+        let short = root_as_tilemap_component_data(&buf).unwrap();
+        assert_eq!(tile_at(&short, 0, 0), Some(1));
+        assert_eq!(tile_at(&short, 1, 0), None);
+    }
+}
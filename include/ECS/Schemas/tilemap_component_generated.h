@@ -0,0 +1,185 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+#ifndef FLATBUFFERS_GENERATED_TILEMAPCOMPONENT_PIXELCRAFT_ECS_H_
+#define FLATBUFFERS_GENERATED_TILEMAPCOMPONENT_PIXELCRAFT_ECS_H_
+
+#include "flatbuffers/flatbuffers.h"
+
+// Ensure the included flatbuffers.h is the same version as when this file was
+// generated, otherwise it may not be compatible.
+static_assert(FLATBUFFERS_VERSION_MAJOR == 25 &&
+              FLATBUFFERS_VERSION_MINOR == 2 &&
+              FLATBUFFERS_VERSION_REVISION == 10,
+             "Non-compatible flatbuffers version included");
+
+namespace PixelCraft {
+namespace ECS {
+
+struct TilemapComponentData;
+struct TilemapComponentDataBuilder;
+
+struct TilemapComponentData FLATBUFFERS_FINAL_CLASS : private ::flatbuffers::Table {
+  typedef TilemapComponentDataBuilder Builder;
+  enum FlatBuffersVTableOffset FLATBUFFERS_VTABLE_UNDERLYING_TYPE {
+    VT_TILESET_PATH = 4,
+    VT_TILE_WIDTH = 6,
+    VT_TILE_HEIGHT = 8,
+    VT_COLUMNS = 10,
+    VT_ROWS = 12,
+    VT_TILES = 14
+  };
+  /// Tileset image, relative to the project's asset root.
+  const ::flatbuffers::String *tileset_path() const {
+    return GetPointer<const ::flatbuffers::String *>(VT_TILESET_PATH);
+  }
+  /// Size of one tile in pixels.
+  uint32_t tile_width() const {
+    return GetField<uint32_t>(VT_TILE_WIDTH, 0);
+  }
+  uint32_t tile_height() const {
+    return GetField<uint32_t>(VT_TILE_HEIGHT, 0);
+  }
+  uint32_t columns() const {
+    return GetField<uint32_t>(VT_COLUMNS, 0);
+  }
+  uint32_t rows() const {
+    return GetField<uint32_t>(VT_ROWS, 0);
+  }
+  /// Tile index for each cell in row-major order, columns * rows entries.
+  const ::flatbuffers::Vector<uint32_t> *tiles() const {
+    return GetPointer<const ::flatbuffers::Vector<uint32_t> *>(VT_TILES);
+  }
+  bool Verify(::flatbuffers::Verifier &verifier) const {
+    return VerifyTableStart(verifier) &&
+           VerifyOffset(verifier, VT_TILESET_PATH) &&
+           verifier.VerifyString(tileset_path()) &&
+           VerifyField<uint32_t>(verifier, VT_TILE_WIDTH, 4) &&
+           VerifyField<uint32_t>(verifier, VT_TILE_HEIGHT, 4) &&
+           VerifyField<uint32_t>(verifier, VT_COLUMNS, 4) &&
+           VerifyField<uint32_t>(verifier, VT_ROWS, 4) &&
+           VerifyOffset(verifier, VT_TILES) &&
+           verifier.VerifyVector(tiles()) &&
+           verifier.EndTable();
+  }
+};
+
+struct TilemapComponentDataBuilder {
+  typedef TilemapComponentData Table;
+  ::flatbuffers::FlatBufferBuilder &fbb_;
+  ::flatbuffers::uoffset_t start_;
+  void add_tileset_path(::flatbuffers::Offset<::flatbuffers::String> tileset_path) {
+    fbb_.AddOffset(TilemapComponentData::VT_TILESET_PATH, tileset_path);
+  }
+  void add_tile_width(uint32_t tile_width) {
+    fbb_.AddElement<uint32_t>(TilemapComponentData::VT_TILE_WIDTH, tile_width, 0);
+  }
+  void add_tile_height(uint32_t tile_height) {
+    fbb_.AddElement<uint32_t>(TilemapComponentData::VT_TILE_HEIGHT, tile_height, 0);
+  }
+  void add_columns(uint32_t columns) {
+    fbb_.AddElement<uint32_t>(TilemapComponentData::VT_COLUMNS, columns, 0);
+  }
+  void add_rows(uint32_t rows) {
+    fbb_.AddElement<uint32_t>(TilemapComponentData::VT_ROWS, rows, 0);
+  }
+  void add_tiles(::flatbuffers::Offset<::flatbuffers::Vector<uint32_t>> tiles) {
+    fbb_.AddOffset(TilemapComponentData::VT_TILES, tiles);
+  }
+  explicit TilemapComponentDataBuilder(::flatbuffers::FlatBufferBuilder &_fbb)
+        : fbb_(_fbb) {
+    start_ = fbb_.StartTable();
+  }
+  ::flatbuffers::Offset<TilemapComponentData> Finish() {
+    const auto end = fbb_.EndTable(start_);
+    auto o = ::flatbuffers::Offset<TilemapComponentData>(end);
+    return o;
+  }
+};
+
+inline ::flatbuffers::Offset<TilemapComponentData> CreateTilemapComponentData(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    ::flatbuffers::Offset<::flatbuffers::String> tileset_path = 0,
+    uint32_t tile_width = 0,
+    uint32_t tile_height = 0,
+    uint32_t columns = 0,
+    uint32_t rows = 0,
+    ::flatbuffers::Offset<::flatbuffers::Vector<uint32_t>> tiles = 0) {
+  TilemapComponentDataBuilder builder_(_fbb);
+  builder_.add_tiles(tiles);
+  builder_.add_rows(rows);
+  builder_.add_columns(columns);
+  builder_.add_tile_height(tile_height);
+  builder_.add_tile_width(tile_width);
+  builder_.add_tileset_path(tileset_path);
+  return builder_.Finish();
+}
+
+inline ::flatbuffers::Offset<TilemapComponentData> CreateTilemapComponentDataDirect(
+    ::flatbuffers::FlatBufferBuilder &_fbb,
+    const char *tileset_path = nullptr,
+    uint32_t tile_width = 0,
+    uint32_t tile_height = 0,
+    uint32_t columns = 0,
+    uint32_t rows = 0,
+    const std::vector<uint32_t> *tiles = nullptr) {
+  auto tileset_path__ = tileset_path ? _fbb.CreateString(tileset_path) : 0;
+  auto tiles__ = tiles ? _fbb.CreateVector<uint32_t>(*tiles) : 0;
+  return PixelCraft::ECS::CreateTilemapComponentData(
+      _fbb,
+      tileset_path__,
+      tile_width,
+      tile_height,
+      columns,
+      rows,
+      tiles__);
+}
+
+inline const PixelCraft::ECS::TilemapComponentData *GetTilemapComponentData(const void *buf) {
+  return ::flatbuffers::GetRoot<PixelCraft::ECS::TilemapComponentData>(buf);
+}
+
+inline const PixelCraft::ECS::TilemapComponentData *GetSizePrefixedTilemapComponentData(const void *buf) {
+  return ::flatbuffers::GetSizePrefixedRoot<PixelCraft::ECS::TilemapComponentData>(buf);
+}
+
+inline const char *TilemapComponentDataIdentifier() {
+  return "TMAP";
+}
+
+inline bool TilemapComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, TilemapComponentDataIdentifier());
+}
+
+inline bool SizePrefixedTilemapComponentDataBufferHasIdentifier(const void *buf) {
+  return ::flatbuffers::BufferHasIdentifier(
+      buf, TilemapComponentDataIdentifier(), true);
+}
+
+inline bool VerifyTilemapComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifyBuffer<PixelCraft::ECS::TilemapComponentData>(TilemapComponentDataIdentifier());
+}
+
+inline bool VerifySizePrefixedTilemapComponentDataBuffer(
+    ::flatbuffers::Verifier &verifier) {
+  return verifier.VerifySizePrefixedBuffer<PixelCraft::ECS::TilemapComponentData>(TilemapComponentDataIdentifier());
+}
+
+inline void FinishTilemapComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::TilemapComponentData> root) {
+  fbb.Finish(root, TilemapComponentDataIdentifier());
+}
+
+inline void FinishSizePrefixedTilemapComponentDataBuffer(
+    ::flatbuffers::FlatBufferBuilder &fbb,
+    ::flatbuffers::Offset<PixelCraft::ECS::TilemapComponentData> root) {
+  fbb.FinishSizePrefixed(root, TilemapComponentDataIdentifier());
+}
+
+}  // namespace ECS
+}  // namespace PixelCraft
+
+#endif  // FLATBUFFERS_GENERATED_TILEMAPCOMPONENT_PIXELCRAFT_ECS_H_
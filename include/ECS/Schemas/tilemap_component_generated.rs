@@ -0,0 +1,299 @@
+// automatically generated by the FlatBuffers compiler, do not modify
+
+
+// @generated
+
+use core::mem;
+use core::cmp::Ordering;
+
+extern crate flatbuffers;
+use self::flatbuffers::{EndianScalar, Follow};
+
+#[allow(unused_imports, dead_code)]
+pub mod pixel_craft {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+#[allow(unused_imports, dead_code)]
+pub mod ecs {
+
+  use core::mem;
+  use core::cmp::Ordering;
+
+  extern crate flatbuffers;
+  use self::flatbuffers::{EndianScalar, Follow};
+
+pub enum TilemapComponentDataOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TilemapComponentData<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TilemapComponentData<'a> {
+  type Inner = TilemapComponentData<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: flatbuffers::Table::new(buf, loc) }
+  }
+}
+
+impl<'a> TilemapComponentData<'a> {
+  pub const VT_TILESET_PATH: flatbuffers::VOffsetT = 4;
+  pub const VT_TILE_WIDTH: flatbuffers::VOffsetT = 6;
+  pub const VT_TILE_HEIGHT: flatbuffers::VOffsetT = 8;
+  pub const VT_COLUMNS: flatbuffers::VOffsetT = 10;
+  pub const VT_ROWS: flatbuffers::VOffsetT = 12;
+  pub const VT_TILES: flatbuffers::VOffsetT = 14;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TilemapComponentData { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TilemapComponentDataArgs<'args>
+  ) -> flatbuffers::WIPOffset<TilemapComponentData<'bldr>> {
+    let mut builder = TilemapComponentDataBuilder::new(_fbb);
+    if let Some(x) = args.tiles { builder.add_tiles(x); }
+    builder.add_rows(args.rows);
+    builder.add_columns(args.columns);
+    builder.add_tile_height(args.tile_height);
+    builder.add_tile_width(args.tile_width);
+    if let Some(x) = args.tileset_path { builder.add_tileset_path(x); }
+    builder.finish()
+  }
+
+
+  /// Tileset image, relative to the project's asset root.
+  #[inline]
+  pub fn tileset_path(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(TilemapComponentData::VT_TILESET_PATH, None)}
+  }
+  /// Size of one tile in pixels.
+  #[inline]
+  pub fn tile_width(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TilemapComponentData::VT_TILE_WIDTH, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn tile_height(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TilemapComponentData::VT_TILE_HEIGHT, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn columns(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TilemapComponentData::VT_COLUMNS, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn rows(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(TilemapComponentData::VT_ROWS, Some(0)).unwrap()}
+  }
+  /// Tile index for each cell in row-major order, columns * rows entries.
+  #[inline]
+  pub fn tiles(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(TilemapComponentData::VT_TILES, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TilemapComponentData<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("tileset_path", Self::VT_TILESET_PATH, false)?
+     .visit_field::<u32>("tile_width", Self::VT_TILE_WIDTH, false)?
+     .visit_field::<u32>("tile_height", Self::VT_TILE_HEIGHT, false)?
+     .visit_field::<u32>("columns", Self::VT_COLUMNS, false)?
+     .visit_field::<u32>("rows", Self::VT_ROWS, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("tiles", Self::VT_TILES, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TilemapComponentDataArgs<'a> {
+    pub tileset_path: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub columns: u32,
+    pub rows: u32,
+    pub tiles: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for TilemapComponentDataArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TilemapComponentDataArgs {
+      tileset_path: None,
+      tile_width: 0,
+      tile_height: 0,
+      columns: 0,
+      rows: 0,
+      tiles: None,
+    }
+  }
+}
+
+pub struct TilemapComponentDataBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TilemapComponentDataBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_tileset_path(&mut self, tileset_path: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TilemapComponentData::VT_TILESET_PATH, tileset_path);
+  }
+  #[inline]
+  pub fn add_tile_width(&mut self, tile_width: u32) {
+    self.fbb_.push_slot::<u32>(TilemapComponentData::VT_TILE_WIDTH, tile_width, 0);
+  }
+  #[inline]
+  pub fn add_tile_height(&mut self, tile_height: u32) {
+    self.fbb_.push_slot::<u32>(TilemapComponentData::VT_TILE_HEIGHT, tile_height, 0);
+  }
+  #[inline]
+  pub fn add_columns(&mut self, columns: u32) {
+    self.fbb_.push_slot::<u32>(TilemapComponentData::VT_COLUMNS, columns, 0);
+  }
+  #[inline]
+  pub fn add_rows(&mut self, rows: u32) {
+    self.fbb_.push_slot::<u32>(TilemapComponentData::VT_ROWS, rows, 0);
+  }
+  #[inline]
+  pub fn add_tiles(&mut self, tiles: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TilemapComponentData::VT_TILES, tiles);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TilemapComponentDataBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TilemapComponentDataBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TilemapComponentData<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TilemapComponentData<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TilemapComponentData");
+      ds.field("tileset_path", &self.tileset_path());
+      ds.field("tile_width", &self.tile_width());
+      ds.field("tile_height", &self.tile_height());
+      ds.field("columns", &self.columns());
+      ds.field("rows", &self.rows());
+      ds.field("tiles", &self.tiles());
+      ds.finish()
+  }
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a `TilemapComponentData`
+/// and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_tilemap_component_data_unchecked`.
+pub fn root_as_tilemap_component_data(buf: &[u8]) -> Result<TilemapComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root::<TilemapComponentData>(buf)
+}
+#[inline]
+/// Verifies that a buffer of bytes contains a size prefixed
+/// `TilemapComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `size_prefixed_root_as_tilemap_component_data_unchecked`.
+pub fn size_prefixed_root_as_tilemap_component_data(buf: &[u8]) -> Result<TilemapComponentData, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root::<TilemapComponentData>(buf)
+}
+#[inline]
+/// Verifies, with the given options, that a buffer of bytes
+/// contains a `TilemapComponentData` and returns it.
+/// Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_tilemap_component_data_unchecked`.
+pub fn root_as_tilemap_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<TilemapComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::root_with_opts::<TilemapComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Verifies, with the given verifier options, that a buffer of
+/// bytes contains a size prefixed `TilemapComponentData` and returns
+/// it. Note that verification is still experimental and may not
+/// catch every error, or be maximally performant. For the
+/// previous, unchecked, behavior use
+/// `root_as_tilemap_component_data_unchecked`.
+pub fn size_prefixed_root_as_tilemap_component_data_with_opts<'b, 'o>(
+  opts: &'o flatbuffers::VerifierOptions,
+  buf: &'b [u8],
+) -> Result<TilemapComponentData<'b>, flatbuffers::InvalidFlatbuffer> {
+  flatbuffers::size_prefixed_root_with_opts::<TilemapComponentData<'b>>(opts, buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a TilemapComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid `TilemapComponentData`.
+pub unsafe fn root_as_tilemap_component_data_unchecked(buf: &[u8]) -> TilemapComponentData {
+  flatbuffers::root_unchecked::<TilemapComponentData>(buf)
+}
+#[inline]
+/// Assumes, without verification, that a buffer of bytes contains a size prefixed TilemapComponentData and returns it.
+/// # Safety
+/// Callers must trust the given bytes do indeed contain a valid size prefixed `TilemapComponentData`.
+pub unsafe fn size_prefixed_root_as_tilemap_component_data_unchecked(buf: &[u8]) -> TilemapComponentData {
+  flatbuffers::size_prefixed_root_unchecked::<TilemapComponentData>(buf)
+}
+pub const TILEMAP_COMPONENT_DATA_IDENTIFIER: &str = "TMAP";
+
+#[inline]
+pub fn tilemap_component_data_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, TILEMAP_COMPONENT_DATA_IDENTIFIER, false)
+}
+
+#[inline]
+pub fn tilemap_component_data_size_prefixed_buffer_has_identifier(buf: &[u8]) -> bool {
+  flatbuffers::buffer_has_identifier(buf, TILEMAP_COMPONENT_DATA_IDENTIFIER, true)
+}
+
+#[inline]
+pub fn finish_tilemap_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(
+    fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    root: flatbuffers::WIPOffset<TilemapComponentData<'a>>) {
+  fbb.finish(root, Some(TILEMAP_COMPONENT_DATA_IDENTIFIER));
+}
+
+#[inline]
+pub fn finish_size_prefixed_tilemap_component_data_buffer<'a, 'b, A: flatbuffers::Allocator + 'a>(fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>, root: flatbuffers::WIPOffset<TilemapComponentData<'a>>) {
+  fbb.finish_size_prefixed(root, Some(TILEMAP_COMPONENT_DATA_IDENTIFIER));
+}
+}  // pub mod ECS
+}  // pub mod PixelCraft
+
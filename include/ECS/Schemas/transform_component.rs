@@ -0,0 +1,68 @@
+// Default-aware accessors for the generated `TransformComponentData` table.
+
+use crate::common_types_generated::*;
+use crate::transform_component_generated::*;
+
+/// The identity rotation, `(0, 0, 0, 1)` in `x, y, z, w` order.
+pub fn quat_identity() -> Quat {
+    Quat::new(0.0, 0.0, 0.0, 1.0)
+}
+
+/// `local_position`, or the origin when the field is absent.
+pub fn transform_position(data: &TransformComponentData) -> Vec3 {
+    data.local_position().copied().unwrap_or_default()
+}
+
+/// `local_rotation`, or the identity rotation when the field is absent.
+///
+/// Struct fields have no schema defaults, so an absent rotation would otherwise
+/// read as `None`, and a zeroed `Quat` is not a valid rotation.
+pub fn transform_rotation(data: &TransformComponentData) -> Quat {
+    data.local_rotation().copied().unwrap_or_else(quat_identity)
+}
+
+/// `local_scale`, or unit scale when the field is absent.
+pub fn transform_scale(data: &TransformComponentData) -> Vec3 {
+    data.local_scale()
+        .copied()
+        .unwrap_or(Vec3::new(1.0, 1.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flatbuffers::FlatBufferBuilder;
+
+    #[test]
+    fn absent_fields_read_as_the_identity_transform() {
+        let mut fbb = FlatBufferBuilder::new();
+        let offset = TransformComponentData::create(&mut fbb, &Default::default());
+        finish_transform_component_data_buffer(&mut fbb, offset);
+        let transform = root_as_transform_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(transform_position(&transform), Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(transform_rotation(&transform), quat_identity());
+        assert_eq!(transform_scale(&transform), Vec3::new(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn present_fields_are_returned_as_written() {
+        let mut fbb = FlatBufferBuilder::new();
+        let position = Vec3::new(1.0, 2.0, 3.0);
+        let rotation = Quat::new(0.0, 1.0, 0.0, 0.0);
+        let scale = Vec3::new(2.0, 2.0, 2.0);
+        let offset = TransformComponentData::create(
+            &mut fbb,
+            &TransformComponentDataArgs {
+                local_position: Some(&position),
+                local_rotation: Some(&rotation),
+                local_scale: Some(&scale),
+                ..Default::default()
+            },
+        );
+        finish_transform_component_data_buffer(&mut fbb, offset);
+        let transform = root_as_transform_component_data(fbb.finished_data()).unwrap();
+        assert_eq!(transform_position(&transform), position);
+        assert_eq!(transform_rotation(&transform), rotation);
+        assert_eq!(transform_scale(&transform), scale);
+    }
+}
@@ -0,0 +1,318 @@
+// Compile-time pins for the vtable slot of every table field.
+//
+// Level files are memory-mapped and read in place, so a field's vtable slot
+// (`VT_*`) is part of the on-disk format: if a regeneration moved a field, for
+// example because a field was inserted or reordered in a `.fbs` file instead
+// of appended, existing files would silently read one field as another. Each
+// `const` block below fails to compile when a slot changes. New fields get a
+// new line here; existing lines must never be edited.
+
+mod animation_component {
+    use crate::animation_component_generated::*;
+
+    const _: () = {
+        assert!(AnimationComponentData::VT_CLIP_NAME == 4);
+        assert!(AnimationComponentData::VT_FRAME_COUNT == 6);
+        assert!(AnimationComponentData::VT_FPS == 8);
+        assert!(AnimationComponentData::VT_LOOP_MODE == 10);
+        assert!(AnimationComponentData::VT_CURRENT_FRAME == 12);
+    };
+}
+
+mod audio_source_component {
+    use crate::audio_source_component_generated::*;
+
+    const _: () = {
+        assert!(AudioSourceComponentData::VT_AUDIO_CLIP_PATH == 4);
+        assert!(AudioSourceComponentData::VT_VOLUME == 6);
+        assert!(AudioSourceComponentData::VT_PITCH == 8);
+        assert!(AudioSourceComponentData::VT_LOOP_ == 10);
+        assert!(AudioSourceComponentData::VT_SPATIAL_BLEND == 12);
+        assert!(AudioSourceComponentData::VT_MIN_DISTANCE == 14);
+        assert!(AudioSourceComponentData::VT_MAX_DISTANCE == 16);
+        assert!(AudioSourceComponentData::VT_PLAYING == 18);
+        assert!(AudioSourceComponentData::VT_PLAY_ON_SPAWN == 20);
+    };
+}
+
+mod camera_component {
+    use crate::camera_component_generated::*;
+
+    const _: () = {
+        assert!(CameraComponentData::VT_CAMERA_TYPE == 4);
+        assert!(CameraComponentData::VT_FOV == 6);
+        assert!(CameraComponentData::VT_ORTHO_SIZE == 8);
+        assert!(CameraComponentData::VT_NEAR_PLANE == 10);
+        assert!(CameraComponentData::VT_FAR_PLANE == 12);
+        assert!(CameraComponentData::VT_ASPECT_RATIO == 14);
+        assert!(CameraComponentData::VT_IS_MAIN == 16);
+        assert!(CameraComponentData::VT_VIEWPORT == 18);
+    };
+}
+
+mod collider_component_array {
+    use crate::collider_component_array_generated::*;
+
+    const _: () = {
+        assert!(ColliderComponentArrayData::VT_COLLIDERS == 4);
+    };
+}
+
+mod collider_component {
+    use crate::collider_component_generated::*;
+
+    const _: () = {
+        assert!(ColliderComponentData::VT_COLLIDER_TYPE == 4);
+        assert!(ColliderComponentData::VT_IS_TRIGGER == 6);
+        assert!(ColliderComponentData::VT_SIZE == 8);
+        assert!(ColliderComponentData::VT_RADIUS == 10);
+        assert!(ColliderComponentData::VT_HEIGHT == 12);
+        assert!(ColliderComponentData::VT_MATERIAL_NAME == 14);
+        assert!(ColliderComponentData::VT_MESH_PATH == 16);
+        assert!(ColliderComponentData::VT_LAYER == 18);
+        assert!(ColliderComponentData::VT_COLLISION_MASK == 20);
+        assert!(ColliderComponentData::VT_CENTER == 22);
+        assert!(ColliderComponentData::VT_IS_2D == 24);
+        assert!(ColliderComponentData::VT_SIZE_2D == 26);
+        assert!(ColliderComponentData::VT_DENSITY == 28);
+        assert!(ColliderComponentData::VT_MASS_OVERRIDE == 30);
+        assert!(ColliderComponentData::VT_SCHEMA_VERSION == 32);
+        assert!(ColliderComponentData::VT_DEBUG_COLOR == 34);
+        assert!(ColliderComponentData::VT_CAPSULE_AXIS == 36);
+        assert!(ColliderComponentData::VT_MESH_IS_CONVEX == 38);
+        assert!(ColliderComponentData::VT_TRIGGER_CHANNEL == 40);
+        assert!(ColliderComponentData::VT_CONTACT_MARGIN == 42);
+        assert!(ColliderComponentData::VT_TRIGGER_DWELL_SECONDS == 44);
+        assert!(ColliderComponentData::VT_TRIGGER_WITH_TRIGGERS == 46);
+        assert!(ColliderComponentData::VT_IS_STATIC == 48);
+        assert!(ColliderComponentData::VT_UNITS == 50);
+        assert!(ColliderComponentData::VT_SURFACE_TYPE == 52);
+        assert!(ColliderComponentData::VT_TRIGGER_DEBOUNCE_SECONDS == 54);
+    };
+}
+
+mod collider_pool_scene {
+    use crate::collider_pool_scene_generated::*;
+
+    const _: () = {
+        assert!(ColliderPoolSceneData::VT_COLLIDERS == 4);
+        assert!(ColliderPoolSceneData::VT_ENTITIES == 6);
+    };
+}
+
+mod collider_scene {
+    use crate::collider_scene_generated::*;
+
+    const _: () = {
+        assert!(ColliderRecord::VT_ENTITY_ID == 4);
+        assert!(ColliderRecord::VT_COLLIDER == 6);
+    };
+
+    const _: () = {
+        assert!(ColliderSceneData::VT_RECORDS == 4);
+    };
+}
+
+mod compound_collider {
+    use crate::compound_collider_generated::*;
+
+    const _: () = {
+        assert!(CompoundColliderData::VT_CHILDREN == 4);
+        assert!(CompoundColliderData::VT_CHILD_POSITIONS == 6);
+    };
+}
+
+mod light_component {
+    use crate::light_component_generated::*;
+
+    const _: () = {
+        assert!(LightComponentData::VT_LIGHT_TYPE == 4);
+        assert!(LightComponentData::VT_COLOR == 6);
+        assert!(LightComponentData::VT_INTENSITY == 8);
+        assert!(LightComponentData::VT_RANGE == 10);
+        assert!(LightComponentData::VT_INNER_ANGLE == 12);
+        assert!(LightComponentData::VT_OUTER_ANGLE == 14);
+        assert!(LightComponentData::VT_CAST_SHADOWS == 16);
+        assert!(LightComponentData::VT_SHADOW_BIAS == 18);
+        assert!(LightComponentData::VT_SHADOW_RESOLUTION == 20);
+    };
+}
+
+mod mesh_renderer_component {
+    use crate::mesh_renderer_component_generated::*;
+
+    const _: () = {
+        assert!(MeshRendererComponentData::VT_MESH_PATH == 4);
+        assert!(MeshRendererComponentData::VT_MATERIAL_PATH == 6);
+        assert!(MeshRendererComponentData::VT_CAST_SHADOWS == 8);
+        assert!(MeshRendererComponentData::VT_RECEIVE_SHADOWS == 10);
+        assert!(MeshRendererComponentData::VT_ENABLED == 12);
+    };
+}
+
+mod particle_emitter_component {
+    use crate::particle_emitter_component_generated::*;
+
+    const _: () = {
+        assert!(ParticleEmitterComponentData::VT_TEXTURE_PATH == 4);
+        assert!(ParticleEmitterComponentData::VT_EMISSION_RATE == 6);
+        assert!(ParticleEmitterComponentData::VT_LIFETIME == 8);
+        assert!(ParticleEmitterComponentData::VT_START_COLOR == 10);
+        assert!(ParticleEmitterComponentData::VT_END_COLOR == 12);
+        assert!(ParticleEmitterComponentData::VT_START_SIZE == 14);
+        assert!(ParticleEmitterComponentData::VT_END_SIZE == 16);
+        assert!(ParticleEmitterComponentData::VT_INITIAL_VELOCITY == 18);
+        assert!(ParticleEmitterComponentData::VT_GRAVITY_SCALE == 20);
+        assert!(ParticleEmitterComponentData::VT_MAX_PARTICLES == 22);
+    };
+}
+
+mod particle_system_component {
+    use crate::particle_system_component_generated::*;
+
+    const _: () = {
+        assert!(ParticleSystemComponentData::VT_MAX_PARTICLES == 4);
+        assert!(ParticleSystemComponentData::VT_EMISSION_RATE == 6);
+        assert!(ParticleSystemComponentData::VT_LIFETIME == 8);
+        assert!(ParticleSystemComponentData::VT_START_SPEED == 10);
+        assert!(ParticleSystemComponentData::VT_START_SIZE == 12);
+        assert!(ParticleSystemComponentData::VT_START_COLOR == 14);
+        assert!(ParticleSystemComponentData::VT_GRAVITY_MODIFIER == 16);
+        assert!(ParticleSystemComponentData::VT_SIMULATION_SPACE == 18);
+        assert!(ParticleSystemComponentData::VT_TEXTURE_PATH == 20);
+    };
+}
+
+mod physics_material {
+    use crate::physics_material_generated::*;
+
+    const _: () = {
+        assert!(PhysicsMaterialData::VT_NAME == 4);
+        assert!(PhysicsMaterialData::VT_STATIC_FRICTION == 6);
+        assert!(PhysicsMaterialData::VT_DYNAMIC_FRICTION == 8);
+        assert!(PhysicsMaterialData::VT_RESTITUTION == 10);
+        assert!(PhysicsMaterialData::VT_COMBINE_MODE == 12);
+    };
+}
+
+mod rigid_body_component {
+    use crate::rigid_body_component_generated::*;
+
+    const _: () = {
+        assert!(RigidBodyComponentData::VT_BODY_TYPE == 4);
+        assert!(RigidBodyComponentData::VT_MASS == 6);
+        assert!(RigidBodyComponentData::VT_DRAG == 8);
+        assert!(RigidBodyComponentData::VT_ANGULAR_DRAG == 10);
+        assert!(RigidBodyComponentData::VT_USE_GRAVITY == 12);
+        assert!(RigidBodyComponentData::VT_IS_KINEMATIC == 14);
+        assert!(RigidBodyComponentData::VT_LINEAR_VELOCITY == 16);
+        assert!(RigidBodyComponentData::VT_ANGULAR_VELOCITY == 18);
+        assert!(RigidBodyComponentData::VT_GRAVITY_SCALE == 20);
+        assert!(RigidBodyComponentData::VT_LOCK_ROTATION == 22);
+    };
+}
+
+mod script_component {
+    use crate::script_component_generated::*;
+
+    const _: () = {
+        assert!(ScriptProperty::VT_KEY == 4);
+        assert!(ScriptProperty::VT_VALUE == 6);
+    };
+
+    const _: () = {
+        assert!(ScriptComponentData::VT_SCRIPT_PATH == 4);
+        assert!(ScriptComponentData::VT_CLASS_NAME == 6);
+        assert!(ScriptComponentData::VT_ENABLED == 8);
+        assert!(ScriptComponentData::VT_PROPERTIES == 10);
+    };
+}
+
+mod sprite_component {
+    use crate::sprite_component_generated::*;
+
+    const _: () = {
+        assert!(SpriteComponentData::VT_TEXTURE_PATH == 4);
+        assert!(SpriteComponentData::VT_SOURCE_RECT == 6);
+        assert!(SpriteComponentData::VT_TINT == 8);
+        assert!(SpriteComponentData::VT_FLIP_X == 10);
+        assert!(SpriteComponentData::VT_FLIP_Y == 12);
+        assert!(SpriteComponentData::VT_LAYER == 14);
+        assert!(SpriteComponentData::VT_PIXELS_PER_UNIT == 16);
+    };
+}
+
+mod text_component {
+    use crate::text_component_generated::*;
+
+    const _: () = {
+        assert!(TextComponentData::VT_CONTENT == 4);
+        assert!(TextComponentData::VT_FONT_PATH == 6);
+        assert!(TextComponentData::VT_FONT_SIZE == 8);
+        assert!(TextComponentData::VT_COLOR == 10);
+        assert!(TextComponentData::VT_ALIGNMENT == 12);
+        assert!(TextComponentData::VT_WRAP_WIDTH == 14);
+    };
+}
+
+mod tilemap_component {
+    use crate::tilemap_component_generated::*;
+
+    const _: () = {
+        assert!(TilemapComponentData::VT_TILESET_PATH == 4);
+        assert!(TilemapComponentData::VT_TILE_WIDTH == 6);
+        assert!(TilemapComponentData::VT_TILE_HEIGHT == 8);
+        assert!(TilemapComponentData::VT_COLUMNS == 10);
+        assert!(TilemapComponentData::VT_ROWS == 12);
+        assert!(TilemapComponentData::VT_TILES == 14);
+    };
+}
+
+mod transform_component {
+    use crate::transform_component_generated::*;
+
+    const _: () = {
+        assert!(TransformComponentData::VT_LOCAL_POSITION == 4);
+        assert!(TransformComponentData::VT_LOCAL_ROTATION == 6);
+        assert!(TransformComponentData::VT_LOCAL_SCALE == 8);
+        assert!(TransformComponentData::VT_PARENT == 10);
+        assert!(TransformComponentData::VT_CHILDREN == 12);
+        assert!(TransformComponentData::VT_WORLD_TRANSFORM_DIRTY == 14);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collider_component::ColliderComponent;
+    use crate::collider_component_generated::*;
+    use crate::common_types_generated::*;
+    use flatbuffers::FlatBufferBuilder;
+
+    /// A collider with every field written has one vtable slot per field, so a
+    /// field appended to the schema without a pin above fails here.
+    #[test]
+    fn every_collider_field_is_pinned() {
+        let everything = ColliderComponent {
+            size: Some(Vec3::new(1.0, 1.0, 1.0)),
+            material_name: Some("ice".into()),
+            mesh_path: Some("rock.obj".into()),
+            center: Some(Vec3::new(0.0, 0.0, 0.0)),
+            size_2d: Some(Vec2::new(1.0, 1.0)),
+            debug_color: Some(Color::new(0, 0, 0, 0)),
+            trigger_channel: Some("default".into()),
+            surface_type: Some("stone".into()),
+            ..Default::default()
+        };
+        let mut fbb = FlatBufferBuilder::new();
+        fbb.force_defaults(true);
+        let offset = everything.serialize(&mut fbb);
+        finish_collider_component_data_buffer(&mut fbb, offset);
+        let collider = root_as_collider_component_data(fbb.finished_data()).unwrap();
+        let slots = collider._tab.vtable().num_fields();
+        assert_eq!(slots, 26);
+        assert_eq!(
+            ColliderComponentData::VT_TRIGGER_DEBOUNCE_SECONDS as usize,
+            4 + 2 * (slots - 1)
+        );
+    }
+}